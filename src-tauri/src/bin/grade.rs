@@ -0,0 +1,105 @@
+//! Grade or solve a collection of puzzles in parallel, one puzzle of 81 cells per line.
+//!
+//! Usage: grade [--threads N] [--solve] [--config PATH] [FILE]
+//!
+//! Puzzles are read from FILE or from the standard input, the results are printed in the same order,
+//! "score tier steps" per graded puzzle or the answer per solved one.
+use std::io::{self, Read};
+use std::path::Path;
+
+use app_lib::{GameBoard, SolverConfig, batch};
+
+struct Args {
+    threads: Option<usize>,
+    solve: bool,
+    config: Option<String>,
+    input: Option<String>,
+}
+
+fn parse_args() -> Result<Args, String> {
+    let mut args = Args {
+        threads: None,
+        solve: false,
+        config: None,
+        input: None,
+    };
+    let mut iter = std::env::args().skip(1);
+    while let Some(arg) = iter.next() {
+        match arg.as_str() {
+            "--threads" => {
+                let threads = iter.next().ok_or("--threads expects a number")?;
+                args.threads = Some(
+                    threads
+                        .parse()
+                        .map_err(|_| format!("invalid thread count {threads:?}"))?,
+                );
+            }
+            "--solve" => args.solve = true,
+            "--config" => args.config = Some(iter.next().ok_or("--config expects a path")?),
+            _ if arg.starts_with("--") => return Err(format!("unknown option {arg}")),
+            _ => args.input = Some(arg),
+        }
+    }
+    Ok(args)
+}
+
+fn run() -> Result<(), String> {
+    let args = parse_args()?;
+    if let Some(threads) = args.threads {
+        rayon::ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build_global()
+            .map_err(|err| err.to_string())?;
+    }
+    let input = match &args.input {
+        Some(path) => std::fs::read_to_string(path).map_err(|err| format!("{path}: {err}"))?,
+        None => {
+            let mut input = String::new();
+            io::stdin()
+                .read_to_string(&mut input)
+                .map_err(|err| err.to_string())?;
+            input
+        }
+    };
+    let boards = input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| {
+            GameBoard::from_puzzle(line).map_err(|err| format!("line {}: {err}", index + 1))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    if args.solve {
+        for solved in batch::solve_all(&boards) {
+            match solved {
+                Ok(board) => println!(
+                    "{}",
+                    (0..81)
+                        .map(|index| (board.get_answer(index / 9, index % 9) + 1).to_string())
+                        .collect::<String>()
+                ),
+                Err(_) => println!("no unique solution"),
+            }
+        }
+    } else {
+        let config = match &args.config {
+            Some(path) => SolverConfig::load(Path::new(path))?,
+            None => SolverConfig::default(),
+        };
+        for rating in batch::rate_all(&boards, &config) {
+            match rating {
+                Some(rating) => println!("{} {:?} {}", rating.score, rating.tier, rating.steps),
+                None => println!("unrated"),
+            }
+        }
+    }
+    Ok(())
+}
+
+fn main() {
+    if let Err(err) = run() {
+        eprintln!("{err}");
+        std::process::exit(1);
+    }
+}
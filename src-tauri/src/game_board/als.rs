@@ -2,8 +2,8 @@ use crate::utils::{BitMap, Coord, House};
 
 use super::GameBoard;
 
-
-pub struct Als{
+#[derive(Clone, Debug, PartialEq)]
+pub struct Als {
     house_type: usize,
     house_id: usize,
     indices: BitMap,
@@ -11,30 +11,75 @@ pub struct Als{
 }
 
 impl Als {
-    
     pub fn new(house_type: usize, house_id: usize, indices: BitMap, candidates: BitMap) -> Self {
-        Self { house_type, house_id, indices, candidates }
+        Self {
+            house_type,
+            house_id,
+            indices,
+            candidates,
+        }
     }
-    
-    pub fn try_new(game_board: &GameBoard, als_indices: BitMap, house_type:usize, house_id: usize) -> Option<Self>{
+
+    pub fn try_new(
+        game_board: &GameBoard,
+        als_indices: BitMap,
+        house_type: usize,
+        house_id: usize,
+    ) -> Option<Self> {
         let mut candidates = BitMap::new();
-        for cell_id in als_indices.iter_ones(){
-            let (x,y) = Coord::from_house_and_index(&House::from_dim_id(house_type, house_id), cell_id);
+        for cell_id in als_indices.iter_ones() {
+            let (x, y) =
+                Coord::from_house_and_index(&House::from_dim_id(house_type, house_id), cell_id);
             candidates.insert_set(game_board.get_candidates(x, y)?);
         }
-        if candidates.count() == als_indices.count()+1{
-            Some(Self::new(house_type,house_id,als_indices,candidates))
-        }else{
+        if candidates.count() == als_indices.count() + 1 {
+            Some(Self::new(house_type, house_id, als_indices, candidates))
+        } else {
             None
         }
     }
-    
+
     pub fn candidates(&self) -> BitMap {
         self.candidates
     }
-    
+
     pub fn indices(&self) -> BitMap {
         self.indices
     }
-    
-}
\ No newline at end of file
+
+    pub fn house_type(&self) -> usize {
+        self.house_type
+    }
+
+    pub fn house_id(&self) -> usize {
+        self.house_id
+    }
+
+    pub fn house(&self) -> House {
+        House::from_dim_id(self.house_type, self.house_id)
+    }
+
+    /// Iterate through the coordinates of the cells in this ALS
+    pub fn cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        let house = self.house();
+        self.indices
+            .iter_ones()
+            .map(move |index| Coord::from_house_and_index(&house, index))
+    }
+
+    /// Iterate through the coordinates of the cells in this ALS which contain target as candidate
+    pub fn cells_with_candidate<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+        target: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.cells()
+            .filter(move |&(x, y)| game_board.contains_candidate(x, y, target))
+    }
+
+    /// Returns true if cell (x,y) is one of the cells in this ALS
+    pub fn contains_cell(&self, x: usize, y: usize) -> bool {
+        Coord::get_index_from_house(&self.house(), x, y)
+            .is_some_and(|index| self.indices.contains(index))
+    }
+}
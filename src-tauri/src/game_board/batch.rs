@@ -0,0 +1,63 @@
+use rayon::prelude::*;
+
+use crate::solvers::SolverConfig;
+
+use super::{
+    GameBoard, Rating,
+    dlx_solver::{DLXSolver, dlx_solution::DLXSolution},
+};
+
+/// Rate every board with the given configuration in parallel on the rayon thread pool,
+/// the ratings are returned in the order of the boards
+pub fn rate_all(boards: &[GameBoard], config: &SolverConfig) -> Vec<Option<Rating>> {
+    boards.par_iter().map(|board| board.rate(config)).collect()
+}
+
+/// Solve every board with DLX in parallel on the rayon thread pool,
+/// the solved boards are returned in the order of the boards
+pub fn solve_all(boards: &[GameBoard]) -> Vec<Result<GameBoard, DLXSolution>> {
+    boards
+        .par_iter()
+        .map(|board| {
+            let mut board = board.clone();
+            DLXSolver::solve_sudoku(&mut board)?;
+            Ok(board)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod batch_test {
+    use super::*;
+    use crate::utils::Coord;
+
+    fn assert_thread_safe<T: Send + Sync>() {}
+
+    #[test]
+    fn test_thread_safe() {
+        assert_thread_safe::<GameBoard>();
+        assert_thread_safe::<SolverConfig>();
+        assert_thread_safe::<DLXSolver>();
+    }
+
+    #[test]
+    fn test_batch_order() {
+        let boards: Vec<_> = include_str!("../tests/puzzles.txt")
+            .lines()
+            .map(|line| GameBoard::from_puzzle(line).unwrap())
+            .collect();
+        let config = SolverConfig::default();
+        let ratings = rate_all(&boards, &config);
+        let solved = solve_all(&boards);
+        assert_eq!(ratings.len(), boards.len());
+        for ((board, rating), solved) in boards.iter().zip(ratings).zip(solved) {
+            assert!(rating == board.rate(&config));
+            let solved = solved.ok().unwrap();
+            let mut answer = board.clone();
+            assert!(DLXSolver::solve_sudoku(&mut answer).is_ok());
+            assert!(
+                Coord::all_cells().all(|(x, y)| solved.get_answer(x, y) == answer.get_answer(x, y))
+            );
+        }
+    }
+}
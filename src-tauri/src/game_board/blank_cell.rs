@@ -1,6 +1,8 @@
+use serde::Serialize;
+
 use crate::utils::BitMap;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Serialize)]
 pub struct BlankCell {
     ans: usize,
     pen_mark: Option<usize>,
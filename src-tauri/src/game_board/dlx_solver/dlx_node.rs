@@ -1,7 +1,5 @@
-use std::cell::RefCell;
-use std::rc::Rc;
-
-pub type Link = Option<Rc<RefCell<DLXNode>>>;
+/// Index of a node in the node list of the solver
+pub type Link = usize;
 
 #[derive(Debug, PartialEq)]
 pub struct DLXNode {
@@ -15,15 +13,16 @@ pub struct DLXNode {
 }
 
 impl DLXNode {
-    pub fn new(row_id: usize, col_id: usize) -> Rc<RefCell<Self>> {
-        Rc::new(RefCell::new(DLXNode {
-            left: None,
-            right: None,
-            up: None,
-            down: None,
-            column: None,
+    /// A node linked to itself, at index `link` of the node list
+    pub fn new(link: Link, row_id: usize, col_id: usize) -> Self {
+        DLXNode {
+            left: link,
+            right: link,
+            up: link,
+            down: link,
+            column: link,
             row_id,
             col_id,
-        }))
+        }
     }
 }
@@ -2,4 +2,6 @@ pub enum DLXSolution {
     Solution(Vec<usize>),
     NoSolution,
     MultipleSolutions,
+    /// The search was cancelled before it could tell
+    Cancelled,
 }
@@ -1,147 +1,145 @@
 mod dlx_node;
 pub mod dlx_solution;
 use super::super::game_board::{Cell, GameBoard};
+use crate::utils::CancelToken;
 
 use dlx_node::DLXNode;
 use dlx_node::Link;
 use dlx_solution::DLXSolution;
-use std::cell::RefCell;
-use std::rc::Rc;
+
+/// The nodes are kept in a single list and linked by their indices, so the solver can be sent across threads
 pub struct DLXSolver {
-    header: Rc<RefCell<DLXNode>>,       // 矩阵的头节点
-    columns: Vec<Rc<RefCell<DLXNode>>>, // 列头节点
-    col_count: Vec<usize>,              // 每列的节点数
+    nodes: Vec<DLXNode>,   // 所有节点, 0号为矩阵的头节点, 1..=列数号为列头节点
+    col_count: Vec<usize>, // 每列的节点数
+    cancel_token: CancelToken,
 }
 
+const HEADER: Link = 0;
+
 impl DLXSolver {
-    fn new(num_columns: usize) -> Self {
-        let header = DLXNode::new(usize::MAX, usize::MAX);
-        let mut columns = Vec::new();
+    fn new(num_columns: usize, cancel_token: CancelToken) -> Self {
+        let mut nodes = vec![DLXNode::new(HEADER, usize::MAX, usize::MAX)];
         let col_count = vec![0; num_columns];
 
         // 初始化列头节点
-        let mut prev = header.clone();
+        let mut prev = HEADER;
         for i in 0..num_columns {
-            let col_node = DLXNode::new(0, i);
-            col_node.borrow_mut().left = Some(prev.clone());
-            prev.borrow_mut().right = Some(col_node.clone());
-            columns.push(col_node.clone());
-            col_node.borrow_mut().up = Some(col_node.clone());
-            col_node.borrow_mut().down = Some(col_node.clone());
+            let col_node = nodes.len();
+            nodes.push(DLXNode::new(col_node, 0, i));
+            nodes[col_node].left = prev;
+            nodes[prev].right = col_node;
 
             prev = col_node;
         }
 
         // 连接首尾做成循环链表
-        prev.borrow_mut().right = Some(header.clone());
-        header.borrow_mut().left = Some(prev);
+        nodes[prev].right = HEADER;
+        nodes[HEADER].left = prev;
 
         DLXSolver {
-            header,
-            columns,
+            nodes,
             col_count,
+            cancel_token,
         }
     }
+
+    // 列号对应的列头节点
+    fn column(col_id: usize) -> Link {
+        col_id + 1
+    }
+
     // 在矩阵中添加一行
     // Parameters:
     // row_id: 行号
     // cols: 该行中包含的列号
 
     fn add_row(&mut self, row_id: usize, cols: &[usize]) {
-        let mut first: Link = None;
-        let mut prev: Link = None;
+        let mut first: Option<Link> = None;
+        let mut prev: Option<Link> = None;
 
         for &col_id in cols {
-            let col_node = self.columns[col_id].clone();
-            let new_node = DLXNode::new(row_id, col_id);
+            let col_node = Self::column(col_id);
+            let new_node = self.nodes.len();
+            self.nodes.push(DLXNode::new(new_node, row_id, col_id));
 
             //将新节点插入到列中
-            new_node.borrow_mut().up = col_node.borrow().up.clone();
-            new_node.borrow_mut().down = Some(col_node.clone());
+            let up = self.nodes[col_node].up;
+            self.nodes[new_node].up = up;
+            self.nodes[new_node].down = col_node;
 
-            new_node.borrow_mut().up.as_ref().unwrap().borrow_mut().down = Some(new_node.clone());
+            self.nodes[up].down = new_node;
 
-            col_node.borrow_mut().up = Some(new_node.clone());
+            self.nodes[col_node].up = new_node;
 
             self.col_count[col_id] += 1;
 
             // 将新节点的列头指针指向列头节点
-            new_node.borrow_mut().column = Some(col_node.clone());
+            self.nodes[new_node].column = col_node;
 
             // 连接行
             //如果prev存在，建立prev和new_node的双向连接
             // 否则，将new_node记作first
-            if let Some(prev_node) = &prev {
-                new_node.borrow_mut().left = Some(prev_node.clone());
-                prev_node.borrow_mut().right = Some(new_node.clone());
+            if let Some(prev_node) = prev {
+                self.nodes[new_node].left = prev_node;
+                self.nodes[prev_node].right = new_node;
             } else {
-                first = Some(new_node.clone());
+                first = Some(new_node);
             }
 
             prev = Some(new_node);
         }
         // 连接行的首尾
-        if let Some(first_node) = &first {
-            if let Some(last_node) = &prev {
-                first_node.borrow_mut().left = Some(last_node.clone());
-                last_node.borrow_mut().right = Some(first_node.clone());
-            }
+        if let (Some(first_node), Some(last_node)) = (first, prev) {
+            self.nodes[first_node].left = last_node;
+            self.nodes[last_node].right = first_node;
         }
     }
     // 覆盖某一列
     // Parameters:
     // col: 需要覆盖的那一列的列头节点
 
-    fn cover(&mut self, col: Rc<RefCell<DLXNode>>) {
+    fn cover(&mut self, col: Link) {
         // 从列头链表中移除该列头结点
-        col.borrow().left.as_ref().unwrap().borrow_mut().right = col.borrow().right.clone();
-        col.borrow().right.as_ref().unwrap().borrow_mut().left = col.borrow().left.clone();
+        let (left, right) = (self.nodes[col].left, self.nodes[col].right);
+        self.nodes[left].right = right;
+        self.nodes[right].left = left;
 
         // 移除列中的所有行
-        let mut row = col.borrow().down.clone();
-        while let Some(r) = row {
-            if Rc::ptr_eq(&r, &col) {
-                break;
-            }
-            let mut node = r.borrow().right.clone();
-            while let Some(n) = node {
-                if Rc::ptr_eq(&n, &r) {
-                    break;
-                }
-                n.borrow().up.as_ref().unwrap().borrow_mut().down = n.borrow().down.clone();
-                n.borrow().down.as_ref().unwrap().borrow_mut().up = n.borrow().up.clone();
-                self.col_count[n.borrow().col_id] -= 1;
-                node = n.borrow().right.clone();
+        let mut row = self.nodes[col].down;
+        while row != col {
+            let mut node = self.nodes[row].right;
+            while node != row {
+                let (up, down) = (self.nodes[node].up, self.nodes[node].down);
+                self.nodes[up].down = down;
+                self.nodes[down].up = up;
+                self.col_count[self.nodes[node].col_id] -= 1;
+                node = self.nodes[node].right;
             }
-            row = r.borrow().down.clone();
+            row = self.nodes[row].down;
         }
     }
 
     // 恢复某一列
     // Parameters:
     // col: 需要恢复的那一列的列头节点
-    fn uncover(&mut self, col: Rc<RefCell<DLXNode>>) {
+    fn uncover(&mut self, col: Link) {
         // 恢复列中的所有行
-        let mut row = col.borrow().up.clone();
-        while let Some(r) = row {
-            if Rc::ptr_eq(&r, &col) {
-                break;
+        let mut row = self.nodes[col].up;
+        while row != col {
+            let mut node = self.nodes[row].left;
+            while node != row {
+                let (up, down) = (self.nodes[node].up, self.nodes[node].down);
+                self.nodes[down].up = node;
+                self.nodes[up].down = node;
+                self.col_count[self.nodes[node].col_id] += 1;
+                node = self.nodes[node].left;
             }
-            let mut node = r.borrow().left.clone();
-            while let Some(n) = node {
-                if Rc::ptr_eq(&n, &r) {
-                    break;
-                }
-                n.borrow().down.as_ref().unwrap().borrow_mut().up = Some(n.clone());
-                n.borrow().up.as_ref().unwrap().borrow_mut().down = Some(n.clone());
-                self.col_count[n.borrow().col_id] += 1;
-                node = n.borrow().left.clone();
-            }
-            row = r.borrow().up.clone();
+            row = self.nodes[row].up;
         }
         // 恢复列头结点
-        col.borrow().left.as_ref().unwrap().borrow_mut().right = Some(col.clone());
-        col.borrow().right.as_ref().unwrap().borrow_mut().left = Some(col.clone());
+        let (left, right) = (self.nodes[col].left, self.nodes[col].right);
+        self.nodes[left].right = col;
+        self.nodes[right].left = col;
     }
 
     // 递归求解
@@ -150,48 +148,43 @@ impl DLXSolver {
     // Returns: dlx_solution::DLXSolution
     fn search(&mut self, acc: &mut Vec<usize>) -> DLXSolution {
         // 如果所有列都被覆盖，则找到一个解
-        if Rc::ptr_eq(&self.header, self.header.borrow().right.as_ref().unwrap()) {
+        if self.nodes[HEADER].right == HEADER {
             return DLXSolution::Solution(acc.clone());
         }
+        // 搜索被取消
+        if self.cancel_token.is_cancelled() {
+            return DLXSolution::Cancelled;
+        }
 
         // 选择节点数最少的列
         let mut min = usize::MAX;
-        let mut min_col = self.header.clone();
-        let mut col = self.header.borrow().right.clone();
-        while let Some(c) = col {
-            if Rc::ptr_eq(&c, &self.header) {
-                break;
-            }
-            if self.col_count[c.borrow().col_id] < min {
-                min = self.col_count[c.borrow().col_id];
-                min_col = c.clone();
+        let mut min_col = HEADER;
+        let mut col = self.nodes[HEADER].right;
+        while col != HEADER {
+            if self.col_count[self.nodes[col].col_id] < min {
+                min = self.col_count[self.nodes[col].col_id];
+                min_col = col;
             }
-            col = c.borrow().right.clone();
+            col = self.nodes[col].right;
         }
         // 如果该列没有节点，则无解
         if min == 0 {
             return DLXSolution::NoSolution;
         }
         // 覆盖该列
-        self.cover(min_col.clone());
+        self.cover(min_col);
 
         let mut tmp = None;
-        let mut row = min_col.borrow().down.clone();
-        while let Some(r) = row {
-            //对于其中一行：
-            if Rc::ptr_eq(&r, &min_col) {
-                break;
-            }
+        let mut row = self.nodes[min_col].down;
+        //对于其中一行：
+        while row != min_col {
             // 将该行加入解中
-            acc.push(r.borrow().row_id);
+            acc.push(self.nodes[row].row_id);
             // 将该行中的所有列覆盖
-            let mut node = r.borrow().right.clone();
-            while let Some(n) = node {
-                if Rc::ptr_eq(&n, &r) {
-                    break;
-                }
-                self.cover(n.borrow().column.clone().unwrap());
-                node = n.borrow().right.clone();
+            let mut node = self.nodes[row].right;
+            while node != row {
+                self.cover(self.nodes[node].column);
+                node = self.nodes[node].right;
             }
             // 递归搜索
             match self.search(acc) {
@@ -203,24 +196,22 @@ impl DLXSolver {
                     }
                 }
                 DLXSolution::MultipleSolutions => return DLXSolution::MultipleSolutions,
+                DLXSolution::Cancelled => return DLXSolution::Cancelled,
                 _ => (),
             }
             // 回溯
             acc.pop();
             // 恢复该行中的所有列
-            let mut node = r.borrow().left.clone();
-            while let Some(n) = node {
-                if Rc::ptr_eq(&n, &r) {
-                    break;
-                }
-                self.uncover(n.borrow().column.clone().unwrap());
-                node = n.borrow().left.clone();
+            let mut node = self.nodes[row].left;
+            while node != row {
+                self.uncover(self.nodes[node].column);
+                node = self.nodes[node].left;
             }
             // 下一行
-            row = r.borrow().down.clone();
+            row = self.nodes[row].down;
         }
         // 恢复该列
-        self.uncover(min_col.clone());
+        self.uncover(min_col);
         if let Some(t) = tmp {
             DLXSolution::Solution(t)
         } else {
@@ -229,7 +220,7 @@ impl DLXSolver {
     }
 
     pub fn solve_sudoku(game_board: &mut GameBoard) -> Result<(), DLXSolution> {
-        let mut solver = DLXSolver::new(324);
+        let mut solver = DLXSolver::new(324, game_board.cancel_token().clone());
         let mut row_id = 0;
         let solution_mapping = &mut Vec::new();
         for i in 0..9 {
@@ -275,6 +266,7 @@ impl DLXSolver {
             }
             DLXSolution::NoSolution => Err(DLXSolution::NoSolution),
             DLXSolution::MultipleSolutions => Err(DLXSolution::MultipleSolutions),
+            DLXSolution::Cancelled => Err(DLXSolution::Cancelled),
         }
     }
 }
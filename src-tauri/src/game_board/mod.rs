@@ -1,29 +1,167 @@
-use crate::solvers::Solver;
 use crate::solvers::solution::Action::{self, Confirmation, Elimination};
 use crate::solvers::solution::{ConfirmationDetails, EliminationDetails, Solution};
 use crate::solvers::solver_enum::SolverEnum;
-use crate::utils::{BitMap, HouseType};
+use crate::solvers::{Solver, SolverConfig, SolverIdentifier, Tier};
+use crate::utils::{BitMap, CancelToken, CellSet, HouseType};
 use crate::utils::{Coord, House};
-use std::cell::OnceCell;
+use serde::Serialize;
+use std::sync::OnceLock;
 pub mod als;
+pub mod batch;
 pub mod blank_cell;
 pub mod dlx_solver;
+pub mod solve_path;
 use als::Als;
 use blank_cell::BlankCell;
+use dlx_solver::{DLXSolver, dlx_solution::DLXSolution};
+use solve_path::TracedStep;
 
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Serialize)]
 pub enum Cell {
     Printed(usize),
     Blank(BlankCell),
 }
 type CellHardLink = [Option<(usize, usize)>; 9];
+
+/// The difficulty of a board, given by the solvers used to finish it
+#[derive(Debug, PartialEq, Serialize)]
+pub struct Rating {
+    /// The sum of the score weights of the steps
+    pub score: u32,
+    /// The tier of the hardest step
+    pub tier: Tier,
+    pub steps: usize,
+}
+
+/// The steps found by a single solver
+#[derive(Serialize)]
+pub struct StepGroup {
+    pub solver_id: SolverIdentifier,
+    pub solutions: Vec<Solution>,
+}
+
+/// Only the grid is serialized, the candidate index and the caches are derived from it
+#[derive(Serialize)]
 pub struct GameBoard {
     grid: [[Cell; 9]; 9],
-    hard_links: OnceCell<[[[CellHardLink; 9]; 9]; 3]>,
+    // candidate_cells[num] : the cells containing num as candidate, kept in sync with every edit
+    #[serde(skip)]
+    candidate_cells: [CellSet; 9],
+    // candidate_positions[house_type][house_index][num] : the indices of the cells in this house containing num as candidate
+    #[serde(skip)]
+    candidate_positions: [[[BitMap; 9]; 9]; 3],
+    #[serde(skip)]
+    hard_links: OnceLock<[[[CellHardLink; 9]; 9]; 3]>,
     // occupied[house_type][num] : BitMap indicates that these rows(cols/boxes) occupied by the num
-    occupied: OnceCell<[[BitMap; 9]; 3]>,
-    // als_lists[house_type][house_index] : Vector of ALSs in this house
-    als_lists: OnceCell<[[Vec<Als>; 9]; 3]>,
+    #[serde(skip)]
+    occupied: OnceLock<[[BitMap; 9]; 3]>,
+    // als_lists[house_type][house_index] : Vector of ALSs in this house, calculated on demand for each house
+    #[serde(skip)]
+    als_lists: [[OnceLock<Vec<Als>>; 9]; 3],
+    // the steps which removed an answer, None unless the verification is enabled
+    #[serde(skip)]
+    unsound_steps: Option<Vec<TracedStep>>,
+    #[serde(skip)]
+    cancel_token: CancelToken,
+}
+
+/// Only the grid, the candidate index and the cancel token are copied,
+/// the caches are re-calculated on demand and the verification is left off
+impl Clone for GameBoard {
+    fn clone(&self) -> Self {
+        Self {
+            grid: self.grid,
+            candidate_cells: self.candidate_cells,
+            candidate_positions: self.candidate_positions,
+            hard_links: OnceLock::new(),
+            occupied: OnceLock::new(),
+            als_lists: Default::default(),
+            unsound_steps: None,
+            cancel_token: self.cancel_token.clone(),
+        }
+    }
+}
+
+/// This section contains constructors of game board
+impl GameBoard {
+    /// Build the board around the grid, the candidate index is built right away and the caches on demand
+    fn new(grid: [[Cell; 9]; 9]) -> Self {
+        let mut game_board = GameBoard {
+            grid,
+            candidate_cells: [CellSet::new(); 9],
+            candidate_positions: [[[BitMap::new(); 9]; 9]; 3],
+            occupied: OnceLock::new(),
+            hard_links: OnceLock::new(),
+            als_lists: Default::default(),
+            unsound_steps: None,
+            cancel_token: CancelToken::new(),
+        };
+        for (x, y) in Coord::all_cells() {
+            game_board.index_candidates(x, y, BitMap::all());
+        }
+        game_board
+    }
+
+    /// Build the board from the candidate bits of each cell in row-major order.
+    /// A single candidate is a printed number, or a pen mark if bit 9 is set as well.
+    pub fn from_array(arr: [u16; 81]) -> Self {
+        let mut i = 0;
+        let mut j = 0;
+        let mut grid = [[Cell::Blank(BlankCell::new_empty_cell()); 9]; 9];
+        for raw in arr {
+            let printed = (raw & (1 << 9)) == 0;
+            let raw = raw & 0xFDFF;
+            let candidates = BitMap::from_raw(raw);
+            if candidates.count() == 1 {
+                let num = candidates.trailing_zeros();
+
+                if printed {
+                    grid[i][j] = Cell::Printed(num);
+                } else if let Cell::Blank(ref mut cell) = grid[i][j] {
+                    cell.set_pen_mark(num);
+                }
+            } else if let Cell::Blank(ref mut cell) = grid[i][j] {
+                cell.set_candidates(candidates);
+            }
+            j += 1;
+            if j == 9 {
+                j = 0;
+                i += 1;
+            }
+        }
+        Self::new(grid)
+    }
+
+    /// Build the board from a puzzle line, 81 cells in row-major order,
+    /// a digit is a printed number and '.' or '0' a blank cell holding every candidate its peers allow.
+    pub fn from_puzzle(puzzle: &str) -> Result<Self, String> {
+        let cells: Vec<char> = puzzle.trim().chars().collect();
+        if cells.len() != 81 {
+            return Err(format!("expected 81 cells, got {}", cells.len()));
+        }
+        let mut grid = [[Cell::Blank(BlankCell::new_empty_cell()); 9]; 9];
+        for (index, &c) in cells.iter().enumerate() {
+            match c {
+                '1'..='9' => grid[index / 9][index % 9] = Cell::Printed(c as usize - '1' as usize),
+                '.' | '0' => (),
+                _ => return Err(format!("unexpected character {c:?} at cell {index}")),
+            }
+        }
+        for (x, y) in Coord::all_cells() {
+            if let Cell::Blank(_) = grid[x][y] {
+                let mut candidates = BitMap::all();
+                for (sx, sy) in Coord::seeable_cells(x, y) {
+                    if let Cell::Printed(num) = grid[sx][sy] {
+                        candidates.remove(num);
+                    }
+                }
+                if let Cell::Blank(ref mut cell) = grid[x][y] {
+                    cell.set_candidates(candidates);
+                }
+            }
+        }
+        Ok(Self::new(grid))
+    }
 }
 
 ///  This section contains getters of game board information
@@ -62,6 +200,16 @@ impl GameBoard {
         }
     }
 
+    /// Get the cells containing target as candidate
+    pub fn candidate_cells(&self, target: usize) -> CellSet {
+        self.candidate_cells[target]
+    }
+
+    /// Get the indices of the cells in the house containing target as candidate
+    pub fn candidate_positions(&self, house: &House, target: usize) -> BitMap {
+        self.candidate_positions[house.get_dim()][house.get_index()][target]
+    }
+
     /// Returns true if target at cell(x,y) is not denied by a given
     pub fn could_have_been(&self, x: usize, y: usize, target: usize) -> bool {
         match &self.grid[x][y] {
@@ -78,6 +226,12 @@ impl GameBoard {
             _ => false,
         }
     }
+    /// The number of printed numbers and pen marks
+    pub fn filled_cnt(&self) -> usize {
+        Coord::all_cells()
+            .filter(|&(x, y)| !self.not_filled(x, y))
+            .count()
+    }
     /// Returns true if cell (x,y) is a clue equals to target
     pub fn is_clue(&self, x: usize, y: usize, target: usize) -> bool {
         match &self.grid[x][y] {
@@ -113,11 +267,15 @@ impl GameBoard {
         self.hard_links.get_or_init(|| self.calculate_hard_link())
     }
 
-    pub fn als(&self) -> &[[Vec<Als>; 9]; 3] {
-        self.als_lists.get_or_init(|| self.calculate_als())
+    /// Iterate through the ALSs of every house, an ALS in a box-line intersection appears in both houses
+    pub fn als(&self) -> impl Iterator<Item = &Als> {
+        (0..3).flat_map(move |house_type| {
+            (0..9).flat_map(move |house_id| self.get_als_by_house(house_type, house_id))
+        })
     }
     pub fn get_als_by_house(&self, house_type: usize, house_id: usize) -> &[Als] {
-        &self.als()[house_type][house_id]
+        self.als_lists[house_type][house_id]
+            .get_or_init(|| self.calculate_als(house_type, house_id))
     }
     /// For a given cell and candidate, returns the coordinate of the hard-linked cell in the given dimension
     ///
@@ -200,8 +358,9 @@ impl GameBoard {
     /// - is not an pencil mark cell
     /// - doesn't contains the target candidate
     pub fn erase_pencil_mark(&mut self, x: usize, y: usize, target: usize) {
+        let before = self.grid;
         self.delete_candidate(x, y, target, true);
-        self.flush();
+        self.refresh(&before);
     }
 
     /// Add an pencil mark in given cell by user
@@ -212,6 +371,7 @@ impl GameBoard {
     /// - is not an pencil mark cell
     /// - already contained the target candidate
     pub fn add_pencil_mark(&mut self, x: usize, y: usize, target: usize) {
+        let before = self.grid;
         if let Cell::Blank(cell) = &mut self.grid[x][y] {
             if !cell.is_pen_mark() {
                 cell.modify(|candidates, user_deleted| {
@@ -222,7 +382,7 @@ impl GameBoard {
                 });
             }
         }
-        self.flush();
+        self.refresh(&before);
     }
 
     /// Set a cell to pen mark and removes corresponding candidate in all seeable cells.
@@ -230,16 +390,9 @@ impl GameBoard {
     /// This function will *do nothing* if the cell
     /// - is a pen mark cell or a printed cell
     pub fn set_pen_mark(&mut self, x: usize, y: usize, target: usize) {
-        if let Cell::Blank(cell) = &mut self.grid[x][y] {
-            if cell.is_pen_mark() {
-                return;
-            }
-            cell.set_pen_mark(target);
-
-            Coord::seeable_cells(x, y)
-                .for_each(|(xi, yi)| self.delete_candidate(xi, yi, target, false));
-            self.flush();
-        }
+        let before = self.grid;
+        self.write_pen_mark(x, y, target);
+        self.refresh(&before);
     }
     /// Erase the pen mark in cell (x,y)
     ///
@@ -248,6 +401,7 @@ impl GameBoard {
     /// - re-compute the pencil marks in this cell, all the candidates which are valid (no collision with current clues) and not deleted by user will appear.
     /// - put the candidate back in all the seeable cells if it's valid (no collision with other clues) and not deleted by user.
     pub fn erase_pen_mark(&mut self, x: usize, y: usize) {
+        let before = self.grid;
         let mut possible_candidates = BitMap::all();
         let mut to_put_back = Vec::new();
 
@@ -291,7 +445,7 @@ impl GameBoard {
         if let Cell::Blank(ref mut cell) = self.grid[x][y] {
             cell.update_candidates(&possible_candidates);
         }
-        self.flush();
+        self.refresh(&before);
     }
 }
 
@@ -311,88 +465,289 @@ impl GameBoard {
         true
     }
 
-    /// Execute an action
-    fn execute_action(&mut self, action: Action) {
+    /// Returns true if the board can't be finished anymore, i.e.
+    /// - an unsolved cell has no candidate left
+    /// - a number is neither filled nor a candidate in a house
+    pub fn has_contradiction(&self) -> bool {
+        let empty_cell = Coord::all_cells().any(|(x, y)| {
+            self.get_candidates(x, y)
+                .is_some_and(|candidates| candidates.count() == 0)
+        });
+        empty_cell
+            || (0..3).any(|dim| {
+                (0..9).any(|house_id| {
+                    let house = House::from_dim_id(dim, house_id);
+                    (0..9).any(|target| {
+                        !self
+                            .house_occupied_by(&HouseType::from_dim(dim), target)
+                            .contains(house_id)
+                            && !Coord::house(&house)
+                                .any(|(x, y)| self.contains_candidate(x, y, target))
+                    })
+                })
+            })
+    }
+
+    /// Execute an action, the caches are left to be refreshed once the whole solution is executed.
+    /// Returns false if the verification is on and the action removes the answer of its cell.
+    fn execute_action(&mut self, action: Action) -> bool {
+        let sound = !self.verifying() || self.keeps_answer(&action);
         match action {
             Confirmation(ConfirmationDetails { x, y, target }) => {
-                self.set_pen_mark(x, y, target);
+                self.write_pen_mark(x, y, target);
             }
             Elimination(EliminationDetails { x, y, target }) => {
                 for i in 0..9 {
                     if target.contains(i) {
-                        self.erase_pencil_mark(x, y, i);
+                        self.delete_candidate(x, y, i, true);
                     }
                 }
             }
         }
+        sound
+    }
+
+    /// Returns true if the executed actions are checked against the answers
+    fn verifying(&self) -> bool {
+        cfg!(debug_assertions) && self.unsound_steps.is_some()
     }
 
-    fn execute_solution(&mut self, solution: Solution) {
-        let actions = solution.actions;
-        for action in actions {
-            self.execute_action(action);
+    /// Returns true if the action keeps the answer of its cell
+    fn keeps_answer(&self, action: &Action) -> bool {
+        match action {
+            Confirmation(ConfirmationDetails { x, y, target }) => {
+                self.get_answer(*x, *y) == *target
+            }
+            Elimination(EliminationDetails { x, y, target }) => {
+                !target.contains(self.get_answer(*x, *y))
+            }
         }
     }
 
-    /// Find the next possible step
-    pub fn next_step(&mut self) -> Option<Solution> {
-        // gather all possible solvers
-        let solvers: Vec<SolverEnum> = crate::solvers::easy::get_easy_solvers();
+    /// Solve the board with DLX, then check every step executed from now on against the answers in debug builds.
+    ///
+    /// The steps which remove an answer are recorded with the board they were found on instead of panicking,
+    /// so that a whole corpus can be checked and the offending boards added to the fixtures.
+    pub fn enable_verification(&mut self) -> Result<(), DLXSolution> {
+        DLXSolver::solve_sudoku(self)?;
+        self.unsound_steps = Some(Vec::new());
+        Ok(())
+    }
+
+    /// The steps executed since the verification was enabled which removed the answer of a cell
+    pub fn unsound_steps(&self) -> &[TracedStep] {
+        self.unsound_steps.as_deref().unwrap_or_default()
+    }
+
+    /// Let the searches on this board and on its copies give up once the token is cancelled
+    pub fn set_cancel_token(&mut self, cancel_token: CancelToken) {
+        self.cancel_token = cancel_token;
+    }
+
+    pub fn cancel_token(&self) -> &CancelToken {
+        &self.cancel_token
+    }
+
+    /// Returns true if the searches on this board should give up, their results are incomplete from then on
+    pub fn cancelled(&self) -> bool {
+        self.cancel_token.is_cancelled()
+    }
+
+    /// Execute all the actions of the solution, then refresh the caches.
+    /// A solution with an action removing an answer is recorded with the board it was found on.
+    pub fn execute_solution(&mut self, solution: Solution) {
+        let board = self.verifying().then(|| self.clone());
+        let before = self.grid;
+        let mut sound = true;
+        for action in solution.actions.iter().cloned() {
+            sound &= self.execute_action(action);
+        }
+        self.refresh(&before);
+        if let (false, Some(board), Some(unsound_steps)) = (sound, board, &mut self.unsound_steps) {
+            unsound_steps.push(TracedStep { board, solution });
+        }
+    }
 
+    /// Find the next possible step with the solvers enabled in the configuration
+    pub fn next_step(&self, config: &SolverConfig) -> Option<Solution> {
+        self.next_step_by(&config.enabled_solvers())
+    }
+
+    /// Find the next possible step with the given solvers
+    pub(crate) fn next_step_by(&self, solvers: &[SolverEnum]) -> Option<Solution> {
         // try it one-by one until one of them give an answer
-        solvers.into_iter().find_map(|solver| solver.solve(self))
+        solvers
+            .iter()
+            .take_while(|_| !self.cancelled())
+            .find_map(|solver| solver.solve(self))
+    }
+
+    /// Rate the difficulty by solving a copy of the board step by step with the configured solvers,
+    /// returns None if they can't finish it or the search is cancelled.
+    /// The progress is reported as the number of filled cells.
+    pub fn rate(&self, config: &SolverConfig) -> Option<Rating> {
+        let solvers = config.enabled_solvers();
+        let mut board = self.clone();
+        let mut rating = Rating {
+            score: 0,
+            tier: Tier::Easy,
+            steps: 0,
+        };
+        // every step removes at least one of the 729 candidates
+        while !board.finished() && rating.steps < 81 * 9 {
+            let step = board.next_step_by(&solvers)?;
+            let setting = config.setting(step.solver_id)?;
+            rating.score += setting.score;
+            rating.tier = rating.tier.max(setting.tier);
+            rating.steps += 1;
+            board.execute_solution(step);
+            self.cancel_token.report_progress(board.filled_cnt(), 81);
+        }
+        board.finished().then_some(rating)
+    }
+
+    /// Find every step of every solver except the guesses, grouped by solver in the order of the identifiers.
+    /// The solver interrupted by the cancellation and the later ones are left out, the progress is reported per solver.
+    pub fn all_steps(&self) -> Vec<StepGroup> {
+        let solver_ids: Vec<_> = SolverIdentifier::ALL
+            .iter()
+            .filter(|solver_id| !solver_id.is_guess())
+            .collect();
+        solver_ids
+            .iter()
+            .enumerate()
+            .map_while(|(done, &&solver_id)| {
+                let solutions = SolverEnum::from(solver_id).solve_all(self);
+                if self.cancelled() {
+                    return None;
+                }
+                self.cancel_token
+                    .report_progress(done + 1, solver_ids.len());
+                Some((!solutions.is_empty()).then_some(StepGroup {
+                    solver_id,
+                    solutions,
+                }))
+            })
+            .flatten()
+            .collect()
     }
 }
 
 /// This section contains some private APIs for internal use
 impl GameBoard {
-    fn flush(&mut self) {
-        self.occupied.take();
-        self.hard_links.take();
-        self.als_lists.take();
+    /// Update the caches already calculated for the houses containing a cell changed since `before`,
+    /// the clues and candidates of the other houses are the same so their entries are still valid.
+    /// The ALSs of these houses are dropped, to be calculated again when a solver asks for them.
+    fn refresh(&mut self, before: &[[Cell; 9]; 9]) {
+        // dirty[house_type][house_id] : the numbers whose clue or candidates changed in this house
+        let mut dirty = [[BitMap::new(); 9]; 3];
+        for (x, y) in Coord::all_cells() {
+            let changed = Self::changed_numbers(&before[x][y], &self.grid[x][y]);
+            if changed.count() > 0 {
+                self.index_candidates(x, y, changed);
+                for (dim, house_id) in Coord::components_array(x, y).into_iter().enumerate() {
+                    dirty[dim][house_id].insert_set(changed);
+                }
+            }
+        }
+        let dirty_houses = || {
+            (0..3).flat_map(move |dim| {
+                (0..9)
+                    .filter(move |&house_id| dirty[dim][house_id].count() > 0)
+                    .map(move |house_id| (dim, house_id))
+            })
+        };
+        if let Some(mut occupied) = self.occupied.take() {
+            for (dim, house_id) in dirty_houses() {
+                self.occupy_house(&mut occupied[dim], dim, house_id);
+            }
+            let _ = self.occupied.set(occupied);
+        }
+        if let Some(mut hard_links) = self.hard_links.take() {
+            for (dim, house_id) in dirty_houses() {
+                self.link_house(&mut hard_links[dim], dim, house_id, dirty[dim][house_id]);
+            }
+            let _ = self.hard_links.set(hard_links);
+        }
+        for (dim, house_id) in dirty_houses() {
+            self.als_lists[dim][house_id].take();
+        }
+    }
+    /// The numbers whose clue or candidate appeared or disappeared between two states of a cell
+    fn changed_numbers(before: &Cell, after: &Cell) -> BitMap {
+        let marks = |cell: &Cell| match cell {
+            Cell::Printed(num) => (Some(*num), BitMap::new()),
+            Cell::Blank(blank_cell) => match blank_cell.get_pen_mark() {
+                Some(num) => (Some(num), BitMap::new()),
+                None => (None, *blank_cell.get_candidates()),
+            },
+        };
+        let (clue_before, candidates_before) = marks(before);
+        let (clue_after, candidates_after) = marks(after);
+        let mut changed = candidates_before.symmetric_difference(candidates_after);
+        if clue_before != clue_after {
+            for num in clue_before.into_iter().chain(clue_after) {
+                changed.insert(num);
+            }
+        }
+        changed
     }
     fn calculate_occupied(&self) -> [[BitMap; 9]; 3] {
         let mut res = [[BitMap::new(); 9]; 3];
-        for (x, y) in Coord::all_cells() {
-            match self.grid[x][y] {
-                Cell::Printed(num) => {
-                    res[0][num].insert(x);
-                    res[1][num].insert(y);
-                    res[2][num].insert(Coord::get_box_id(x, y));
-                }
-                Cell::Blank(blank_cell) => {
-                    if let Some(num) = blank_cell.get_pen_mark() {
-                        res[0][num].insert(x);
-                        res[1][num].insert(y);
-                        res[2][num].insert(Coord::get_box_id(x, y));
-                    }
-                }
+        for (dim, occupied) in res.iter_mut().enumerate() {
+            for house_id in 0..9 {
+                self.occupy_house(occupied, dim, house_id);
             }
         }
         res
     }
+    // set whether the house is occupied by each num in occupied[num]
+    fn occupy_house(&self, occupied: &mut [BitMap; 9], dim: usize, house_id: usize) {
+        for house_occupied in occupied.iter_mut() {
+            house_occupied.remove(house_id);
+        }
+        for (x, y) in House::from_dim_id(dim, house_id).as_iter() {
+            let clue = match self.grid[x][y] {
+                Cell::Printed(num) => Some(num),
+                Cell::Blank(blank_cell) => blank_cell.get_pen_mark(),
+            };
+            if let Some(num) = clue {
+                occupied[num].insert(house_id);
+            }
+        }
+    }
     fn calculate_hard_link(&self) -> [[[CellHardLink; 9]; 9]; 3] {
         let mut hard_links = [[[[None; 9]; 9]; 9]; 3];
         for (dim, h_links) in hard_links.iter_mut().enumerate() {
             for house_index in 0..9 {
-                for target in 0..9 {
-                    let appearance: Vec<_> = HouseType::from_dim(dim)
-                        .house(house_index)
-                        .as_iter()
-                        .filter(|&(x, y)| self.contains_candidate(x, y, target))
-                        .collect();
-
-                    if appearance.len() == 2 {
-                        let (x1, y1) = appearance[0];
-                        let (x2, y2) = appearance[1];
-                        h_links[x1][y1][target] = Some((x2, y2));
-                        h_links[x2][y2][target] = Some((x1, y1));
-                    }
-                }
+                self.link_house(h_links, dim, house_index, BitMap::all());
             }
         }
         hard_links
     }
+    // set the hard links of the targets between the cells of the house
+    fn link_house(
+        &self,
+        h_links: &mut [[CellHardLink; 9]; 9],
+        dim: usize,
+        house_index: usize,
+        targets: BitMap,
+    ) {
+        let house = HouseType::from_dim(dim).house(house_index);
+        for target in targets.iter_ones() {
+            for (x, y) in house.as_iter() {
+                h_links[x][y][target] = None;
+            }
+            let positions = self.candidate_positions[dim][house_index][target];
+            if positions.count() == 2 {
+                let mut appearance = positions.iter_ones().map(|index| house.ith_cell(index));
+                let (x1, y1) = appearance.next().unwrap();
+                let (x2, y2) = appearance.next().unwrap();
+                h_links[x1][y1][target] = Some((x2, y2));
+                h_links[x2][y2][target] = Some((x1, y1));
+            }
+        }
+    }
     fn calculate_unsolved_mask(&self, house_type: usize, house_id: usize) -> BitMap {
         let house = House::from_dim_id(house_type, house_id);
         let mut res = BitMap::new();
@@ -405,20 +760,49 @@ impl GameBoard {
         }
         res
     }
-    fn calculate_als(&self) -> [[Vec<Als>; 9]; 3] {
-        std::array::from_fn(|house_type| {
-            std::array::from_fn(|house_id| {
-                let unsolved_mask = self.calculate_unsolved_mask(house_type, house_id);
-                let num_unsolved_cells = unsolved_mask.count();
-                (1..num_unsolved_cells)
-                    .flat_map(|subset_size| {
-                        BitMap::get_combos_in_subset(subset_size, unsolved_mask).filter_map(
-                            |als_indices| Als::try_new(self, als_indices, house_type, house_id),
-                        )
-                    })
-                    .collect()
+    fn calculate_als(&self, house_type: usize, house_id: usize) -> Vec<Als> {
+        let unsolved_mask = self.calculate_unsolved_mask(house_type, house_id);
+        let num_unsolved_cells = unsolved_mask.count();
+        (1..num_unsolved_cells)
+            .flat_map(|subset_size| {
+                BitMap::get_combos_in_subset(subset_size, unsolved_mask)
+                    .filter_map(|als_indices| Als::try_new(self, als_indices, house_type, house_id))
             })
-        })
+            .collect()
+    }
+    // set a cell to pen mark and remove the target from the candidates of all seeable cells
+    // without refreshing the caches
+    fn write_pen_mark(&mut self, x: usize, y: usize, target: usize) {
+        if let Cell::Blank(cell) = &mut self.grid[x][y] {
+            if cell.is_pen_mark() {
+                return;
+            }
+            cell.set_pen_mark(target);
+
+            Coord::seeable_cells(x, y)
+                .for_each(|(xi, yi)| self.delete_candidate(xi, yi, target, false));
+        }
+    }
+    // update the candidate index of cell (x,y) for the given numbers
+    fn index_candidates(&mut self, x: usize, y: usize, nums: BitMap) {
+        let houses = Coord::components_array(x, y);
+        let indices = [y, x, (x % 3) * 3 + y % 3];
+        for num in nums.iter_ones() {
+            let contained = self.contains_candidate(x, y, num);
+            if contained {
+                self.candidate_cells[num].insert(x, y);
+            } else {
+                self.candidate_cells[num].remove(x, y);
+            }
+            for dim in 0..3 {
+                let positions = &mut self.candidate_positions[dim][houses[dim]][num];
+                if contained {
+                    positions.insert(indices[dim]);
+                } else {
+                    positions.remove(indices[dim]);
+                }
+            }
+        }
     }
     // delete target in a cell's candidate list
     // and mark it as user deleted if user_deleted_flag is true
@@ -457,7 +841,19 @@ pub mod game_board_test {
 
     use crate::solvers::easy;
 
+    use super::solve_path::SolvePath;
     use super::*;
+    use std::collections::BTreeSet;
+
+    /// .3....6...5.9....46...34.1.31....7...86.1...5.4..62...5..6..3.........2......8..9
+    /// played with the default solvers until nothing below a forcing chain applies
+    const EXTREME_POSITION: [u16; 81] = [
+        968, 4, 970, 723, 722, 593, 32, 784, 704, 707, 16, 577, 256, 704, 544, 642, 516, 8, 32,
+        834, 962, 722, 4, 8, 784, 1, 706, 4, 1, 530, 648, 920, 784, 64, 936, 674, 834, 128, 32,
+        588, 1, 836, 778, 776, 16, 832, 8, 848, 720, 32, 2, 513, 896, 516, 16, 834, 968, 32, 842,
+        833, 4, 712, 641, 969, 864, 973, 605, 856, 853, 664, 2, 545, 585, 610, 591, 591, 602, 128,
+        536, 632, 256,
+    ];
 
     impl GameBoard {
         pub fn from_string(input: &str) -> Self {
@@ -485,45 +881,7 @@ pub mod game_board_test {
                     }
                 }
             }
-            GameBoard {
-                grid,
-                occupied: OnceCell::new(),
-                hard_links: OnceCell::new(),
-                als_lists: OnceCell::new(),
-            }
-        }
-
-        pub fn from_array(arr: [u16; 81]) -> Self {
-            let mut i = 0;
-            let mut j = 0;
-            let mut grid = [[Cell::Blank(BlankCell::new_empty_cell()); 9]; 9];
-            for raw in arr {
-                let printed = (raw & (1 << 9)) == 0;
-                let raw = raw & 0xFDFF;
-                let candidates = BitMap::from_raw(raw);
-                if candidates.count() == 1 {
-                    let num = candidates.trailing_zeros();
-
-                    if printed {
-                        grid[i][j] = Cell::Printed(num);
-                    } else if let Cell::Blank(ref mut cell) = grid[i][j] {
-                        cell.set_pen_mark(num);
-                    }
-                } else if let Cell::Blank(ref mut cell) = grid[i][j] {
-                    cell.set_candidates(candidates);
-                }
-                j += 1;
-                if j == 9 {
-                    j = 0;
-                    i += 1;
-                }
-            }
-            GameBoard {
-                grid,
-                occupied: OnceCell::new(),
-                hard_links: OnceCell::new(),
-                als_lists: OnceCell::new(),
-            }
+            GameBoard::new(grid)
         }
     }
 
@@ -599,8 +957,8 @@ pub mod game_board_test {
         while !game_board.finished() {
             for solver in &solvers {
                 if let Some(solution) = solver.solve(&game_board) {
-                    for action in solution.actions {
-                        match &action {
+                    for action in &solution.actions {
+                        match action {
                             Confirmation(confirmation_details) => {
                                 let ConfirmationDetails { x, y, target } = confirmation_details;
                                 assert_eq!(game_board.get_answer(*x, *y), *target);
@@ -612,14 +970,377 @@ pub mod game_board_test {
                                 }
                             }
                         }
-                        game_board.execute_action(action);
                     }
+                    // executed as a whole, so that the caches are refreshed before the next solver
+                    game_board.execute_solution(solution);
+                    assert_caches_fresh(&game_board);
                     break;
                 }
             }
         }
     }
 
+    #[test]
+    fn test_all_steps() {
+        let game_board = GameBoard::from_string(
+            "..68532..2.36...1..........6.......2..59.47..3.......8..........2...63.7..47829..",
+        );
+        let mut answer = game_board.clone();
+        assert!(dlx_solver::DLXSolver::solve_sudoku(&mut answer).is_ok());
+        let groups = game_board.all_steps();
+        assert!(groups.iter().any(|group| group.solutions.len() > 1));
+        let positions: Vec<_> = groups
+            .iter()
+            .map(|group| {
+                SolverIdentifier::ALL
+                    .iter()
+                    .position(|&solver_id| solver_id == group.solver_id)
+                    .unwrap()
+            })
+            .collect();
+        assert!(positions.windows(2).all(|pair| pair[0] < pair[1]));
+        for StepGroup {
+            solver_id,
+            solutions,
+        } in groups
+        {
+            assert!(!solver_id.is_guess());
+            let first = SolverEnum::from(solver_id).solve(&game_board).unwrap();
+            assert_eq!(
+                format!("{:?}", solutions[0].actions),
+                format!("{:?}", first.actions)
+            );
+            let mut keys: Vec<_> = solutions
+                .iter()
+                .map(|solution| {
+                    let mut actions: Vec<_> = solution
+                        .actions
+                        .iter()
+                        .map(|action| format!("{action:?}"))
+                        .collect();
+                    actions.sort();
+                    actions
+                })
+                .collect();
+            keys.sort();
+            keys.dedup();
+            assert_eq!(
+                keys.len(),
+                solutions.len(),
+                "{solver_id:?} has duplicated steps"
+            );
+            for action in solutions.iter().flat_map(|solution| &solution.actions) {
+                match action {
+                    Confirmation(ConfirmationDetails { x, y, target }) => {
+                        assert_eq!(answer.get_answer(*x, *y), *target);
+                    }
+                    Elimination(EliminationDetails { x, y, target }) => {
+                        assert!(!target.contains(answer.get_answer(*x, *y)));
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_next_step_config() {
+        let game_board = GameBoard::from_string(
+            "..68532..2.36...1..........6.......2..59.47..3.......8..........2...63.7..47829..",
+        );
+        let mut config = SolverConfig::default();
+        let step = game_board.next_step(&config).unwrap();
+        assert_eq!(step.solver_id, SolverIdentifier::HiddenSingle);
+        for setting in &mut config.solvers {
+            if setting.solver_id == SolverIdentifier::HiddenSingle {
+                setting.enabled = false;
+            }
+        }
+        let step = game_board.next_step(&config).unwrap();
+        assert_ne!(step.solver_id, SolverIdentifier::HiddenSingle);
+        for setting in &mut config.solvers {
+            setting.enabled = false;
+        }
+        assert!(game_board.next_step(&config).is_none());
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_verification() {
+        let mut game_board = GameBoard::from_string(
+            "..68532..2.36...1..........6.......2..59.47..3.......8..........2...63.7..47829..",
+        );
+        let wrong_step = |game_board: &GameBoard, solver_id| {
+            let target = game_board.get_answer(0, 0);
+            Solution {
+                actions: vec![Elimination(EliminationDetails {
+                    x: 0,
+                    y: 0,
+                    target: BitMap::from(target),
+                })],
+                house_clues: vec![],
+                candidate_clues: vec![],
+                solver_id,
+            }
+        };
+        // nothing is checked before the answers are known
+        game_board.execute_solution(wrong_step(&game_board, SolverIdentifier::XWing));
+        assert!(game_board.unsound_steps().is_empty());
+
+        let mut game_board = GameBoard::from_string(
+            "..68532..2.36...1..........6.......2..59.47..3.......8..........2...63.7..47829..",
+        );
+        assert!(game_board.enable_verification().is_ok());
+        let step = game_board.next_step_by(&easy::get_easy_solvers()).unwrap();
+        game_board.execute_solution(step);
+        assert!(game_board.unsound_steps().is_empty());
+        let before = serde_json::to_value(&game_board).unwrap();
+        game_board.execute_solution(wrong_step(&game_board, SolverIdentifier::XWing));
+        let [unsound] = game_board.unsound_steps() else {
+            panic!("the wrong step isn't recorded")
+        };
+        assert_eq!(unsound.solution.solver_id, SolverIdentifier::XWing);
+        assert_eq!(serde_json::to_value(&unsound.board).unwrap(), before);
+        assert!(game_board.clone().unsound_steps().is_empty());
+        assert!(
+            GameBoard::from_string(&".".repeat(81))
+                .enable_verification()
+                .is_err()
+        );
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    fn test_verification_corpus() {
+        let config = SolverConfig::default();
+        let solvers = config.enabled_solvers();
+        let mut tiers = BTreeSet::new();
+        for puzzle in include_str!("../tests/puzzles.txt").lines() {
+            let mut game_board = GameBoard::from_string(puzzle);
+            assert!(game_board.enable_verification().is_ok());
+            while let Some(step) = game_board.next_step_by(&solvers) {
+                tiers.insert(config.setting(step.solver_id).unwrap().tier);
+                game_board.execute_solution(step);
+                if game_board.finished() {
+                    break;
+                }
+            }
+            assert!(game_board.finished(), "{puzzle} isn't finished");
+            let unsound: Vec<_> = game_board
+                .unsound_steps()
+                .iter()
+                .map(|unsound| {
+                    format!(
+                        "{:?} {:?}\n{}",
+                        unsound.solution.solver_id,
+                        unsound.solution.actions,
+                        serde_json::to_string(&unsound.board).unwrap()
+                    )
+                })
+                .collect();
+            assert!(
+                unsound.is_empty(),
+                "{puzzle} has unsound steps:\n{}",
+                unsound.join("\n")
+            );
+        }
+
+        // the puzzles needing a forcing chain take minutes to play in a debug build,
+        // the extreme tier is checked on a position reached from one of them
+        let mut game_board = GameBoard::from_array(EXTREME_POSITION);
+        assert!(game_board.enable_verification().is_ok());
+        let step = game_board.next_step_by(&solvers).unwrap();
+        tiers.insert(config.setting(step.solver_id).unwrap().tier);
+        game_board.execute_solution(step);
+        assert!(game_board.unsound_steps().is_empty());
+
+        for tier in [Tier::Easy, Tier::Medium, Tier::Hard, Tier::Extreme] {
+            assert!(tiers.contains(&tier), "no {tier:?} step in the corpus");
+        }
+    }
+
+    #[test]
+    fn test_rate() {
+        let config = SolverConfig::default();
+        let rating = GameBoard::from_string(
+            "..68532..2.36...1..........6.......2..59.47..3.......8..........2...63.7..47829..",
+        )
+        .rate(&config)
+        .unwrap();
+        assert_eq!(rating.tier, Tier::Easy);
+        assert_eq!(
+            rating.score,
+            rating.steps as u32 * Tier::Easy.default_score()
+        );
+
+        let game_board = GameBoard::from_string(
+            "4.3...2..6..47...9.5.8..3.1....9......6.....32...5..8..1.7....2.........7....5..6",
+        );
+        let mut easy_only = config.clone();
+        for setting in &mut easy_only.solvers {
+            setting.enabled = setting.tier == Tier::Easy;
+        }
+        assert!(game_board.rate(&easy_only).is_none());
+        let rating = game_board.rate(&config).unwrap();
+        assert_eq!(rating.tier, Tier::Medium);
+        assert!(rating.score > rating.steps as u32 * Tier::Easy.default_score());
+    }
+
+    #[test]
+    fn test_cancel() {
+        let config = SolverConfig::default();
+        let mut game_board = GameBoard::from_string(
+            "4.3...2..6..47...9.5.8..3.1....9......6.....32...5..8..1.7....2.........7....5..6",
+        );
+        let reports = std::sync::Arc::new(std::sync::Mutex::new(Vec::new()));
+        let sink = reports.clone();
+        let token = CancelToken::new().with_progress(move |done, total| {
+            sink.lock().unwrap().push((done, total));
+        });
+        game_board.set_cancel_token(token.clone());
+        assert!(game_board.rate(&config).is_some());
+        assert_eq!(reports.lock().unwrap().last(), Some(&(81, 81)));
+
+        token.cancel();
+        assert!(game_board.clone().cancelled());
+        assert!(game_board.rate(&config).is_none());
+        assert!(game_board.next_step(&config).is_none());
+        assert!(game_board.all_steps().is_empty());
+        assert!(SolvePath::record(&game_board).steps.is_empty());
+        assert!(matches!(
+            DLXSolver::solve_sudoku(&mut game_board),
+            Err(DLXSolution::Cancelled)
+        ));
+
+        let mut game_board = GameBoard::from_string(
+            "4.3...2..6..47...9.5.8..3.1....9......6.....32...5..8..1.7....2.........7....5..6",
+        );
+        game_board.set_cancel_token(CancelToken::new().with_timeout(std::time::Duration::ZERO));
+        assert!(game_board.all_steps().is_empty());
+
+        // cancelled once the first solver is done, the steps of the second one are dropped
+        let token = CancelToken::new();
+        let stopper = token.clone();
+        let token = token.with_progress(move |done, _| {
+            if done == 1 {
+                stopper.cancel();
+            }
+        });
+        game_board.set_cancel_token(token.clone());
+        let first = SolverIdentifier::ALL[0];
+        assert!(!first.is_guess());
+        let steps = game_board.all_steps();
+        assert!(steps.len() <= 1 && steps.iter().all(|group| group.solver_id == first));
+        assert!(token.interrupted());
+    }
+
+    // the candidate index and the incrementally updated caches match the ones calculated from scratch
+    fn assert_caches_fresh(game_board: &GameBoard) {
+        for target in 0..9 {
+            let cells: CellSet = Coord::all_cells()
+                .filter(|&(x, y)| game_board.contains_candidate(x, y, target))
+                .collect();
+            assert_eq!(game_board.candidate_cells(target), cells);
+            for (dim, id) in (0..3).flat_map(|dim| (0..9).map(move |id| (dim, id))) {
+                let house = House::from_dim_id(dim, id);
+                let positions: BitMap = (0..9)
+                    .filter(|&index| {
+                        let (x, y) = house.ith_cell(index);
+                        cells.contains(x, y)
+                    })
+                    .collect();
+                assert!(game_board.candidate_positions(&house, target) == positions);
+            }
+        }
+        assert!(*game_board.occupied() == game_board.calculate_occupied());
+        assert!(*game_board.hard_links() == game_board.calculate_hard_link());
+        for (house_type, house_id) in (0..3).flat_map(|dim| (0..9).map(move |id| (dim, id))) {
+            assert!(
+                game_board.get_als_by_house(house_type, house_id)
+                    == game_board.calculate_als(house_type, house_id)
+            );
+        }
+    }
+
+    #[test]
+    fn test_refresh() {
+        let solvers = SolverConfig::default().enabled_solvers();
+        for puzzle in include_str!("../tests/puzzles.txt").lines() {
+            let mut game_board = GameBoard::from_string(puzzle);
+            assert_caches_fresh(&game_board);
+            while let Some(step) = game_board.next_step_by(&solvers) {
+                game_board.execute_solution(step);
+                assert_caches_fresh(&game_board);
+                if game_board.finished() {
+                    break;
+                }
+            }
+        }
+
+        let mut game_board = GameBoard::from_string(
+            "4.3...2..6..47...9.5.8..3.1....9......6.....32...5..8..1.7....2.........7....5..6",
+        );
+        assert_caches_fresh(&game_board);
+        game_board.set_pen_mark(0, 1, 8);
+        assert_caches_fresh(&game_board);
+        game_board.erase_pencil_mark(0, 3, 0);
+        game_board.erase_pencil_mark(0, 3, 4);
+        assert_caches_fresh(&game_board);
+        game_board.add_pencil_mark(0, 3, 0);
+        assert_caches_fresh(&game_board);
+        game_board.erase_pen_mark(0, 1);
+        assert_caches_fresh(&game_board);
+        // a cache calculated after some edits is kept up to date as well
+        let mut game_board = game_board.clone();
+        game_board.set_pen_mark(8, 8, 5);
+        let _ = game_board.hard_links();
+        game_board.erase_pen_mark(8, 8);
+        assert_caches_fresh(&game_board);
+    }
+
+    #[test]
+    #[ignore = "benchmark, run with --release --ignored --nocapture"]
+    fn bench_refresh() {
+        let paths: Vec<_> = include_str!("../tests/puzzles.txt")
+            .lines()
+            .map(|puzzle| SolvePath::record(&GameBoard::from_string(puzzle)))
+            .collect();
+        // replay the solve paths and ask for every cache after each step,
+        // cloning drops the caches as flushing them after every edit did
+        for (name, flush) in [("flush", true), ("incremental", false)] {
+            let start = std::time::Instant::now();
+            for _ in 0..5 {
+                for path in &paths {
+                    let mut board = path.board_at(0).unwrap().clone();
+                    for traced in &path.steps {
+                        board.execute_solution(traced.solution.clone());
+                        if flush {
+                            board = board.clone();
+                        }
+                        let _ = (board.occupied(), board.hard_links(), board.als().count());
+                    }
+                }
+            }
+            println!("{name}: {:?} per corpus", start.elapsed() / 5);
+        }
+    }
+
+    #[test]
+    #[ignore = "benchmark, run with --release --ignored --nocapture"]
+    fn bench_rate() {
+        let config = SolverConfig::default();
+        let puzzles: Vec<_> = include_str!("../tests/puzzles.txt")
+            .lines()
+            .map(GameBoard::from_string)
+            .collect();
+        let start = std::time::Instant::now();
+        for _ in 0..5 {
+            for game_board in &puzzles {
+                assert!(game_board.rate(&config).is_some());
+            }
+        }
+        println!("rate: {:?} per corpus", start.elapsed() / 5);
+    }
+
     #[test]
     fn test_als() {
         let game_board = GameBoard::from_array([
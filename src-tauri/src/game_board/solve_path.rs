@@ -0,0 +1,150 @@
+use serde::Serialize;
+
+use crate::solvers::{
+    SolverIdentifier, easy::get_easy_solvers, hard::get_hard_solvers, medium::get_medium_solvers,
+    solution::Solution, solver_enum::SolverEnum,
+};
+
+use super::GameBoard;
+
+/// A step of a solve path, with the board it was found on
+#[derive(Serialize)]
+pub struct TracedStep {
+    pub board: GameBoard,
+    pub solution: Solution,
+}
+
+/// The steps taken by the easy, medium and hard solvers until they finish the board or get stuck,
+/// kept to scrub through the path in the UI and to detect regressions when a solver changes
+#[derive(Serialize)]
+pub struct SolvePath {
+    pub steps: Vec<TracedStep>,
+    /// The board after the last step
+    pub end: GameBoard,
+}
+
+impl SolvePath {
+    /// Record the solve path of the board with the easy, medium and hard solvers
+    pub fn record(game_board: &GameBoard) -> Self {
+        let mut solvers = get_easy_solvers();
+        solvers.extend(get_medium_solvers());
+        solvers.extend(get_hard_solvers());
+        Self::record_by(game_board, &solvers)
+    }
+
+    /// Record the solve path of the board with the given solvers, tried in order at every step,
+    /// until they get stuck or the search is cancelled. The progress is reported as the number of filled cells.
+    pub(crate) fn record_by(game_board: &GameBoard, solvers: &[SolverEnum]) -> Self {
+        let mut board = game_board.clone();
+        let mut steps = Vec::new();
+        // every step removes at least one of the 729 candidates
+        while !board.finished() && steps.len() < 81 * 9 {
+            let Some(solution) = board.next_step_by(solvers) else {
+                break;
+            };
+            let before = board.clone();
+            board.execute_solution(solution.clone());
+            steps.push(TracedStep {
+                board: before,
+                solution,
+            });
+            board.cancel_token().report_progress(board.filled_cnt(), 81);
+        }
+        Self { steps, end: board }
+    }
+
+    /// Returns true if the solvers finished the board
+    pub fn finished(&self) -> bool {
+        self.end.finished()
+    }
+
+    /// The board before the given step, or the end board right after the last step
+    pub fn board_at(&self, step: usize) -> Option<&GameBoard> {
+        match self.steps.get(step) {
+            Some(traced) => Some(&traced.board),
+            None => (step == self.steps.len()).then_some(&self.end),
+        }
+    }
+
+    /// The solver of each step, in order
+    pub fn solver_ids(&self) -> Vec<SolverIdentifier> {
+        self.steps
+            .iter()
+            .map(|traced| traced.solution.solver_id)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod solve_path_test {
+    use super::*;
+    use crate::solvers::Solver;
+
+    #[test]
+    fn record_and_replay() {
+        let game_board = GameBoard::from_string(
+            "4.3...2..6..47...9.5.8..3.1....9......6.....32...5..8..1.7....2.........7....5..6",
+        );
+        let path = SolvePath::record(&game_board);
+        assert!(path.finished());
+        assert!(
+            path.board_at(path.steps.len())
+                .is_some_and(GameBoard::finished)
+        );
+        assert!(path.board_at(path.steps.len() + 1).is_none());
+        // replaying every step from its snapshot finds the same step and leads to the next snapshot
+        for (i, traced) in path.steps.iter().enumerate() {
+            let replayed = SolverEnum::from(traced.solution.solver_id)
+                .solve(&traced.board)
+                .unwrap();
+            assert_eq!(
+                serde_json::to_value(&replayed).unwrap(),
+                serde_json::to_value(&traced.solution).unwrap()
+            );
+            let mut board = traced.board.clone();
+            board.execute_solution(replayed);
+            assert_eq!(
+                serde_json::to_value(&board).unwrap(),
+                serde_json::to_value(path.board_at(i + 1).unwrap()).unwrap()
+            );
+        }
+    }
+
+    #[test]
+    fn solver_snapshot() {
+        let game_board = GameBoard::from_string(
+            "4.3...2..6..47...9.5.8..3.1....9......6.....32...5..8..1.7....2.........7....5..6",
+        );
+        let solver_ids = SolvePath::record(&game_board).solver_ids();
+        assert_eq!(solver_ids.len(), 61);
+        // the techniques used between the singles
+        let techniques: Vec<_> = solver_ids
+            .into_iter()
+            .filter(|&solver_id| {
+                solver_id != SolverIdentifier::NakedSingle
+                    && solver_id != SolverIdentifier::HiddenSingle
+            })
+            .collect();
+        assert_eq!(
+            techniques,
+            [
+                SolverIdentifier::Pointing,
+                SolverIdentifier::NakedPair,
+                SolverIdentifier::Skyscraper
+            ]
+        );
+    }
+
+    #[test]
+    fn stuck_path() {
+        let game_board = GameBoard::from_string(
+            "4.3...2..6..47...9.5.8..3.1....9......6.....32...5..8..1.7....2.........7....5..6",
+        );
+        let path = SolvePath::record_by(&game_board, &get_easy_solvers());
+        assert!(!path.finished());
+        assert!(!path.steps.is_empty());
+        let json = serde_json::to_value(&path).unwrap();
+        assert_eq!(json["steps"].as_array().unwrap().len(), path.steps.len());
+        assert_eq!(json["end"]["grid"].as_array().unwrap().len(), 9);
+    }
+}
@@ -1,13 +1,28 @@
 #![allow(unused_variables)] // 禁用本文件中所有 unused_variables 警告
 #![allow(dead_code)]
 
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::Mutex;
+use std::time::Duration;
+
+use serde::Serialize;
+use tauri::{Emitter, Manager};
+
+use utils::CancelToken;
+
 mod game_board;
 mod solvers;
 mod utils;
 
+// used by the batch grading tool
+pub use game_board::{GameBoard, batch};
+pub use solvers::SolverConfig;
+
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
+        .manage(Searches::default())
         .setup(|app| {
             if cfg!(debug_assertions) {
                 app.handle().plugin(
@@ -18,17 +33,216 @@ pub fn run() {
             }
             Ok(())
         })
-        .invoke_handler(tauri::generate_handler![generate])
+        .invoke_handler(tauri::generate_handler![
+            generate,
+            all_steps,
+            get_solver_config,
+            set_solver_config,
+            rate,
+            solve_path,
+            solve,
+            cancel_search
+        ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
+/// Generates a puzzle of the difficulty on a worker, tracked and cancelled like the other searches
+#[tauri::command]
+async fn generate(
+    app: tauri::AppHandle,
+    difficulty: i32,
+    search_id: u32,
+    timeout_ms: Option<u64>,
+) -> Result<String, String> {
+    run_search(app, search_id, timeout_ms, move |token| {
+        let cell = game_board::Cell::Printed(3);
+        format!("Generate function called with difficulty {difficulty}")
+    })
+    .await
+}
+
+/// Builds the board sent by the frontend, one raw value per cell
+fn board_from_raw(board: Vec<u16>) -> Result<game_board::GameBoard, String> {
+    let board: [u16; 81] = board
+        .try_into()
+        .map_err(|board: Vec<u16>| format!("expected 81 cells, got {}", board.len()))?;
+    Ok(game_board::GameBoard::from_array(board))
+}
+
+/// The cancel tokens of the searches running on the workers, by the id chosen by the frontend
+#[derive(Default)]
+struct Searches(Mutex<HashMap<u32, CancelToken>>);
+
+/// Emitted as "search-progress" while a search is running
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SearchProgress {
+    search_id: u32,
+    done: usize,
+    total: usize,
+}
+
+/// Emitted as "search-finished" once a search returns, `cancelled` if it stopped early
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct SearchFinished {
+    search_id: u32,
+    cancelled: bool,
+}
+
+/// Runs the search on a worker thread to keep the command thread responsive.
+/// It gives up once `cancel_search` is called with its id or after `timeout_ms`,
+/// and returns an error instead of its incomplete result.
+/// A search finishing before the cancellation or the deadline returns its result.
+async fn run_search<T: Send + 'static>(
+    app: tauri::AppHandle,
+    search_id: u32,
+    timeout_ms: Option<u64>,
+    search: impl FnOnce(CancelToken) -> T + Send + 'static,
+) -> Result<T, String> {
+    let progress_app = app.clone();
+    let mut token = CancelToken::new().with_progress(move |done, total| {
+        let progress = SearchProgress {
+            search_id,
+            done,
+            total,
+        };
+        if let Err(err) = progress_app.emit("search-progress", progress) {
+            log::warn!("failed to emit the search progress: {err}");
+        }
+    });
+    if let Some(timeout_ms) = timeout_ms {
+        token = token.with_timeout(Duration::from_millis(timeout_ms));
+    }
+    let searches = app.state::<Searches>();
+    searches
+        .0
+        .lock()
+        .map_err(|err| err.to_string())?
+        .insert(search_id, token.clone());
+    let worker_token = token.clone();
+    let res = tauri::async_runtime::spawn_blocking(move || search(worker_token)).await;
+    searches
+        .0
+        .lock()
+        .map_err(|err| err.to_string())?
+        .remove(&search_id);
+    let finished = SearchFinished {
+        search_id,
+        cancelled: token.interrupted(),
+    };
+    app.emit("search-finished", finished.clone())
+        .map_err(|err| err.to_string())?;
+    if finished.cancelled {
+        return Err("cancelled".to_string());
+    }
+    res.map_err(|err| err.to_string())
+}
+
+/// Cancels the running search with the given id, does nothing if it has already finished
+#[tauri::command]
+fn cancel_search(searches: tauri::State<'_, Searches>, search_id: u32) -> Result<(), String> {
+    let searches = searches.0.lock().map_err(|err| err.to_string())?;
+    if let Some(token) = searches.get(&search_id) {
+        token.cancel();
+    }
+    Ok(())
+}
+
+/// Lists every step found on the board, grouped by solver, for the "show all steps" view
+#[tauri::command]
+async fn all_steps(
+    app: tauri::AppHandle,
+    board: Vec<u16>,
+    search_id: u32,
+    timeout_ms: Option<u64>,
+) -> Result<Vec<game_board::StepGroup>, String> {
+    let mut board = board_from_raw(board)?;
+    run_search(app, search_id, timeout_ms, move |token| {
+        board.set_cancel_token(token);
+        board.all_steps()
+    })
+    .await
+}
+
+/// Solves the board with DLX, returns the answer of each cell in row-major order, from 1 to 9
+#[tauri::command]
+async fn solve(
+    app: tauri::AppHandle,
+    board: Vec<u16>,
+    search_id: u32,
+    timeout_ms: Option<u64>,
+) -> Result<Vec<usize>, String> {
+    let mut board = board_from_raw(board)?;
+    run_search(app, search_id, timeout_ms, move |token| {
+        board.set_cancel_token(token);
+        match game_board::dlx_solver::DLXSolver::solve_sudoku(&mut board) {
+            Ok(()) => Ok((0..81)
+                .map(|index| board.get_answer(index / 9, index % 9) + 1)
+                .collect()),
+            Err(game_board::dlx_solver::dlx_solution::DLXSolution::MultipleSolutions) => {
+                Err("the board has multiple solutions".to_string())
+            }
+            Err(_) => Err("the board has no solution".to_string()),
+        }
+    })
+    .await?
+}
+
+/// The solver configuration is saved in the app data directory
+fn solver_config_path(app: &tauri::AppHandle) -> Result<PathBuf, String> {
+    app.path()
+        .app_data_dir()
+        .map(|dir| dir.join("solver_config.json"))
+        .map_err(|err| err.to_string())
+}
+
+#[tauri::command]
+fn get_solver_config(app: tauri::AppHandle) -> Result<solvers::SolverConfig, String> {
+    solvers::SolverConfig::load(&solver_config_path(&app)?)
+}
+
+#[tauri::command]
+fn set_solver_config(app: tauri::AppHandle, config: solvers::SolverConfig) -> Result<(), String> {
+    config.normalized().save(&solver_config_path(&app)?)
+}
+
+/// Rates the board with the saved solver configuration, None if the enabled solvers can't finish it
+#[tauri::command]
+async fn rate(
+    app: tauri::AppHandle,
+    board: Vec<u16>,
+    search_id: u32,
+    timeout_ms: Option<u64>,
+) -> Result<Option<game_board::Rating>, String> {
+    let config = solvers::SolverConfig::load(&solver_config_path(&app)?)?;
+    let mut board = board_from_raw(board)?;
+    run_search(app, search_id, timeout_ms, move |token| {
+        board.set_cancel_token(token);
+        board.rate(&config)
+    })
+    .await
+}
+
+/// Records the steps of the easy, medium and hard solvers with the board before each of them,
+/// for scrubbing through the solve path
 #[tauri::command]
-fn generate(difficulty: i32) -> String {
-    let cell = game_board::Cell::Printed(3);
-    format!("Generate function called with difficulty {difficulty}")
+async fn solve_path(
+    app: tauri::AppHandle,
+    board: Vec<u16>,
+    search_id: u32,
+    timeout_ms: Option<u64>,
+) -> Result<game_board::solve_path::SolvePath, String> {
+    let mut board = board_from_raw(board)?;
+    run_search(app, search_id, timeout_ms, move |token| {
+        board.set_cancel_token(token);
+        game_board::solve_path::SolvePath::record(&board)
+    })
+    .await
 }
 
 #[cfg(test)]
 pub mod tests {
     pub mod common; // 声明common模块
+    mod soundness;
 }
@@ -1,63 +1,86 @@
 use crate::game_board::GameBoard;
 
 use crate::solvers::solution::Action::Elimination;
-use crate::solvers::solution::Solution;
 use crate::solvers::solution::{Action, Candidate, EliminationDetails};
+use crate::solvers::solution::{Solution, distinct_by_actions};
 use crate::solvers::{Solver, SolverIdentifier};
 use crate::utils::{AllEqualValue, BitMap, Coord, House};
 pub struct Claiming;
 
-impl Solver for Claiming {
-    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        for i in 0..9 {
-            for target in 0..9 {
-                for line in [House::Row(i), House::Col(i)] {
-                    let mut candidate_clues = Vec::new();
-                    if let Some(box_id) = Coord::house(&line)
-                        .filter_map(|(x, y)| {
-                            if game_board.contains_candidate(x, y, target) {
-                                candidate_clues.push(Candidate {
-                                    x,
-                                    y,
-                                    candidates: BitMap::from(target),
-                                });
-                                Some(Coord::get_box_id(x, y))
-                            } else {
-                                None
-                            }
-                        })
-                        .all_equal_value()
-                    {
-                        // potential claiming found
-                        let eliminations: Vec<Action> = Coord::box_coords(box_id)
-                            .filter(|&(x, y)| !Coord::is_in_house(x, y, &line))
-                            .filter_map(|(x, y)| {
-                                if game_board.contains_candidate(x, y, target) {
-                                    Some(Elimination(EliminationDetails {
-                                        x,
-                                        y,
-                                        target: BitMap::from(target),
-                                    }))
-                                } else {
-                                    None
-                                }
-                            })
-                            .collect();
-                        if !eliminations.is_empty() {
-                            return Some(Solution {
-                                actions: eliminations,
-                                house_clues: vec![House::Box(box_id), line],
-                                candidate_clues,
-                                solver_id: self.solver_id(),
-                            });
-                        }
-                    }
+impl Claiming {
+    fn try_get_solution(
+        &self,
+        game_board: &GameBoard,
+        target: usize,
+        line: House,
+    ) -> Option<Solution> {
+        let mut candidate_clues = Vec::new();
+        if let Some(box_id) = Coord::house(&line)
+            .filter_map(|(x, y)| {
+                if game_board.contains_candidate(x, y, target) {
+                    candidate_clues.push(Candidate {
+                        x,
+                        y,
+                        candidates: BitMap::from(target),
+                    });
+                    Some(Coord::get_box_id(x, y))
+                } else {
+                    None
                 }
+            })
+            .all_equal_value()
+        {
+            // potential claiming found
+            let eliminations: Vec<Action> = Coord::box_coords(box_id)
+                .filter(|&(x, y)| !Coord::is_in_house(x, y, &line))
+                .filter_map(|(x, y)| {
+                    if game_board.contains_candidate(x, y, target) {
+                        Some(Elimination(EliminationDetails {
+                            x,
+                            y,
+                            target: BitMap::from(target),
+                        }))
+                    } else {
+                        None
+                    }
+                })
+                .collect();
+            if !eliminations.is_empty() {
+                return Some(Solution {
+                    actions: eliminations,
+                    house_clues: vec![House::Box(box_id), line],
+                    candidate_clues,
+                    solver_id: self.solver_id(),
+                });
             }
         }
         None
     }
 
+    /// Every claiming candidate with eliminations, line by line
+    fn iter_claiming<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        (0..9).flat_map(move |i| {
+            (0..9).flat_map(move |target| {
+                [House::Row(i), House::Col(i)]
+                    .into_iter()
+                    .filter_map(move |line| self.try_get_solution(game_board, target, line))
+            })
+        })
+    }
+}
+
+impl Solver for Claiming {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_claiming(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_claiming(game_board))
+    }
+
     fn solver_id(&self) -> crate::solvers::SolverIdentifier {
         SolverIdentifier::Claiming
     }
@@ -1,61 +1,50 @@
-use crate::game_board::{Cell, GameBoard};
+use crate::game_board::GameBoard;
 
-use crate::solvers::solution::{Action::Confirmation, Candidate, ConfirmationDetails, Solution};
+use crate::solvers::solution::{
+    Action::Confirmation, Candidate, ConfirmationDetails, Solution, distinct_by_actions,
+};
 use crate::solvers::{Solver, SolverIdentifier};
 use crate::utils::{BitMap, Coord, House};
 
 pub struct HiddenSingle;
 
-impl Solver for HiddenSingle {
-    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        let form_result =
-            |x: usize, y: usize, target: usize, clue: House, index: usize| -> Solution {
-                let actions = vec![Confirmation(ConfirmationDetails { x, y, target })];
-                let house_clues = vec![clue];
-                let candidate_clues = vec![Candidate {
-                    x,
-                    y,
-                    candidates: BitMap::from(target),
-                }];
-                Solution {
-                    actions,
-                    house_clues,
-                    candidate_clues,
-                    solver_id: self.solver_id(),
-                }
-            };
-        for target in 0..9 {
-            let contains_target = |(x, y): &(usize, usize)| -> bool {
-                let cell = game_board.get_cell(*x, *y);
-                if let Cell::Blank(cell) = cell {
-                    if cell.is_pen_mark() {
-                        return false;
+impl HiddenSingle {
+    /// Every house where a digit fits in a single cell, digit by digit
+    fn iter_singles<'a>(&self, game_board: &'a GameBoard) -> impl Iterator<Item = Solution> + 'a {
+        let solver_id = self.solver_id();
+        (0..9).flat_map(move |target| {
+            (0..9)
+                .flat_map(|i| [House::Row(i), House::Col(i), House::Box(i)])
+                .filter_map(move |clue| {
+                    let filtered = Coord::house(&clue)
+                        .filter(|&(x, y)| game_board.contains_candidate(x, y, target))
+                        .collect::<Vec<_>>();
+                    if filtered.len() != 1 {
+                        return None;
                     }
-                    return cell.get_candidates().contains(target);
-                }
-                false
-            };
-            for i in 0..9 {
-                let filtered = Coord::row(i).filter(contains_target).collect::<Vec<_>>();
-                if filtered.len() == 1 {
-                    let (x, y) = filtered[0];
-                    return Some(form_result(x, y, target, House::Row(i), 0));
-                }
-                let filtered = Coord::col(i).filter(contains_target).collect::<Vec<_>>();
-                if filtered.len() == 1 {
                     let (x, y) = filtered[0];
-                    return Some(form_result(x, y, target, House::Col(i), 0));
-                }
-                let filtered = Coord::box_coords(i)
-                    .filter(contains_target)
-                    .collect::<Vec<_>>();
-                if filtered.len() == 1 {
-                    let (x, y) = filtered[0];
-                    return Some(form_result(x, y, target, House::Box(i), 0));
-                }
-            }
-        }
-        None
+                    Some(Solution {
+                        actions: vec![Confirmation(ConfirmationDetails { x, y, target })],
+                        house_clues: vec![clue],
+                        candidate_clues: vec![Candidate {
+                            x,
+                            y,
+                            candidates: BitMap::from(target),
+                        }],
+                        solver_id,
+                    })
+                })
+        })
+    }
+}
+
+impl Solver for HiddenSingle {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_singles(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_singles(game_board))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
@@ -66,6 +55,7 @@ impl Solver for HiddenSingle {
 #[cfg(test)]
 mod hidden_single_test {
     use super::*;
+    use assert_matches::assert_matches;
 
     #[test]
     fn naked_single_found_test() {
@@ -100,6 +90,26 @@ mod hidden_single_test {
         assert_eq!(candidates.get_raw(), 8)
     }
 
+    #[test]
+    fn hidden_single_all_test() {
+        let board = GameBoard::from_string(
+            ".7.9..8633..78.294..9...1754...........637...........17.....4....1.49..7624..8.19",
+        );
+        let hidden_single_solver = HiddenSingle;
+        let solutions = hidden_single_solver.solve_all(&board);
+        // a digit alone in its row and in its box is listed once
+        assert!(solutions.len() < hidden_single_solver.iter_singles(&board).count());
+        assert!(solutions.len() > 1);
+        assert_matches!(
+            solutions[0].actions[..],
+            [Confirmation(ConfirmationDetails {
+                x: 0,
+                y: 5,
+                target: 3
+            })]
+        );
+    }
+
     #[test]
     fn naked_single_no_solution_test() {
         let board = GameBoard::from_string(
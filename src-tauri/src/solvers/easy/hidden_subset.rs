@@ -1,85 +1,96 @@
 use std::vec;
 
 use crate::game_board::{Cell, GameBoard};
-use crate::solvers::solution::{Action, Candidate, EliminationDetails, Solution};
+use crate::solvers::solution::{
+    Action, Candidate, EliminationDetails, Solution, distinct_by_actions,
+};
 use crate::solvers::{Solver, SolverIdentifier};
 use crate::utils::House::{Box, Col, Row};
-use crate::utils::{BitMap, Coord};
+use crate::utils::{BitMap, Coord, House};
 
-fn solve_hidden_subset(
+/// Every hidden subset of n candidates with eliminations, house by house
+fn iter_hidden_subsets(
     n: usize,
     game_board: &GameBoard,
     solver_id: SolverIdentifier,
+) -> impl Iterator<Item = Solution> + '_ {
+    (0..9)
+        .flat_map(|i| [Box(i), Row(i), Col(i)])
+        .flat_map(move |clue| {
+            BitMap::get_combinations(n)
+                .filter_map(move |combo| try_hidden_subset(n, game_board, clue, combo, solver_id))
+        })
+}
+
+fn try_hidden_subset(
+    n: usize,
+    game_board: &GameBoard,
+    clue: House,
+    combo: BitMap,
+    solver_id: SolverIdentifier,
 ) -> Option<Solution> {
-    for i in 0..9 {
-        for clue in [Box(i), Row(i), Col(i)] {
-            let combos = BitMap::get_combinations(n);
-            'combo: for combo in combos {
-                //combo 是可能的candidates
-                // 把所有包括combo的格子的加进来
-                let mut action_cells = BitMap::new();
-                for index in 0..9 {
-                    let (x, y) = Coord::from_house_and_index(&clue, index);
-                    match game_board.get_cell(x, y) {
-                        Cell::Printed(num) => {
-                            if combo.contains(*num) {
-                                continue 'combo;
-                            }
-                        }
-                        Cell::Blank(blank_cell) => {
-                            if let Some(num) = blank_cell.get_pen_mark() {
-                                if combo.contains(num) {
-                                    continue 'combo;
-                                }
-                            } else {
-                                let candidates = blank_cell.get_candidates();
-                                if candidates.intersect(combo).count() != 0 {
-                                    action_cells.insert(index);
-                                }
-                            }
-                        }
-                    }
+    //combo 是可能的candidates
+    // 把所有包括combo的格子的加进来
+    let mut action_cells = BitMap::new();
+    for index in 0..9 {
+        let (x, y) = Coord::from_house_and_index(&clue, index);
+        match game_board.get_cell(x, y) {
+            Cell::Printed(num) => {
+                if combo.contains(*num) {
+                    return None;
                 }
-                if action_cells.count() == n {
-                    let mut eliminations: Vec<Action> = Vec::new();
-                    let mut candidate_clues: Vec<Candidate> = Vec::new();
-                    for i in (0..9).filter(|x| action_cells.contains(*x)) {
-                        let (x, y) = Coord::from_house_and_index(&clue, i);
-                        let mut include = BitMap::new();
-                        let mut exclude = BitMap::new();
-                        if let Some(cell_candidates) = game_board.get_candidates(x, y) {
-                            for candidate in (0..9).filter(|x| cell_candidates.contains(*x)) {
-                                if combo.contains(candidate) {
-                                    include.insert(candidate);
-                                } else {
-                                    exclude.insert(candidate);
-                                }
-                            }
-                        }
-                        if exclude.count() != 0 {
-                            eliminations.push(Action::Elimination(EliminationDetails {
-                                x,
-                                y,
-                                target: exclude,
-                            }));
-                        }
-                        candidate_clues.push(Candidate {
-                            x,
-                            y,
-                            candidates: include,
-                        });
+            }
+            Cell::Blank(blank_cell) => {
+                if let Some(num) = blank_cell.get_pen_mark() {
+                    if combo.contains(num) {
+                        return None;
                     }
-
-                    if !eliminations.is_empty() {
-                        return Some(Solution {
-                            actions: eliminations,
-                            house_clues: vec![clue],
-                            candidate_clues,
-                            solver_id,
-                        });
+                } else {
+                    let candidates = blank_cell.get_candidates();
+                    if candidates.intersect(combo).count() != 0 {
+                        action_cells.insert(index);
+                    }
+                }
+            }
+        }
+    }
+    if action_cells.count() == n {
+        let mut eliminations: Vec<Action> = Vec::new();
+        let mut candidate_clues: Vec<Candidate> = Vec::new();
+        for i in (0..9).filter(|x| action_cells.contains(*x)) {
+            let (x, y) = Coord::from_house_and_index(&clue, i);
+            let mut include = BitMap::new();
+            let mut exclude = BitMap::new();
+            if let Some(cell_candidates) = game_board.get_candidates(x, y) {
+                for candidate in (0..9).filter(|x| cell_candidates.contains(*x)) {
+                    if combo.contains(candidate) {
+                        include.insert(candidate);
+                    } else {
+                        exclude.insert(candidate);
                     }
                 }
             }
+            if exclude.count() != 0 {
+                eliminations.push(Action::Elimination(EliminationDetails {
+                    x,
+                    y,
+                    target: exclude,
+                }));
+            }
+            candidate_clues.push(Candidate {
+                x,
+                y,
+                candidates: include,
+            });
+        }
+
+        if !eliminations.is_empty() {
+            return Some(Solution {
+                actions: eliminations,
+                house_clues: vec![clue],
+                candidate_clues,
+                solver_id,
+            });
         }
     }
     None
@@ -87,7 +98,11 @@ fn solve_hidden_subset(
 pub struct HiddenPair;
 impl Solver for HiddenPair {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        solve_hidden_subset(2, game_board, self.solver_id())
+        iter_hidden_subsets(2, game_board, self.solver_id()).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(iter_hidden_subsets(2, game_board, self.solver_id()))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
@@ -99,7 +114,11 @@ pub struct HiddenTriple;
 
 impl Solver for HiddenTriple {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        solve_hidden_subset(3, game_board, self.solver_id())
+        iter_hidden_subsets(3, game_board, self.solver_id()).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(iter_hidden_subsets(3, game_board, self.solver_id()))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
@@ -110,7 +129,11 @@ impl Solver for HiddenTriple {
 pub struct HiddenQuadruple;
 impl Solver for HiddenQuadruple {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        solve_hidden_subset(4, game_board, self.solver_id())
+        iter_hidden_subsets(4, game_board, self.solver_id()).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(iter_hidden_subsets(4, game_board, self.solver_id()))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
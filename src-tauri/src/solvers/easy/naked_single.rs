@@ -1,37 +1,46 @@
 use crate::game_board::{Cell, GameBoard};
 
 use crate::solvers::traits::Solver;
-use crate::solvers::{solution::*, SolverIdentifier};
+use crate::solvers::{SolverIdentifier, solution::*};
+use crate::utils::Coord;
 pub struct NakedSingle;
+impl NakedSingle {
+    /// Every cell with a single candidate left, row by row
+    fn iter_singles<'a>(&self, game_board: &'a GameBoard) -> impl Iterator<Item = Solution> + 'a {
+        let solver_id = self.solver_id();
+        Coord::all_cells().filter_map(move |(row, col)| match game_board.get_cell(row, col) {
+            Cell::Blank(blank_cell)
+                if blank_cell.get_pen_mark().is_none()
+                    && blank_cell.get_candidates().count() == 1 =>
+            {
+                let candidates = blank_cell.get_candidates();
+                Some(Solution {
+                    actions: vec![Action::Confirmation(ConfirmationDetails {
+                        x: row,
+                        y: col,
+                        target: candidates.trailing_zeros(),
+                    })],
+                    house_clues: vec![],
+                    candidate_clues: vec![Candidate {
+                        x: row,
+                        y: col,
+                        candidates: *candidates,
+                    }],
+                    solver_id,
+                })
+            }
+            _ => None,
+        })
+    }
+}
+
 impl Solver for NakedSingle {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        for row in 0..9 {
-            for col in 0..9 {
-                if let Cell::Blank(blank_cell) = game_board.get_cell(row, col) {
-                    if blank_cell.get_pen_mark().is_none() {
-                        let candidates = blank_cell.get_candidates();
-                        if candidates.count() == 1 {
-                            return Some(Solution {
-                                actions: vec![Action::Confirmation(ConfirmationDetails {
-                                    x: row,
-                                    y: col,
-                                    target: candidates.trailing_zeros(),
-                                })],
-                                house_clues: vec![],
-                                candidate_clues: vec![Candidate {
-                                    x: row,
-                                    y: col,
-                                    candidates: *candidates,
-                                }],
-                                solver_id: self.solver_id(),
-                            });
-                        }
-                    }
-                }
-            }
-        }
+        self.iter_singles(game_board).next()
+    }
 
-        None
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_singles(game_board))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
@@ -1,62 +1,69 @@
 use crate::game_board::GameBoard;
-use crate::solvers::solution::{Action, Candidate, EliminationDetails, Solution};
+use crate::solvers::solution::{
+    Action, Candidate, EliminationDetails, Solution, distinct_by_actions,
+};
 use crate::solvers::{Solver, SolverIdentifier};
 use crate::utils::House::{Box, Col, Row};
-use crate::utils::{BitMap, Coord};
+use crate::utils::{BitMap, Coord, House};
 
-fn solve_naked_subset(
+/// Every naked subset of n cells with eliminations, house by house
+fn iter_naked_subsets(
     n: usize,
     game_board: &GameBoard,
     solver_id: SolverIdentifier,
+) -> impl Iterator<Item = Solution> + '_ {
+    (0..9)
+        .flat_map(|i| [Box(i), Row(i), Col(i)])
+        .flat_map(move |clue| {
+            BitMap::get_combinations(n)
+                .filter_map(move |combo| try_naked_subset(n, game_board, clue, combo, solver_id))
+        })
+}
+
+fn try_naked_subset(
+    n: usize,
+    game_board: &GameBoard,
+    clue: House,
+    combo: BitMap,
+    solver_id: SolverIdentifier,
 ) -> Option<Solution> {
-    for i in 0..9 {
-        for clue in [Box(i), Row(i), Col(i)] {
-            let combos = BitMap::get_combinations(n);
-            'combo: for combo in combos {
-                let mut eliminations: Vec<Action> = Vec::new();
-                let mut candidate_clues = Vec::new();
-                let mut candidates = BitMap::new();
-                let include = (0..9usize).filter(|x| combo.contains(*x));
-                let exclude = (0..9usize).filter(|x| !combo.contains(*x));
-
-                for index in include {
-                    let (x, y) = Coord::from_house_and_index(&clue, index);
-                    if let Some(candi) = game_board.get_candidates(x, y) {
-                        candidates = candidates.union(candi);
-                        candidate_clues.push(Candidate {
-                            x,
-                            y,
-                            candidates: candi,
-                        })
-                    } else {
-                        continue 'combo;
-                    }
-                }
-                if candidates.count() == n {
-                    for index in exclude {
-                        let (x, y) = Coord::from_house_and_index(&clue, index);
-                        if let Some(candi) = game_board.get_candidates(x, y) {
-                            let target = candidates.intersect(candi);
-                            if target.count() > 0 {
-                                eliminations.push(Action::Elimination(EliminationDetails {
-                                    x,
-                                    y,
-                                    target,
-                                }));
-                            }
-                        }
-                    }
-                    if !eliminations.is_empty() {
-                        return Some(Solution {
-                            actions: eliminations,
-                            house_clues: vec![clue],
-                            candidate_clues,
-                            solver_id,
-                        });
-                    }
+    let mut eliminations: Vec<Action> = Vec::new();
+    let mut candidate_clues = Vec::new();
+    let mut candidates = BitMap::new();
+    let include = (0..9usize).filter(|x| combo.contains(*x));
+    let exclude = (0..9usize).filter(|x| !combo.contains(*x));
+
+    for index in include {
+        let (x, y) = Coord::from_house_and_index(&clue, index);
+        if let Some(candi) = game_board.get_candidates(x, y) {
+            candidates = candidates.union(candi);
+            candidate_clues.push(Candidate {
+                x,
+                y,
+                candidates: candi,
+            })
+        } else {
+            return None;
+        }
+    }
+    if candidates.count() == n {
+        for index in exclude {
+            let (x, y) = Coord::from_house_and_index(&clue, index);
+            if let Some(candi) = game_board.get_candidates(x, y) {
+                let target = candidates.intersect(candi);
+                if target.count() > 0 {
+                    eliminations.push(Action::Elimination(EliminationDetails { x, y, target }));
                 }
             }
         }
+        if !eliminations.is_empty() {
+            return Some(Solution {
+                actions: eliminations,
+                house_clues: vec![clue],
+                candidate_clues,
+                solver_id,
+            });
+        }
     }
     None
 }
@@ -64,7 +71,11 @@ fn solve_naked_subset(
 pub struct NakedPair;
 impl Solver for NakedPair {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        solve_naked_subset(2, game_board, self.solver_id())
+        iter_naked_subsets(2, game_board, self.solver_id()).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(iter_naked_subsets(2, game_board, self.solver_id()))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
@@ -75,7 +86,11 @@ impl Solver for NakedPair {
 pub struct NakedTriple;
 impl Solver for NakedTriple {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        solve_naked_subset(3, game_board, self.solver_id())
+        iter_naked_subsets(3, game_board, self.solver_id()).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(iter_naked_subsets(3, game_board, self.solver_id()))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
@@ -86,7 +101,11 @@ impl Solver for NakedTriple {
 pub struct NakedQuadruple;
 impl Solver for NakedQuadruple {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        solve_naked_subset(4, game_board, self.solver_id())
+        iter_naked_subsets(4, game_board, self.solver_id()).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(iter_naked_subsets(4, game_board, self.solver_id()))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
@@ -1,73 +1,91 @@
 use crate::game_board::GameBoard;
 use crate::solvers::solution::candidate::Candidate;
 use crate::solvers::solution::elimination_details::EliminationDetails;
-use crate::solvers::solution::{Action, Solution};
+use crate::solvers::solution::{Action, Solution, distinct_by_actions};
 use crate::solvers::{Solver, SolverIdentifier};
 
 use crate::utils::{BitMap, Coord, House};
 pub struct Pointing;
-impl Solver for Pointing {
-    fn solve(&self, game_board: &GameBoard) -> Option<crate::solvers::solution::Solution> {
-        for box_id in 0..9 {
-            'target: for target in 0..9 {
-                // for a fixed box and target
-                let iter = Coord::box_coords(box_id);
-                let mut row = BitMap::new();
-                let mut col = BitMap::new();
-                let mut candidate_clues = Vec::new();
+impl Pointing {
+    fn try_get_solution(
+        &self,
+        game_board: &GameBoard,
+        box_id: usize,
+        target: usize,
+    ) -> Option<Solution> {
+        // for a fixed box and target
+        let iter = Coord::box_coords(box_id);
+        let mut row = BitMap::new();
+        let mut col = BitMap::new();
+        let mut candidate_clues = Vec::new();
 
-                // iterate though all cells in the box
-                for (i, j) in iter {
-                    if game_board.contains_candidate(i, j, target) {
-                        row.insert(i);
-                        col.insert(j);
-                        candidate_clues.push(Candidate {
-                            x: i,
-                            y: j,
-                            candidates: BitMap::from(target),
-                        });
-                        if row.count() > 1 && col.count() > 1 {
-                            // this target doesn't fit pointing pattern, try next one
-                            continue 'target;
-                        }
-                    }
-                }
-                //this candidate doesn't appear in the box
-                if row.count() == 0 {
-                    continue;
+        // iterate though all cells in the box
+        for (i, j) in iter {
+            if game_board.contains_candidate(i, j, target) {
+                row.insert(i);
+                col.insert(j);
+                candidate_clues.push(Candidate {
+                    x: i,
+                    y: j,
+                    candidates: BitMap::from(target),
+                });
+                if row.count() > 1 && col.count() > 1 {
+                    // this target doesn't fit pointing pattern
+                    return None;
                 }
+            }
+        }
+        //this candidate doesn't appear in the box
+        if row.count() == 0 {
+            return None;
+        }
 
-                // pointing pattern found
-                let clue = if row.count() == 1 {
-                    House::Row(row.trailing_zeros())
+        // pointing pattern found
+        let clue = if row.count() == 1 {
+            House::Row(row.trailing_zeros())
+        } else {
+            House::Col(col.trailing_zeros())
+        };
+        let eliminations: Vec<Action> = Coord::house(&clue)
+            .filter(|&(x, y)| Coord::get_box_id(x, y) != box_id)
+            .filter_map(|(x, y)| {
+                if game_board.contains_candidate(x, y, target) {
+                    Some(Action::Elimination(EliminationDetails {
+                        x,
+                        y,
+                        target: BitMap::from(target),
+                    }))
                 } else {
-                    House::Col(col.trailing_zeros())
-                };
-                let eliminations: Vec<Action> = Coord::house(&clue)
-                    .filter(|&(x, y)| Coord::get_box_id(x, y) != box_id)
-                    .filter_map(|(x, y)| {
-                        if game_board.contains_candidate(x, y, target) {
-                            Some(Action::Elimination(EliminationDetails {
-                                x,
-                                y,
-                                target: BitMap::from(target),
-                            }))
-                        } else {
-                            None
-                        }
-                    })
-                    .collect();
-                if !eliminations.is_empty() {
-                    return Some(Solution {
-                        actions: eliminations,
-                        house_clues: vec![House::Box(box_id), clue],
-                        candidate_clues,
-                        solver_id: self.solver_id(),
-                    });
+                    None
                 }
-            }
-        }
-        None
+            })
+            .collect();
+        (!eliminations.is_empty()).then(|| Solution {
+            actions: eliminations,
+            house_clues: vec![House::Box(box_id), clue],
+            candidate_clues,
+            solver_id: self.solver_id(),
+        })
+    }
+
+    /// Every pointing candidate with eliminations, box by box
+    fn iter_pointing<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        (0..9).flat_map(move |box_id| {
+            (0..9).filter_map(move |target| self.try_get_solution(game_board, box_id, target))
+        })
+    }
+}
+
+impl Solver for Pointing {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_pointing(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_pointing(game_board))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
@@ -0,0 +1,199 @@
+use crate::{
+    game_board::GameBoard,
+    solvers::{
+        Solver, SolverIdentifier,
+        implication::{Implications, Literal},
+        solution::{Candidate, Solution, distinct_by_actions},
+    },
+    utils::{Coord, House},
+};
+
+use super::{CellForcingChain, DigitForcingChain, UnitForcingChain, literal_action};
+
+/// The most links followed from each branch. Forcing chains are tried after the Kraken Fish,
+/// so they reach a bit further than its default of 8, while every candidate of the board may start a branch.
+const MAX_CHAIN_LENGTH: usize = 10;
+
+fn chain_clues(chain: Vec<Literal>) -> Vec<Candidate> {
+    chain.iter().map(Literal::as_candidate).collect()
+}
+
+fn implications_of(
+    game_board: &GameBoard,
+    branches: impl IntoIterator<Item = Literal>,
+) -> Vec<Implications> {
+    branches
+        .into_iter()
+        .map(|branch| Implications::new(game_board, branch, MAX_CHAIN_LENGTH))
+        .collect()
+}
+
+/// The literals implied by all the branches, one of which must be true
+fn common_consequences(
+    implications: &[Implications],
+    house_clues: Vec<House>,
+    solver_id: SolverIdentifier,
+) -> Vec<Solution> {
+    implications[0]
+        .iter_implied()
+        .filter(|&literal| implications[1..].iter().all(|imp| imp.implies(literal)))
+        .map(|consequence| Solution {
+            actions: vec![literal_action(consequence)],
+            house_clues: house_clues.clone(),
+            candidate_clues: implications
+                .iter()
+                .filter_map(|imp| imp.chain_to(consequence))
+                .map(chain_clues)
+                .collect::<Vec<_>>()
+                .join(&Candidate::SEPARATOR),
+            solver_id,
+        })
+        .collect()
+}
+
+impl CellForcingChain {
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        Coord::all_cells()
+            .take_while(|_| !game_board.cancelled())
+            .flat_map(move |(x, y)| {
+                let Some(candidates) = game_board.get_candidates(x, y) else {
+                    return vec![];
+                };
+                if candidates.count() < 2 {
+                    return vec![];
+                }
+                let branches = candidates
+                    .iter_ones()
+                    .map(|target| Literal::new(x, y, target, true));
+                common_consequences(
+                    &implications_of(game_board, branches),
+                    vec![],
+                    self.solver_id(),
+                )
+            })
+    }
+}
+
+impl Solver for CellForcingChain {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::CellForcingChain
+    }
+}
+
+impl UnitForcingChain {
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        (0..3)
+            .flat_map(|dim| (0..9).map(move |id| House::from_dim_id(dim, id)))
+            .take_while(|_| !game_board.cancelled())
+            .flat_map(move |house| {
+                (0..9).flat_map(move |target| {
+                    let branches: Vec<_> = Coord::house(&house)
+                        .filter(|&(x, y)| game_board.contains_candidate(x, y, target))
+                        .map(|(x, y)| Literal::new(x, y, target, true))
+                        .collect();
+                    if branches.len() < 2 {
+                        return vec![];
+                    }
+                    common_consequences(
+                        &implications_of(game_board, branches),
+                        vec![house],
+                        self.solver_id(),
+                    )
+                })
+            })
+    }
+}
+
+impl Solver for UnitForcingChain {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::UnitForcingChain
+    }
+}
+
+/// The branch implying a literal to be both true and false is false
+fn try_get_contradiction(
+    implications: &Implications,
+    branch: Literal,
+    solver_id: SolverIdentifier,
+) -> Option<Solution> {
+    let contradiction = implications.contradiction()?;
+    Some(Solution {
+        actions: vec![literal_action(branch.negate())],
+        house_clues: vec![],
+        candidate_clues: [contradiction, contradiction.negate()]
+            .into_iter()
+            .filter_map(|end| implications.chain_to(end))
+            .map(chain_clues)
+            .collect::<Vec<_>>()
+            .join(&Candidate::SEPARATOR),
+        solver_id,
+    })
+}
+
+impl DigitForcingChain {
+    /// The contradictions first, then the common consequences of the candidate being true and false
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        Coord::all_cells()
+            .take_while(|_| !game_board.cancelled())
+            .flat_map(move |(x, y)| {
+                (0..9)
+                    .filter(move |&target| game_board.contains_candidate(x, y, target))
+                    .flat_map(move |target| {
+                        let on = Literal::new(x, y, target, true);
+                        let implications = implications_of(game_board, [on, on.negate()]);
+                        let mut solutions: Vec<_> =
+                            [(on, &implications[0]), (on.negate(), &implications[1])]
+                                .into_iter()
+                                .filter_map(|(branch, imp)| {
+                                    try_get_contradiction(imp, branch, self.solver_id())
+                                })
+                                .collect();
+                        solutions.extend(common_consequences(
+                            &implications,
+                            vec![],
+                            self.solver_id(),
+                        ));
+                        solutions
+                    })
+            })
+    }
+}
+
+impl Solver for DigitForcingChain {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::DigitForcingChain
+    }
+}
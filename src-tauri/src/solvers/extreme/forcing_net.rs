@@ -0,0 +1,238 @@
+use crate::{
+    game_board::{Cell, GameBoard},
+    solvers::{
+        Solver, SolverIdentifier,
+        implication::Literal,
+        solution::{Candidate, Solution, distinct_by_actions},
+    },
+    utils::{BitMap, Coord, House},
+};
+
+use super::{ForcingNet, literal_action};
+
+/// The rounds of singles filled in each branch. A round fills every single found at once,
+/// so six rounds reach deep into the board while the clues of a branch stay short enough to follow.
+const MAX_NET_ROUNDS: usize = 6;
+
+/// A copy of the candidates where singles are filled under an assumption
+struct NetBranch {
+    assumption: Literal,
+    values: [[Option<usize>; 9]; 9],
+    candidates: [[BitMap; 9]; 9],
+    filled: Vec<(usize, usize, usize)>,
+    contradicted: bool,
+}
+
+impl NetBranch {
+    fn new(game_board: &GameBoard, assumption: Literal) -> Self {
+        let mut values = [[None; 9]; 9];
+        let mut candidates = [[BitMap::new(); 9]; 9];
+        for (x, y) in Coord::all_cells() {
+            match game_board.get_cell(x, y) {
+                Cell::Printed(num) => values[x][y] = Some(*num),
+                Cell::Blank(cell) => {
+                    values[x][y] = cell.get_pen_mark();
+                    candidates[x][y] = game_board.get_candidates(x, y).unwrap_or_default();
+                }
+            }
+        }
+        let mut res = Self {
+            assumption,
+            values,
+            candidates,
+            filled: vec![],
+            contradicted: false,
+        };
+        let Literal { x, y, target, on } = assumption;
+        if on {
+            res.fill(x, y, target);
+        } else {
+            res.remove(x, y, target);
+        }
+        res.propagate();
+        res
+    }
+
+    fn remove(&mut self, x: usize, y: usize, target: usize) {
+        self.candidates[x][y].remove(target);
+        if self.values[x][y].is_none() && self.candidates[x][y].count() == 0 {
+            self.contradicted = true;
+        }
+    }
+
+    fn fill(&mut self, x: usize, y: usize, target: usize) {
+        if self.values[x][y].is_some() || !self.candidates[x][y].contains(target) {
+            self.contradicted = true;
+            return;
+        }
+        self.values[x][y] = Some(target);
+        self.candidates[x][y] = BitMap::new();
+        self.filled.push((x, y, target));
+        for (sx, sy) in Coord::seeable_cells(x, y) {
+            self.remove(sx, sy, target);
+        }
+    }
+
+    /// All the naked singles and hidden singles, marks the contradiction if a house has no place for a candidate
+    fn find_singles(&mut self) -> Vec<(usize, usize, usize)> {
+        let mut singles: Vec<_> = Coord::all_cells()
+            .filter(|&(x, y)| self.candidates[x][y].count() == 1)
+            .map(|(x, y)| (x, y, self.candidates[x][y].trailing_zeros()))
+            .collect();
+        for house in (0..3).flat_map(|dim| (0..9).map(move |id| House::from_dim_id(dim, id))) {
+            for target in 0..9 {
+                if Coord::house(&house).any(|(x, y)| self.values[x][y] == Some(target)) {
+                    continue;
+                }
+                let places: Vec<_> = Coord::house(&house)
+                    .filter(|&(x, y)| self.candidates[x][y].contains(target))
+                    .collect();
+                match places[..] {
+                    [] => self.contradicted = true,
+                    [(x, y)] if !singles.contains(&(x, y, target)) => singles.push((x, y, target)),
+                    _ => (),
+                }
+            }
+        }
+        singles
+    }
+
+    fn propagate(&mut self) {
+        for _ in 0..MAX_NET_ROUNDS {
+            if self.contradicted {
+                return;
+            }
+            let singles = self.find_singles();
+            if singles.is_empty() || self.contradicted {
+                return;
+            }
+            for (x, y, target) in singles {
+                self.fill(x, y, target);
+                if self.contradicted {
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Returns true if the candidate is true (`on`) or false in this branch
+    fn holds(&self, literal: Literal) -> bool {
+        let Literal { x, y, target, on } = literal;
+        if on {
+            self.values[x][y] == Some(target)
+        } else {
+            self.values[x][y].is_some_and(|value| value != target)
+                || (self.values[x][y].is_none() && !self.candidates[x][y].contains(target))
+        }
+    }
+
+    fn clues(&self) -> Vec<Candidate> {
+        std::iter::once(self.assumption.as_candidate())
+            .chain(
+                self.filled
+                    .iter()
+                    .filter(|&&(x, y, target)| Literal::new(x, y, target, true) != self.assumption)
+                    .map(|&(x, y, target)| Candidate::new_single(x, y, target)),
+            )
+            .collect()
+    }
+}
+
+/// Fills the singles in all the branches, the consequences in all the non-contradicted branches hold
+fn try_get_solution(
+    game_board: &GameBoard,
+    branches: &[Literal],
+    house_clues: Vec<House>,
+    solver_id: SolverIdentifier,
+) -> Option<Solution> {
+    let branches: Vec<_> = branches
+        .iter()
+        .map(|&assumption| NetBranch::new(game_board, assumption))
+        .collect();
+    let valid: Vec<_> = branches
+        .iter()
+        .filter(|branch| !branch.contradicted)
+        .collect();
+    if valid.is_empty() {
+        return None;
+    }
+    let consequences = |on: bool| -> Vec<Literal> {
+        Coord::all_cells()
+            .flat_map(|(x, y)| {
+                game_board
+                    .get_candidates(x, y)
+                    .unwrap_or_default()
+                    .iter_ones()
+                    .map(move |target| Literal::new(x, y, target, on))
+                    .collect::<Vec<_>>()
+            })
+            .filter(|&literal| valid.iter().all(|branch| branch.holds(literal)))
+            .collect()
+    };
+    let mut actions = consequences(true);
+    if actions.is_empty() {
+        actions = consequences(false);
+    }
+    (!actions.is_empty()).then(|| Solution {
+        actions: actions.into_iter().map(literal_action).collect(),
+        house_clues,
+        candidate_clues: branches
+            .iter()
+            .map(NetBranch::clues)
+            .collect::<Vec<_>>()
+            .join(&Candidate::SEPARATOR),
+        solver_id,
+    })
+}
+
+impl ForcingNet {
+    /// The nets of the cells first, then the ones of the houses
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        let cell_nets = Coord::all_cells()
+            .take_while(|_| !game_board.cancelled())
+            .filter_map(move |(x, y)| {
+                let candidates = game_board.get_candidates(x, y)?;
+                if candidates.count() < 2 {
+                    return None;
+                }
+                let branches: Vec<_> = candidates
+                    .iter_ones()
+                    .map(|target| Literal::new(x, y, target, true))
+                    .collect();
+                try_get_solution(game_board, &branches, vec![], self.solver_id())
+            });
+        let house_nets = (0..3)
+            .flat_map(|dim| (0..9).map(move |id| House::from_dim_id(dim, id)))
+            .take_while(|_| !game_board.cancelled())
+            .flat_map(move |house| {
+                (0..9).filter_map(move |target| {
+                    let branches: Vec<_> = Coord::house(&house)
+                        .filter(|&(x, y)| game_board.contains_candidate(x, y, target))
+                        .map(|(x, y)| Literal::new(x, y, target, true))
+                        .collect();
+                    if branches.len() < 2 {
+                        return None;
+                    }
+                    try_get_solution(game_board, &branches, vec![house], self.solver_id())
+                })
+            });
+        cell_nets.chain(house_nets)
+    }
+}
+
+impl Solver for ForcingNet {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::ForcingNet
+    }
+}
@@ -0,0 +1,288 @@
+use crate::solvers::{
+    implication::Literal,
+    solution::{Action, ConfirmationDetails, EliminationDetails},
+    solver_enum::SolverEnum,
+};
+use crate::utils::BitMap;
+
+mod forcing_chain;
+mod forcing_net;
+
+/// [HoDoKu explanations on Forcing Chains](https://hodoku.sourceforge.net/en/tech_chains.php)
+/// ## Terminology
+/// - Every candidate of a single cell is assumed to be true in turn, each assumption is called a **Branch**.
+/// - From each branch, a chain of implications is followed. Each link flips between true and false:
+///   a true candidate turns off the other candidates in its cell and its peers,
+///   a false candidate turns on the other candidate of a bi-value cell, and the other end of a hard link.
+/// - One of the branches must be true, so a candidate implied to be true (or false) by all the branches
+///   is true (or false) as well.
+/// ## Return Format
+/// - **Actions**: Contains 1 element, representing the common consequence.
+/// - **House Clues**: An empty vector.
+/// - **Candidate Clues**: Contains 1 group of elements per branch, seperated by the Candidate::SEPARATOR,
+///   each representing the chain from the branch to the consequence.
+///   A true candidate is represented by its own mask, a false one by the complement mask.
+pub struct CellForcingChain;
+
+/// [HoDoKu explanations on Forcing Chains](https://hodoku.sourceforge.net/en/tech_chains.php)
+///
+/// The [`CellForcingChain`] whose branches are all the appearances of a candidate in a single house.
+/// Follows the same terminology and return format, except that the **House Clues** contains 1 element,
+/// representing the house.
+pub struct UnitForcingChain;
+
+/// [HoDoKu explanations on Forcing Chains](https://hodoku.sourceforge.net/en/tech_chains.php)
+/// ## Terminology
+/// - A single candidate is assumed to be true and false in turn, following the implications of the [`CellForcingChain`].
+/// - If the candidate being true (or false) implies another candidate to be both true and false, it's a **Contradiction**,
+///   and the candidate must be false (or true).
+/// - Otherwise, a candidate implied to be true (or false) by both branches is true (or false) as well.
+/// ## Return Format
+/// - **Actions**: Contains 1 element, representing the candidate itself on contradiction, or the common consequence.
+/// - **House Clues**: An empty vector.
+/// - **Candidate Clues**: Contains 2 groups of elements, seperated by the Candidate::SEPARATOR:
+///     - On contradiction, the chains to the other candidate being true and being false
+///     - Otherwise, the chains from the candidate being true and being false to the consequence
+pub struct DigitForcingChain;
+
+/// [HoDoKu explanations on Forcing Nets](https://hodoku.sourceforge.net/en/tech_chains.php)
+/// ## Terminology
+/// - The branches of a [`CellForcingChain`] or a [`UnitForcingChain`], but instead of a single chain,
+///   all the naked and hidden singles are filled in each branch for a limited number of rounds.
+/// - A branch leading to a cell or a house where nothing can be filled in is a **Contradiction**,
+///   and only the other branches are considered.
+/// - The candidates filled (or removed) in all the remaining branches are true (or false).
+/// ## Return Format
+/// - **Actions**: Contains variable number of elements, representing the common confirmations if any,
+///   otherwise the common eliminations.
+/// - **House Clues**: Contains the house of the branches, or an empty vector for a cell.
+/// - **Candidate Clues**: Contains 1 group of elements per branch, seperated by the Candidate::SEPARATOR,
+///   each representing the assumption followed by the filled candidates in order.
+pub struct ForcingNet;
+
+/// The action turning the candidate into the literal
+fn literal_action(literal: Literal) -> Action {
+    let Literal { x, y, target, on } = literal;
+    if on {
+        Action::Confirmation(ConfirmationDetails { x, y, target })
+    } else {
+        Action::Elimination(EliminationDetails {
+            x,
+            y,
+            target: BitMap::from(target),
+        })
+    }
+}
+
+#[rustfmt::skip]
+pub fn get_extreme_solvers() -> Vec<SolverEnum> {
+    vec![
+        SolverEnum::from(CellForcingChain ),
+        SolverEnum::from(UnitForcingChain ),
+        SolverEnum::from(DigitForcingChain),
+        SolverEnum::from(ForcingNet       ),
+    ]
+}
+
+#[cfg(test)]
+mod extreme_test {
+    use super::*;
+    use crate::game_board::GameBoard;
+    use crate::solvers::{Solver, SolverIdentifier};
+    use crate::tests::common::test_function_e;
+    use crate::utils::House::Row;
+
+    const UNIT_FORCING_CHAIN_BOARD: [u16; 81] = [
+        584, 740, 558, 256, 746, 746, 513, 560, 688, 16, 744, 554, 736, 746, 1, 674, 768, 4, 513,
+        672, 768, 528, 674, 4, 674, 8, 64, 584, 2, 524, 740, 740, 680, 528, 1, 256, 32, 844, 1,
+        580, 16, 776, 588, 128, 2, 128, 836, 16, 581, 591, 778, 620, 612, 552, 516, 561, 128, 8,
+        609, 624, 256, 514, 560, 768, 568, 64, 2, 676, 688, 684, 564, 1, 2, 569, 552, 677, 256,
+        688, 748, 628, 696,
+    ];
+
+    #[test]
+    fn test_cell_forcing_chain() {
+        test_function_e(
+            CellForcingChain,
+            [
+                554, 522, 16, 768, 525, 545, 64, 128, 548, 872, 776, 864, 2, 524, 128, 1, 528, 556,
+                1, 4, 640, 576, 16, 552, 802, 810, 808, 606, 667, 583, 664, 546, 256, 672, 613,
+                609, 774, 899, 775, 644, 546, 64, 8, 805, 16, 860, 32, 836, 668, 521, 537, 896,
+                836, 2, 128, 530, 550, 1, 256, 540, 562, 618, 584, 816, 785, 801, 536, 576, 2, 4,
+                808, 640, 790, 64, 8, 32, 640, 532, 786, 771, 769,
+            ],
+            vec![(6, 2)], //exp_actions
+            vec![2],      //exp_action_targets
+            vec![],       //exp_house_clues
+            vec![
+                (6, 1),
+                (6, 2),
+                (0, 0),
+                (6, 1),
+                (6, 6),
+                (8, 6),
+                (8, 5),
+                (8, 5),
+                (8, 0),
+                (6, 2),
+                (6, 2),
+            ], //exp_candidate_clues
+            vec![2, 509, 65535, 16, 495, 16, 495, 4, 507, 4, 509], //exp_candidate_masks
+        );
+    }
+    #[test]
+    fn test_unit_forcing_chain() {
+        test_function_e(
+            UnitForcingChain,
+            UNIT_FORCING_CHAIN_BOARD,
+            vec![(8, 1)], //exp_actions
+            vec![8],      //exp_action_targets
+            vec![Row(4)], //exp_house_clues
+            vec![
+                (4, 1),
+                (8, 1),
+                (0, 0),
+                (4, 5),
+                (4, 5),
+                (5, 5),
+                (5, 5),
+                (5, 4),
+                (5, 4),
+                (5, 3),
+                (8, 3),
+                (8, 1),
+                (8, 1),
+                (0, 0),
+                (4, 6),
+                (7, 6),
+                (7, 1),
+                (8, 1),
+            ], //exp_candidate_clues
+            vec![
+                8, 503, 65535, 8, 255, 256, 509, 2, 510, 1, 510, 1, 503, 65535, 8, 503, 8, 503,
+            ], //exp_candidate_masks
+        );
+    }
+    #[test]
+    fn test_unit_forcing_chain_all() {
+        let game_board = GameBoard::from_array(UNIT_FORCING_CHAIN_BOARD);
+        let solutions = UnitForcingChain.solve_all(&game_board);
+        assert!(solutions.len() > 1);
+        assert_eq!(
+            format!("{:?}", solutions[0].actions),
+            format!("{:?}", UnitForcingChain.solve(&game_board).unwrap().actions)
+        );
+        assert!(
+            solutions
+                .iter()
+                .all(|solution| solution.solver_id == SolverIdentifier::UnitForcingChain)
+        );
+    }
+    #[test]
+    fn test_digit_forcing_chain() {
+        test_function_e(
+            DigitForcingChain,
+            [
+                621, 256, 589, 625, 760, 600, 2, 716, 717, 128, 524, 589, 768, 585, 514, 580, 32,
+                16, 16, 552, 514, 609, 745, 4, 704, 256, 713, 806, 692, 916, 598, 594, 1, 8, 708,
+                996, 556, 64, 660, 532, 256, 536, 676, 513, 2, 782, 1, 780, 128, 586, 32, 16, 580,
+                836, 837, 2, 917, 8, 625, 592, 996, 724, 740, 584, 664, 728, 626, 4, 256, 1, 602,
+                744, 845, 540, 32, 595, 595, 128, 836, 606, 588,
+            ],
+            vec![(7, 1)], //exp_actions
+            vec![8],      //exp_action_targets
+            vec![],       //exp_house_clues
+            vec![
+                (1, 1),
+                (1, 6),
+                (1, 6),
+                (2, 6),
+                (2, 6),
+                (4, 6),
+                (4, 2),
+                (3, 1),
+                (7, 1),
+                (7, 1),
+                (0, 0),
+                (1, 1),
+                (1, 1),
+                (7, 1),
+            ], //exp_candidate_clues
+            vec![
+                4, 507, 64, 447, 128, 383, 128, 383, 128, 503, 65535, 507, 8, 503,
+            ], //exp_candidate_masks
+        );
+    }
+    #[test]
+    fn test_forcing_net() {
+        test_function_e(
+            ForcingNet,
+            [
+                528, 4, 128, 577, 584, 777, 2, 617, 809, 768, 579, 577, 16, 32, 527, 716, 717, 524,
+                8, 579, 544, 711, 710, 775, 836, 16, 773, 2, 897, 769, 8, 16, 548, 676, 645, 64,
+                576, 32, 520, 647, 646, 519, 660, 256, 533, 641, 528, 4, 673, 256, 64, 680, 514,
+                553, 4, 8, 592, 256, 1, 640, 624, 608, 2, 672, 896, 848, 614, 590, 574, 1, 588,
+                796, 545, 833, 2, 612, 588, 572, 860, 588, 128,
+            ],
+            vec![(0, 5)], //exp_actions
+            vec![8],      //exp_action_targets
+            vec![],       //exp_house_clues
+            vec![
+                (0, 5),
+                (0, 3),
+                (0, 8),
+                (2, 5),
+                (0, 4),
+                (0, 7),
+                (8, 6),
+                (5, 8),
+                (6, 7),
+                (3, 5),
+                (5, 6),
+                (6, 6),
+                (8, 5),
+                (6, 2),
+                (8, 7),
+                (7, 5),
+                (4, 6),
+                (7, 8),
+                (7, 7),
+                (1, 8),
+                (3, 6),
+                (0, 0),
+                (0, 5),
+                (0, 4),
+                (0, 8),
+                (2, 5),
+                (0, 3),
+                (0, 7),
+                (8, 6),
+                (5, 8),
+                (5, 3),
+                (6, 7),
+                (3, 5),
+                (5, 0),
+                (5, 6),
+                (6, 6),
+                (8, 5),
+                (4, 5),
+                (3, 2),
+                (6, 2),
+                (8, 0),
+                (2, 4),
+                (3, 7),
+                (4, 6),
+                (8, 1),
+                (7, 0),
+                (1, 2),
+                (0, 0),
+                (0, 5),
+            ], //exp_candidate_clues
+            vec![
+                1, 64, 256, 256, 8, 32, 256, 32, 64, 32, 8, 32, 16, 16, 8, 8, 16, 16, 4, 8, 4,
+                65535, 8, 64, 256, 256, 1, 32, 256, 32, 128, 64, 32, 1, 8, 32, 16, 1, 256, 16, 32,
+                128, 1, 128, 1, 128, 1, 65535, 256,
+            ], //exp_candidate_masks
+        );
+    }
+}
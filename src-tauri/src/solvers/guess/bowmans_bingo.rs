@@ -0,0 +1,196 @@
+use crate::{
+    game_board::GameBoard,
+    solvers::{
+        Solver, SolverIdentifier,
+        easy::get_easy_solvers,
+        solution::{
+            Action, Candidate, ConfirmationDetails, EliminationDetails, Solution,
+            distinct_by_actions,
+        },
+    },
+    utils::{BitMap, Coord},
+};
+
+/// [HoDoKu explanations on Bowman's Bingo](https://hodoku.sourceforge.net/en/tech_last.php)
+/// ## Terminology
+/// - A candidate is assumed to be true, and the easy solvers are applied on a copy of the board
+///   until they stall. This is a guess rather than a logical deduction.
+/// - If the board reaches a **Contradiction**, i.e. an unsolved cell has no candidate left or
+///   a number has no place in a house, the assumed candidate is false.
+/// - The candidates are tried cell by cell, starting from the cells with the fewest candidates.
+///   The expected answer is never looked at.
+/// ## Return Format
+/// - **Actions**: Contains 1 element, representing the assumed candidate.
+/// - **House Clues**: An empty vector.
+/// - **Candidate Clues**: Contains variable number of elements, representing the assumed candidate
+///   followed by the candidates confirmed by the easy solvers in order.
+pub struct BowmansBingo;
+
+/// The easy steps played on the copy before the assumption is given up. The easy solvers fill a board
+/// in far fewer steps, so the limit only guards against a step that changes nothing.
+const MAX_TRIAL_STEPS: usize = 200;
+
+/// Returns the confirmed candidates in order if assuming the candidate leads to a contradiction
+fn try_find_contradiction(
+    game_board: &GameBoard,
+    x: usize,
+    y: usize,
+    target: usize,
+) -> Option<Vec<(usize, usize, usize)>> {
+    let mut board = game_board.clone();
+    board.set_pen_mark(x, y, target);
+    let solvers = get_easy_solvers();
+    let mut confirmed = vec![];
+    for _ in 0..MAX_TRIAL_STEPS {
+        if board.has_contradiction() {
+            return Some(confirmed);
+        }
+        let step = board.next_step_by(&solvers)?;
+        confirmed.extend(step.actions.iter().filter_map(|action| match action {
+            Action::Confirmation(ConfirmationDetails { x, y, target }) => Some((*x, *y, *target)),
+            Action::Elimination(_) => None,
+        }));
+        board.execute_solution(step);
+    }
+    None
+}
+
+impl BowmansBingo {
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        let mut cells: Vec<_> = Coord::all_cells()
+            .filter_map(|(x, y)| Some((x, y, game_board.get_candidates(x, y)?)))
+            .filter(|(_, _, candidates)| candidates.count() >= 2)
+            .collect();
+        cells.sort_by_key(|(_, _, candidates)| candidates.count());
+        cells
+            .into_iter()
+            .take_while(|_| !game_board.cancelled())
+            .flat_map(move |(x, y, candidates)| {
+                (0..9)
+                    .filter(move |&target| candidates.contains(target))
+                    .filter_map(move |target| {
+                        let confirmed = try_find_contradiction(game_board, x, y, target)?;
+                        Some(Solution {
+                            actions: vec![Action::Elimination(EliminationDetails {
+                                x,
+                                y,
+                                target: BitMap::from(target),
+                            })],
+                            house_clues: vec![],
+                            candidate_clues: std::iter::once((x, y, target))
+                                .chain(confirmed)
+                                .map(|(cx, cy, ct)| Candidate::new_single(cx, cy, ct))
+                                .collect(),
+                            solver_id: self.solver_id(),
+                        })
+                    })
+            })
+    }
+}
+
+impl Solver for BowmansBingo {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::BowmansBingo
+    }
+}
+
+#[cfg(test)]
+mod bowmans_bingo_test {
+    use super::*;
+    use crate::game_board::dlx_solver::DLXSolver;
+    use crate::solvers::solver_enum::SolverEnum;
+    use crate::tests::common::test_function_e;
+
+    #[test]
+    fn test_bowmans_bingo() {
+        test_function_e(
+            BowmansBingo,
+            [
+                528, 4, 128, 577, 584, 777, 2, 617, 809, 768, 579, 577, 16, 32, 527, 716, 717, 524,
+                8, 579, 544, 711, 710, 775, 836, 16, 773, 2, 897, 769, 8, 16, 548, 676, 645, 64,
+                576, 32, 520, 647, 646, 519, 660, 256, 533, 641, 528, 4, 673, 256, 64, 680, 514,
+                553, 4, 8, 592, 256, 1, 640, 624, 608, 2, 672, 896, 848, 614, 590, 574, 1, 588,
+                796, 545, 833, 2, 612, 588, 572, 860, 588, 128,
+            ],
+            vec![(3, 5)], //exp_actions
+            vec![4],      //exp_action_targets
+            vec![],       //exp_house_clues
+            vec![
+                (3, 5),
+                (3, 6),
+                (5, 3),
+                (6, 7),
+                (0, 8),
+                (0, 5),
+                (1, 5),
+                (0, 4),
+                (0, 3),
+                (0, 7),
+                (1, 8),
+                (2, 5),
+                (4, 5),
+                (4, 8),
+                (4, 6),
+                (1, 1),
+                (6, 6),
+                (6, 2),
+                (1, 2),
+                (1, 7),
+                (1, 6),
+                (2, 1),
+                (2, 6),
+                (2, 8),
+                (3, 2),
+                (3, 7),
+                (3, 1),
+                (5, 0),
+                (5, 8),
+                (5, 6),
+                (7, 1),
+            ], //exp_candidate_clues
+            vec![
+                4, 32, 32, 32, 32, 256, 8, 64, 1, 8, 4, 2, 1, 16, 4, 2, 16, 64, 1, 128, 64, 64,
+                256, 1, 256, 1, 128, 1, 8, 128, 256,
+            ], //exp_candidate_masks
+        );
+    }
+
+    /// The expected answer is only used to verify every step
+    #[test]
+    fn test_bowmans_bingo_answers() {
+        let mut game_board = GameBoard::from_string(
+            ".38...2.....56....4......5.2..45...7.6.....9...3.97...34.91...2......1....2.....8",
+        );
+        assert!(DLXSolver::solve_sudoku(&mut game_board).is_ok());
+        let mut solvers = get_easy_solvers();
+        solvers.push(SolverEnum::from(BowmansBingo));
+        while !game_board.finished() {
+            let solution = solvers
+                .iter()
+                .find_map(|solver| solver.solve(&game_board))
+                .expect("guessing never stalls");
+            for action in &solution.actions {
+                match action {
+                    Action::Confirmation(ConfirmationDetails { x, y, target }) => {
+                        assert_eq!(game_board.get_answer(*x, *y), *target);
+                    }
+                    Action::Elimination(EliminationDetails { x, y, target }) => {
+                        assert!(!target.contains(game_board.get_answer(*x, *y)));
+                    }
+                }
+            }
+            game_board.execute_solution(solution);
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use crate::solvers::solver_enum::SolverEnum;
+
+mod bowmans_bingo;
+pub(super) use bowmans_bingo::BowmansBingo;
+
+#[rustfmt::skip]
+pub fn get_guess_solvers() -> Vec<SolverEnum> {
+    vec![
+        SolverEnum::from(BowmansBingo),
+    ]
+}
@@ -0,0 +1,287 @@
+use std::collections::HashSet;
+
+use crate::{
+    game_board::{GameBoard, als::Als},
+    solvers::{
+        Solver, SolverIdentifier,
+        solution::{
+            Action, Candidate, EliminationDetails, Solution, Visitor, distinct_visited,
+            first_visited,
+        },
+    },
+    utils::{BitMap, CellSet, Coord},
+};
+
+/// [SudokuWiki explanations on Aligned Pair Exclusion](https://www.sudokuwiki.org/Aligned_Pair_Exclusion)
+/// ## Terminology
+/// - The n (2 for pair, 3 for triple) unsolved cells are called the **Base Cells**, and the cells seeing all of them are their **Common Peers**.
+/// - A **Combination** assigns a candidate to each base cell. It's invalid if two base cells seeing each other take the same candidate,
+///   or if it takes 2 candidates of an ALS (including a bi-value cell) lying in the common peers, which would leave the ALS short of a candidate.
+/// - The candidates of a base cell which appear in no valid combination are eliminated.
+/// ## Return Format
+/// - **Actions**: Contains variable number of elements, representing the eliminated candidates in each base cell.
+/// - **House Clues**: An empty vector.
+/// - **Candidate Clues**: Contains groups of elements, seperated by the Candidate::SEPARATOR:
+///     - The base cells with all their candidates
+///     - For each ALS excluding a combination, its cells with all their candidates
+pub struct AlignedPairExclusion;
+
+/// [SudokuWiki explanations on Aligned Triple Exclusion](https://www.sudokuwiki.org/Aligned_Triple_Exclusion)
+///
+/// The [`AlignedPairExclusion`] with 3 base cells. Follows the same terminology and return format.
+pub struct AlignedTripleExclusion;
+
+struct AlsMask<'a> {
+    als: &'a Als,
+    cells: CellSet,
+}
+
+struct AlignedExclusion<'a> {
+    game_board: &'a GameBoard,
+    n: usize,
+    solver_id: SolverIdentifier,
+    unsolved: Vec<(usize, usize, BitMap)>,
+    als_list: Vec<AlsMask<'a>>,
+}
+
+impl<'a> AlignedExclusion<'a> {
+    fn new(game_board: &'a GameBoard, n: usize, solver_id: SolverIdentifier) -> Self {
+        // an ALS inside the intersection of a box and a line appears in both houses
+        let mut seen = HashSet::new();
+        let als_list = game_board
+            .als()
+            .map(|als| AlsMask {
+                als,
+                cells: als.cells().collect(),
+            })
+            .filter(|als| seen.insert(als.cells))
+            .collect();
+        Self {
+            game_board,
+            n,
+            solver_id,
+            unsolved: Coord::all_cells()
+                .filter_map(|(x, y)| Some((x, y, game_board.get_candidates(x, y)?)))
+                .collect(),
+            als_list,
+        }
+    }
+
+    /// Depth first search of the base cells in ascending order, returns true once the visitor stops it
+    fn search(&self, base: &mut Vec<usize>, common_peers: CellSet, visit: Visitor) -> bool {
+        if base.len() == self.n {
+            return self
+                .try_get_solution(base, common_peers)
+                .is_some_and(&mut *visit);
+        }
+        if self.game_board.cancelled() {
+            return true;
+        }
+        let start = base.last().map_or(0, |&last| last + 1);
+        for next in start..self.unsolved.len() {
+            let (x, y, _) = self.unsolved[next];
+            let common_peers = common_peers.intersect(CellSet::peers(x, y));
+            if common_peers.is_empty() {
+                continue;
+            }
+            base.push(next);
+            let stopped = self.search(base, common_peers, visit);
+            base.pop();
+            if stopped {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn try_get_solution(&self, base: &[usize], common_peers: CellSet) -> Option<Solution> {
+        let cells: Vec<_> = base.iter().map(|&index| self.unsolved[index]).collect();
+        let als_list: Vec<_> = self
+            .als_list
+            .iter()
+            .filter(|als| als.cells.is_subset(common_peers))
+            .collect();
+        if als_list.is_empty() {
+            return None;
+        }
+        let mut surviving = vec![BitMap::new(); cells.len()];
+        let mut used = vec![false; als_list.len()];
+        let mut combo = vec![];
+        Self::for_each_combination(&cells, &mut combo, &mut |combo| {
+            let taken: BitMap = combo.iter().copied().collect();
+            let excluding = als_list
+                .iter()
+                .position(|als| als.als.candidates().intersect(taken).count() >= 2);
+            match excluding {
+                Some(index) => used[index] = true,
+                None => combo
+                    .iter()
+                    .zip(surviving.iter_mut())
+                    .for_each(|(&target, survived)| survived.insert(target)),
+            }
+        });
+        // no valid combination at all means the board is broken
+        if surviving.iter().all(|survived| survived.count() == 0) {
+            return None;
+        }
+        let actions: Vec<_> = cells
+            .iter()
+            .zip(&surviving)
+            .filter_map(|(&(x, y, candidates), survived)| {
+                let target = candidates.difference(*survived);
+                (target.count() > 0).then_some(Action::Elimination(EliminationDetails {
+                    x,
+                    y,
+                    target,
+                }))
+            })
+            .collect();
+        (!actions.is_empty()).then(|| Solution {
+            actions,
+            house_clues: vec![],
+            candidate_clues: std::iter::once(
+                cells
+                    .iter()
+                    .map(|&(x, y, candidates)| Candidate::new(x, y, candidates))
+                    .collect::<Vec<_>>(),
+            )
+            .chain(
+                als_list
+                    .iter()
+                    .zip(&used)
+                    .filter(|&(_, &used)| used)
+                    .map(|(als, _)| {
+                        als.als
+                            .cells()
+                            .map(|(x, y)| {
+                                Candidate::new(
+                                    x,
+                                    y,
+                                    self.game_board
+                                        .get_candidates(x, y)
+                                        .expect("ALS cells are unsolved"),
+                                )
+                            })
+                            .collect()
+                    }),
+            )
+            .collect::<Vec<_>>()
+            .join(&Candidate::SEPARATOR),
+            solver_id: self.solver_id,
+        })
+    }
+
+    /// Calls `visit` on every combination where base cells seeing each other take different candidates
+    fn for_each_combination(
+        cells: &[(usize, usize, BitMap)],
+        combo: &mut Vec<usize>,
+        visit: &mut impl FnMut(&[usize]),
+    ) {
+        let Some(&(x, y, candidates)) = cells.get(combo.len()) else {
+            visit(combo);
+            return;
+        };
+        for target in candidates.iter_ones() {
+            let conflict = combo
+                .iter()
+                .zip(cells)
+                .any(|(&other, &(ox, oy, _))| other == target && Coord::sees(x, y, ox, oy));
+            if !conflict {
+                combo.push(target);
+                Self::for_each_combination(cells, combo, visit);
+                combo.pop();
+            }
+        }
+    }
+}
+
+fn search_aligned_exclusion(
+    game_board: &GameBoard,
+    n: usize,
+    solver_id: SolverIdentifier,
+    visit: Visitor,
+) {
+    AlignedExclusion::new(game_board, n, solver_id).search(&mut vec![], CellSet::ALL, visit);
+}
+
+impl Solver for AlignedPairExclusion {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        first_visited(|visit| search_aligned_exclusion(game_board, 2, self.solver_id(), visit))
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_visited(|visit| search_aligned_exclusion(game_board, 2, self.solver_id(), visit))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::AlignedPairExclusion
+    }
+}
+
+impl Solver for AlignedTripleExclusion {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        first_visited(|visit| search_aligned_exclusion(game_board, 3, self.solver_id(), visit))
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_visited(|visit| search_aligned_exclusion(game_board, 3, self.solver_id(), visit))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::AlignedTripleExclusion
+    }
+}
+
+#[cfg(test)]
+mod aligned_exclusion_test {
+    use super::*;
+    use crate::tests::common::test_function_e;
+
+    #[test]
+    fn test_aligned_pair_exclusion() {
+        test_function_e(
+            AlignedPairExclusion,
+            [
+                8, 2, 624, 692, 756, 708, 1, 532, 768, 4, 128, 624, 816, 1, 832, 592, 514, 520,
+                256, 529, 593, 532, 2, 520, 640, 596, 32, 128, 64, 775, 8, 772, 775, 532, 32, 533,
+                547, 801, 775, 807, 868, 16, 8, 640, 581, 16, 545, 8, 677, 740, 709, 2, 256, 581,
+                579, 537, 531, 535, 540, 32, 256, 593, 128, 547, 825, 915, 64, 920, 899, 564, 529,
+                534, 608, 4, 915, 915, 912, 899, 608, 8, 530,
+            ],
+            vec![(7, 2)], //exp_actions
+            vec![1],      //exp_action_targets
+            vec![],       //exp_house_clues
+            vec![
+                (6, 2),
+                (7, 2),
+                (0, 0),
+                (6, 0),
+                (7, 0),
+                (8, 0),
+                (0, 0),
+                (0, 2),
+                (1, 2),
+                (2, 2),
+            ], //exp_candidate_clues
+            vec![19, 403, 65535, 67, 35, 96, 65535, 112, 112, 81], //exp_candidate_masks
+        );
+    }
+    #[test]
+    fn test_aligned_triple_exclusion() {
+        test_function_e(
+            AlignedTripleExclusion,
+            [
+                560, 128, 64, 804, 774, 8, 790, 820, 1, 2, 520, 768, 16, 612, 513, 580, 612, 128,
+                560, 516, 1, 864, 834, 128, 8, 880, 850, 256, 609, 514, 520, 528, 612, 640, 517,
+                608, 4, 608, 520, 640, 1, 864, 848, 2, 880, 577, 16, 640, 868, 868, 2, 517, 8, 864,
+                520, 256, 560, 610, 640, 608, 531, 529, 4, 640, 2, 548, 1, 548, 16, 832, 832, 520,
+                577, 577, 532, 774, 520, 772, 32, 128, 530,
+            ],
+            vec![(3, 5)],                                                 //exp_actions
+            vec![64],                                                     //exp_action_targets
+            vec![],                                                       //exp_house_clues
+            vec![(3, 5), (4, 5), (7, 4), (0, 0), (6, 5), (0, 0), (8, 5)], //exp_candidate_clues
+            vec![100, 352, 36, 65535, 96, 65535, 260],                    //exp_candidate_masks
+        );
+    }
+}
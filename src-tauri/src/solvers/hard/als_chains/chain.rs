@@ -0,0 +1,167 @@
+use crate::{
+    game_board::GameBoard,
+    solvers::{
+        Solver, SolverIdentifier,
+        solution::{Candidate, Solution, Visitor, distinct_visited, first_visited},
+    },
+    utils::CellSet,
+};
+
+use super::{AlsChain, AlsNode, collect_als_nodes, eliminate_seeing_all};
+
+/// The most ALSs linked in one chain. Five covers the ALS-XZ and ALS-XY-Wing shapes with two links to spare,
+/// while the number of chains to try grows with every extra ALS.
+const MAX_CHAIN_LENGTH: usize = 5;
+
+struct ChainSearch<'a, 'b> {
+    game_board: &'a GameBoard,
+    nodes: &'b [AlsNode<'a>],
+    // links[i] : all (j, rcc) pairs where rcc is a restricted common candidate between node i and node j
+    links: Vec<Vec<(usize, usize)>>,
+}
+
+impl<'a, 'b> ChainSearch<'a, 'b> {
+    fn new(game_board: &'a GameBoard, nodes: &'b [AlsNode<'a>]) -> Self {
+        let mut links = vec![vec![]; nodes.len()];
+        for i in 0..nodes.len() {
+            for j in i + 1..nodes.len() {
+                for rcc in nodes[i].restricted_commons(&nodes[j]).iter_ones() {
+                    links[i].push((j, rcc));
+                    links[j].push((i, rcc));
+                }
+            }
+        }
+        Self {
+            game_board,
+            nodes,
+            links,
+        }
+    }
+
+    /// Depth first search for the chains with exactly `length` ALSs starting from the path,
+    /// returns true once the visitor stops it
+    fn search(
+        &self,
+        path: &mut Vec<usize>,
+        rccs: &mut Vec<usize>,
+        used_cells: CellSet,
+        length: usize,
+        solver_id: SolverIdentifier,
+        visit: Visitor,
+    ) -> bool {
+        if path.len() == length {
+            return self.visit_solutions(path, rccs, solver_id, visit);
+        }
+        let Some(&last) = path.last() else {
+            return false;
+        };
+        for &(next, rcc) in &self.links[last] {
+            if self.nodes[next].overlaps(used_cells) || rccs.last() == Some(&rcc) {
+                continue;
+            }
+            path.push(next);
+            rccs.push(rcc);
+            let stopped = self.search(
+                path,
+                rccs,
+                used_cells.union(self.nodes[next].cells),
+                length,
+                solver_id,
+                visit,
+            );
+            path.pop();
+            rccs.pop();
+            if stopped {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Visits the eliminations of every candidate shared by both ends of the chain
+    fn visit_solutions(
+        &self,
+        path: &[usize],
+        rccs: &[usize],
+        solver_id: SolverIdentifier,
+        visit: Visitor,
+    ) -> bool {
+        let (Some(&first), Some(&last), Some(&first_rcc), Some(&last_rcc)) =
+            (path.first(), path.last(), rccs.first(), rccs.last())
+        else {
+            return false;
+        };
+        let (first, last) = (&self.nodes[first], &self.nodes[last]);
+        let mut z_candidates = first.candidates().intersect(last.candidates());
+        z_candidates.remove(first_rcc);
+        z_candidates.remove(last_rcc);
+        z_candidates.iter_ones().any(|z| {
+            let z_cells: Vec<_> = first.candidate_cells[z]
+                .iter()
+                .chain(last.candidate_cells[z].iter())
+                .copied()
+                .collect();
+            let actions = eliminate_seeing_all(self.game_board, &z_cells, z);
+            !actions.is_empty()
+                && visit(Solution {
+                    actions,
+                    house_clues: path.iter().map(|&i| self.nodes[i].als.house()).collect(),
+                    candidate_clues: self.candidate_clues(path, rccs, z),
+                    solver_id,
+                })
+        })
+    }
+
+    fn candidate_clues(&self, path: &[usize], rccs: &[usize], z: usize) -> Vec<Candidate> {
+        let mut clues = vec![];
+        for (i, &node) in path.iter().enumerate() {
+            clues.extend(self.nodes[node].candidate_clues(self.game_board));
+            clues.push(Candidate::SEPARATOR);
+            if let Some(&rcc) = rccs.get(i) {
+                clues.extend(self.nodes[node].target_clues(rcc));
+                clues.extend(self.nodes[path[i + 1]].target_clues(rcc));
+                clues.push(Candidate::SEPARATOR);
+            }
+        }
+        let first = &self.nodes[path[0]];
+        let last = &self.nodes[path[path.len() - 1]];
+        clues.extend(first.target_clues(z).chain(last.target_clues(z)));
+        clues
+    }
+}
+
+impl AlsChain {
+    /// The shorter chains first
+    fn search(&self, game_board: &GameBoard, visit: Visitor) {
+        let nodes = collect_als_nodes(game_board);
+        let chain_search = ChainSearch::new(game_board, &nodes);
+        (2..=MAX_CHAIN_LENGTH).any(|length| {
+            (0..nodes.len())
+                .take_while(|_| !game_board.cancelled())
+                .any(|start| {
+                    chain_search.search(
+                        &mut vec![start],
+                        &mut vec![],
+                        nodes[start].cells,
+                        length,
+                        self.solver_id(),
+                        visit,
+                    )
+                })
+        });
+    }
+}
+
+impl Solver for AlsChain {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        first_visited(|visit| self.search(game_board, visit))
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_visited(|visit| self.search(game_board, visit))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::AlsChain
+    }
+}
@@ -0,0 +1,175 @@
+use crate::{
+    game_board::GameBoard,
+    solvers::{
+        Solver, SolverIdentifier,
+        solution::{Candidate, Solution, Visitor, distinct_visited, first_visited},
+    },
+    utils::{BitMap, CellSet, Coord},
+};
+
+use super::{AlsNode, DeathBlossom, collect_als_nodes, eliminate_seeing_all};
+
+/// The most candidates of the stem cell, each needing its own petal ALS.
+/// Larger stems need that many petals seeing a common candidate, which seldom exist, and each petal multiplies the combinations.
+const MAX_STEM_SIZE: usize = 4;
+
+struct Stem {
+    x: usize,
+    y: usize,
+    candidates: BitMap,
+}
+
+struct BlossomSearch<'a, 'b> {
+    game_board: &'a GameBoard,
+    nodes: &'b [AlsNode<'a>],
+    stem: Stem,
+    // petals[i] : all the ALSs which could be the petal of the i-th stem candidate
+    petals: Vec<Vec<usize>>,
+}
+
+impl<'a, 'b> BlossomSearch<'a, 'b> {
+    fn try_new(game_board: &'a GameBoard, nodes: &'b [AlsNode<'a>], stem: Stem) -> Option<Self> {
+        let stem_cell = CellSet::from_cell(stem.x, stem.y);
+        let petals: Vec<Vec<usize>> = stem
+            .candidates
+            .iter_ones()
+            .map(|target| {
+                nodes
+                    .iter()
+                    .enumerate()
+                    .filter(|(_, node)| {
+                        !node.overlaps(stem_cell)
+                            && node.candidates().contains(target)
+                            && node.candidates().difference(stem.candidates).count() > 0
+                            && node.candidate_cells[target]
+                                .iter()
+                                .all(|&(x, y)| Coord::sees(x, y, stem.x, stem.y))
+                    })
+                    .map(|(i, _)| i)
+                    .collect()
+            })
+            .collect();
+        petals
+            .iter()
+            .all(|petal| !petal.is_empty())
+            .then_some(Self {
+                game_board,
+                nodes,
+                stem,
+                petals,
+            })
+    }
+
+    /// Choose a petal for each stem candidate in turn, keeping track of the candidates shared by all chosen petals,
+    /// returns true once the visitor stops it
+    fn search(
+        &self,
+        chosen: &mut Vec<usize>,
+        used_cells: CellSet,
+        common: BitMap,
+        solver_id: SolverIdentifier,
+        visit: Visitor,
+    ) -> bool {
+        if chosen.len() == self.petals.len() {
+            return self.visit_solutions(chosen, common, solver_id, visit);
+        }
+        for &petal in &self.petals[chosen.len()] {
+            let node = &self.nodes[petal];
+            let next_common = common.intersect(node.candidates());
+            if node.overlaps(used_cells) || next_common.count() == 0 {
+                continue;
+            }
+            chosen.push(petal);
+            let stopped = self.search(
+                chosen,
+                used_cells.union(node.cells),
+                next_common,
+                solver_id,
+                visit,
+            );
+            chosen.pop();
+            if stopped {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Visits the eliminations of every candidate shared by all the petals
+    fn visit_solutions(
+        &self,
+        chosen: &[usize],
+        common: BitMap,
+        solver_id: SolverIdentifier,
+        visit: Visitor,
+    ) -> bool {
+        common.iter_ones().any(|z| {
+            let z_cells: Vec<_> = chosen
+                .iter()
+                .flat_map(|&petal| self.nodes[petal].candidate_cells[z].iter())
+                .copied()
+                .collect();
+            let actions = eliminate_seeing_all(self.game_board, &z_cells, z);
+            !actions.is_empty()
+                && visit(Solution {
+                    actions,
+                    house_clues: chosen
+                        .iter()
+                        .map(|&petal| self.nodes[petal].als.house())
+                        .collect(),
+                    candidate_clues: self.candidate_clues(chosen, z),
+                    solver_id,
+                })
+        })
+    }
+
+    fn candidate_clues(&self, chosen: &[usize], z: usize) -> Vec<Candidate> {
+        let mut clues = vec![
+            Candidate::new(self.stem.x, self.stem.y, self.stem.candidates),
+            Candidate::SEPARATOR,
+        ];
+        for &petal in chosen {
+            clues.extend(self.nodes[petal].candidate_clues(self.game_board));
+            clues.push(Candidate::SEPARATOR);
+        }
+        clues.extend(
+            chosen
+                .iter()
+                .flat_map(|&petal| self.nodes[petal].target_clues(z)),
+        );
+        clues
+    }
+}
+
+impl DeathBlossom {
+    fn search(&self, game_board: &GameBoard, visit: Visitor) {
+        let nodes = collect_als_nodes(game_board);
+        Coord::all_cells()
+            .filter_map(|(x, y)| {
+                game_board
+                    .get_candidates(x, y)
+                    .filter(|candidates| (2..=MAX_STEM_SIZE).contains(&candidates.count()))
+                    .map(|candidates| Stem { x, y, candidates })
+            })
+            .filter_map(|stem| BlossomSearch::try_new(game_board, &nodes, stem))
+            .take_while(|_| !game_board.cancelled())
+            .any(|blossom| {
+                let common = BitMap::all().difference(blossom.stem.candidates);
+                blossom.search(&mut vec![], CellSet::new(), common, self.solver_id(), visit)
+            });
+    }
+}
+
+impl Solver for DeathBlossom {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        first_visited(|visit| self.search(game_board, visit))
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_visited(|visit| self.search(game_board, visit))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::DeathBlossom
+    }
+}
@@ -0,0 +1,342 @@
+use std::collections::HashSet;
+
+use crate::{
+    game_board::{GameBoard, als::Als},
+    solvers::solution::{Action, Candidate, EliminationDetails},
+    utils::{BitMap, CellSet, Coord},
+};
+
+/// [HoDoKu explanations on ALS-Chain](https://hodoku.sourceforge.net/en/tech_als.php#ach)
+/// ## Terminology
+/// - The chain is a sequence of non-overlapping ALSs, denoted by A1, A2, ..., An.
+/// - Two adjacent ALSs are joined by a **Restricted Common Candidate (RCC)**: a candidate appears in
+///   both ALSs, and all its appearances in one ALS see all its appearances in the other.
+///   Two consecutive RCCs in the chain must be different candidates.
+/// - The candidate shared by A1 and An, which is neither the first nor the last RCC, is called **z**.
+///   z must be true in either A1 or An, thus all the cells seeing every z in both ends lose z.
+/// - A chain with 2 ALSs is the ALS-XZ, and a chain with 3 ALSs is the ALS-XY-Wing.
+///
+/// ## Return Format
+/// - **Actions**: Contains variable number of elements, representing z eliminated in each cell seeing all z in A1 and An.
+/// - **House Clues**: Contains n elements, representing the house of each ALS in chain order.
+/// - **Candidate Clues**: Contains 2n groups of elements, seperated by the Candidate::SEPARATOR:
+///     - The cells of A1 with all their candidates
+///     - The RCC between A1 and A2 in both ALSs
+///     - The cells of A2 with all their candidates
+///     - ...
+///     - The cells of An with all their candidates
+///     - z in A1 and An
+pub struct AlsChain;
+mod chain;
+
+/// [HoDoKu explanations on Death Blossom](https://hodoku.sourceforge.net/en/tech_als.php#db)
+/// ## Terminology
+/// - The cell whose candidates all lead to an ALS is called the **Stem**.
+/// - For each candidate of the stem, an ALS whose appearances of the candidate all see the stem is
+///   called a **Petal**. Petals are not allowed to overlap each other.
+/// - The candidate shared by all petals but not appearing in the stem is called **z**. Whichever
+///   candidate the stem takes, its petal is locked and z must be true in one of the petals.
+///
+/// ## Return Format
+/// - **Actions**: Contains variable number of elements, representing z eliminated in each cell seeing all z in the petals.
+/// - **House Clues**: Contains 1 element per petal, representing the house of each petal in the order of the stem candidates.
+/// - **Candidate Clues**: Contains groups of elements, seperated by the Candidate::SEPARATOR:
+///     - The stem with all its candidates
+///     - For each stem candidate in ascending order, the cells of its petal with all their candidates
+///     - z in all the petals
+pub struct DeathBlossom;
+mod death_blossom;
+
+/// An ALS lifted out of its house for the cross-house searches.
+struct AlsNode<'a> {
+    als: &'a Als,
+    cells: CellSet,
+    candidate_cells: [Vec<(usize, usize)>; 9],
+}
+
+impl<'a> AlsNode<'a> {
+    fn new(game_board: &GameBoard, als: &'a Als) -> Self {
+        Self {
+            als,
+            cells: als.cells().collect(),
+            candidate_cells: std::array::from_fn(|target| {
+                als.cells_with_candidate(game_board, target).collect()
+            }),
+        }
+    }
+
+    fn candidates(&self) -> BitMap {
+        self.als.candidates()
+    }
+
+    fn overlaps(&self, cells: CellSet) -> bool {
+        !self.cells.intersect(cells).is_empty()
+    }
+
+    /// All the candidates which are restricted common between two non-overlapping ALSs
+    fn restricted_commons(&self, other: &AlsNode) -> BitMap {
+        if self.overlaps(other.cells) {
+            return BitMap::new();
+        }
+        self.candidates()
+            .intersect(other.candidates())
+            .iter_ones()
+            .filter(|&target| {
+                self.candidate_cells[target].iter().all(|&(px, py)| {
+                    other.candidate_cells[target]
+                        .iter()
+                        .all(|&(qx, qy)| Coord::sees(px, py, qx, qy))
+                })
+            })
+            .collect()
+    }
+
+    fn candidate_clues(&self, game_board: &GameBoard) -> impl Iterator<Item = Candidate> {
+        self.als.cells().filter_map(|(x, y)| {
+            game_board
+                .get_candidates(x, y)
+                .map(|candidates| Candidate::from_coord((x, y), candidates))
+        })
+    }
+
+    fn target_clues(&self, target: usize) -> impl Iterator<Item = Candidate> + '_ {
+        self.candidate_cells[target]
+            .iter()
+            .map(move |&coord| Candidate::from_coord_single(coord, target))
+    }
+}
+
+/// Collect all the ALSs on the board, an ALS lies in a box-line intersection is only collected once.
+fn collect_als_nodes(game_board: &GameBoard) -> Vec<AlsNode<'_>> {
+    let mut visited = HashSet::new();
+    game_board
+        .als()
+        .map(|als| AlsNode::new(game_board, als))
+        .filter(|node| visited.insert(node.cells))
+        .collect()
+}
+
+/// Eliminate target in all the cells which see every given cell
+fn eliminate_seeing_all(
+    game_board: &GameBoard,
+    cells: &[(usize, usize)],
+    target: usize,
+) -> Vec<Action> {
+    cells
+        .iter()
+        .fold(game_board.candidate_cells(target), |acc, &(cx, cy)| {
+            acc.intersect(CellSet::peers(cx, cy))
+        })
+        .iter()
+        .map(|(x, y)| {
+            Action::Elimination(EliminationDetails {
+                x,
+                y,
+                target: BitMap::from(target),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod als_chains_test {
+    use super::*;
+    use crate::tests::common::test_function_e;
+    use crate::utils::House::{Box, Col, Row};
+    #[test]
+    fn als_xz_test() {
+        test_function_e(
+            AlsChain,
+            [
+                560, 128, 64, 804, 774, 8, 790, 820, 1, 2, 520, 768, 16, 612, 513, 580, 612, 128,
+                560, 516, 1, 864, 834, 128, 8, 880, 850, 256, 609, 514, 520, 528, 612, 640, 517,
+                608, 4, 608, 520, 640, 1, 864, 848, 2, 880, 577, 16, 640, 868, 868, 2, 517, 8, 864,
+                520, 256, 560, 610, 640, 608, 531, 529, 4, 640, 2, 548, 1, 548, 16, 832, 832, 520,
+                577, 577, 532, 774, 520, 772, 32, 128, 530,
+            ],
+            vec![(3, 5)],         //exp_actions
+            vec![32],             //exp_action_targets
+            vec![Row(5), Col(5)], //exp_house_clues
+            vec![
+                (5, 0),
+                (5, 3),
+                (5, 4),
+                (5, 6),
+                (0, 0),
+                (5, 3),
+                (5, 4),
+                (4, 5),
+                (0, 0),
+                (4, 5),
+                (6, 5),
+                (0, 0),
+                (5, 3),
+                (5, 4),
+                (4, 5),
+                (6, 5),
+            ], //exp_candidate_clues
+            vec![
+                65, 356, 356, 5, 65535, 256, 256, 256, 65535, 352, 96, 65535, 32, 32, 32, 32,
+            ], //exp_candidate_masks
+        );
+    }
+
+    #[test]
+    fn als_xy_wing_test() {
+        test_function_e(
+            AlsChain,
+            [
+                516, 522, 528, 554, 128, 513, 256, 64, 552, 768, 522, 1, 64, 550, 526, 572, 560,
+                640, 640, 576, 32, 524, 16, 256, 2, 524, 513, 536, 513, 832, 640, 590, 32, 604,
+                786, 782, 568, 4, 832, 794, 513, 602, 632, 128, 810, 2, 560, 128, 780, 588, 604,
+                632, 1, 812, 64, 640, 520, 1, 256, 518, 548, 546, 16, 560, 256, 2, 572, 620, 640,
+                513, 524, 588, 1, 560, 4, 570, 618, 602, 128, 770, 842,
+            ],
+            vec![(2, 7), (7, 8), (8, 8)], //exp_actions
+            vec![8, 8, 8],                //exp_action_targets
+            vec![Row(0), Col(7), Box(8)], //exp_house_clues
+            vec![
+                (0, 8),
+                (0, 0),
+                (0, 8),
+                (1, 7),
+                (0, 0),
+                (1, 7),
+                (3, 7),
+                (8, 7),
+                (0, 0),
+                (3, 7),
+                (8, 7),
+                (6, 7),
+                (0, 0),
+                (6, 6),
+                (6, 7),
+                (7, 7),
+                (0, 0),
+                (0, 8),
+                (7, 7),
+            ], //exp_candidate_clues
+            vec![
+                40, 65535, 32, 32, 65535, 48, 274, 258, 65535, 2, 2, 2, 65535, 36, 34, 12, 65535,
+                8, 8,
+            ], //exp_candidate_masks
+        );
+    }
+
+    #[test]
+    fn als_chain_test() {
+        test_function_e(
+            AlsChain,
+            [
+                32, 64, 532, 8, 2, 529, 128, 768, 517, 1, 920, 664, 4, 944, 688, 576, 554, 522,
+                780, 652, 2, 928, 673, 64, 557, 557, 16, 2, 544, 660, 1, 576, 644, 536, 648, 256,
+                576, 1, 768, 656, 8, 514, 532, 644, 32, 524, 668, 668, 688, 676, 256, 515, 64, 515,
+                128, 780, 576, 2, 821, 572, 813, 557, 525, 780, 526, 32, 896, 645, 653, 527, 16,
+                64, 16, 782, 513, 576, 804, 556, 814, 558, 640,
+            ],
+            vec![(1, 7)],                 //exp_actions
+            vec![8],                      //exp_action_targets
+            vec![Row(1), Col(3), Box(5)], //exp_house_clues
+            vec![
+                (1, 1),
+                (1, 2),
+                (1, 4),
+                (1, 5),
+                (0, 0),
+                (1, 4),
+                (1, 5),
+                (2, 3),
+                (0, 0),
+                (2, 3),
+                (4, 3),
+                (7, 3),
+                (0, 0),
+                (4, 3),
+                (4, 6),
+                (0, 0),
+                (3, 7),
+                (4, 6),
+                (4, 7),
+                (0, 0),
+                (1, 1),
+                (1, 2),
+                (3, 7),
+            ], //exp_candidate_clues
+            vec![
+                408, 152, 432, 176, 65535, 32, 32, 32, 65535, 416, 144, 384, 65535, 16, 16, 65535,
+                136, 20, 132, 65535, 8, 8, 8,
+            ], //exp_candidate_masks
+        );
+    }
+
+    #[test]
+    fn death_blossom_test_1() {
+        test_function_e(
+            DeathBlossom,
+            [
+                544, 2, 529, 524, 64, 532, 128, 537, 256, 128, 516, 576, 1, 536, 256, 552, 568, 2,
+                256, 8, 529, 546, 642, 674, 516, 64, 529, 515, 897, 32, 526, 655, 711, 16, 770,
+                588, 8, 64, 518, 256, 534, 566, 1, 128, 548, 528, 897, 646, 558, 655, 743, 616,
+                770, 620, 579, 544, 778, 128, 775, 519, 584, 541, 601, 579, 641, 650, 16, 32, 518,
+                256, 525, 713, 4, 16, 896, 576, 769, 8, 2, 545, 673,
+            ],
+            vec![(7, 7)],         //exp_actions
+            vec![8],              //exp_action_targets
+            vec![Row(7), Row(0)], //exp_house_clues
+            vec![
+                (0, 5),
+                (0, 0),
+                (7, 0),
+                (7, 1),
+                (7, 2),
+                (7, 5),
+                (7, 8),
+                (0, 0),
+                (0, 2),
+                (0, 7),
+                (0, 0),
+                (7, 2),
+                (7, 8),
+                (0, 7),
+            ], //exp_candidate_clues
+            vec![
+                20, 65535, 67, 129, 138, 6, 201, 65535, 17, 25, 65535, 8, 8, 8,
+            ], //exp_candidate_masks
+        );
+    }
+
+    #[test]
+    fn death_blossom_test_2() {
+        test_function_e(
+            DeathBlossom,
+            [
+                560, 128, 64, 804, 774, 8, 790, 820, 1, 2, 520, 768, 16, 612, 513, 580, 612, 128,
+                560, 516, 1, 864, 834, 128, 8, 880, 850, 256, 609, 514, 520, 528, 612, 640, 517,
+                608, 4, 608, 520, 640, 1, 864, 848, 2, 880, 577, 16, 640, 868, 868, 2, 517, 8, 864,
+                520, 256, 560, 610, 640, 608, 531, 529, 4, 640, 2, 548, 1, 548, 16, 832, 832, 520,
+                577, 577, 532, 774, 520, 772, 32, 128, 530,
+            ],
+            vec![(0, 6)],         //exp_actions
+            vec![16],             //exp_action_targets
+            vec![Col(7), Col(6)], //exp_house_clues
+            vec![
+                (1, 6),
+                (0, 0),
+                (0, 7),
+                (1, 7),
+                (2, 7),
+                (7, 7),
+                (0, 0),
+                (4, 6),
+                (7, 6),
+                (0, 0),
+                (0, 7),
+                (2, 7),
+                (4, 6),
+            ], //exp_candidate_clues
+            vec![
+                68, 65535, 308, 100, 368, 320, 65535, 336, 320, 65535, 16, 16, 16,
+            ], //exp_candidate_masks
+        );
+    }
+}
@@ -0,0 +1,156 @@
+use crate::utils::House;
+
+/// [HoDoKu explanations on Franken Fish](https://hodoku.sourceforge.net/en/tech_fishc.php#ff)
+/// ## Terminology
+/// - The fish of size n (2 to 4) consists of n **Base Sets** and n **Cover Sets** for a single candidate.
+///   The base sets must not share any candidate, and a house can't be both a base set and a cover set.
+/// - For a Franken fish, the base sets are rows and boxes while the cover sets are columns and boxes,
+///   or the other way around. At least one box is used.
+/// - The candidates in the base sets are called the **Body**, except those outside all the cover sets, which are called **Fins**.
+/// - Without fins, the candidates in the cover sets but outside the base sets are eliminated.
+///   With fins, only the ones seeing all the fins are eliminated.
+/// ## Return Format
+/// - **Actions**: Contains variable number of elements, representing the eliminated candidates.
+/// - **House Clues**: Contains 2n elements, representing the base sets followed by the cover sets.
+/// - **Candidate Clues**: Contains 1 or 2 groups of elements, seperated by the Candidate::SEPARATOR:
+///     - The body
+///     - The fins, only if there are fins
+pub struct FrankenFish;
+
+/// [HoDoKu explanations on Mutant Fish](https://hodoku.sourceforge.net/en/tech_fishc.php#mf)
+///
+/// The fish whose base sets and cover sets may mix rows, columns and boxes freely, as long as it's
+/// neither a basic fish nor a [`FrankenFish`]. Follows the same terminology and return format as [`FrankenFish`].
+pub struct MutantFish;
+mod search;
+
+/// Maximum size of the complex fish, shared with the Kraken Fish
+pub(super) const MAX_FISH_SIZE: usize = 4;
+/// Maximum number of fins
+const MAX_FIN_CNT: usize = 4;
+
+/// The house types allowed in the base sets and the cover sets
+#[derive(Clone, Copy)]
+struct FishShape {
+    base: [bool; 3],
+    cover: [bool; 3],
+}
+
+impl FishShape {
+    const FRANKEN_ROW: Self = Self {
+        base: [true, false, true],
+        cover: [false, true, true],
+    };
+    const FRANKEN_COL: Self = Self {
+        base: [false, true, true],
+        cover: [true, false, true],
+    };
+    const BASIC_ROW: Self = Self {
+        base: [true, false, false],
+        cover: [false, true, false],
+    };
+    const BASIC_COL: Self = Self {
+        base: [false, true, false],
+        cover: [true, false, false],
+    };
+    const MUTANT: Self = Self {
+        base: [true, true, true],
+        cover: [true, true, true],
+    };
+
+    fn from_houses(base: &[House], cover: &[House]) -> Self {
+        let mut shape = Self {
+            base: [false; 3],
+            cover: [false; 3],
+        };
+        base.iter()
+            .for_each(|house| shape.base[house.get_dim()] = true);
+        cover
+            .iter()
+            .for_each(|house| shape.cover[house.get_dim()] = true);
+        shape
+    }
+
+    fn fits_in(&self, other: &Self) -> bool {
+        (0..3).all(|dim| {
+            (!self.base[dim] || other.base[dim]) && (!self.cover[dim] || other.cover[dim])
+        })
+    }
+
+    fn is_basic(&self) -> bool {
+        self.fits_in(&Self::BASIC_ROW) || self.fits_in(&Self::BASIC_COL)
+    }
+
+    fn is_franken(&self) -> bool {
+        !self.is_basic() && (self.fits_in(&Self::FRANKEN_ROW) || self.fits_in(&Self::FRANKEN_COL))
+    }
+
+    fn is_mutant(&self) -> bool {
+        !self.is_basic() && !self.is_franken()
+    }
+}
+
+#[cfg(test)]
+mod complex_fish_test {
+    use super::*;
+    use crate::tests::common::test_function_e;
+    use crate::utils::House::{Box, Col, Row};
+
+    #[test]
+    fn test_franken_fish() {
+        test_function_e(
+            FrankenFish,
+            [
+                537, 256, 521, 548, 64, 514, 524, 128, 569, 32, 516, 585, 521, 537, 128, 833, 514,
+                857, 729, 2, 713, 548, 537, 768, 589, 601, 633, 4, 712, 713, 2, 544, 521, 16, 256,
+                713, 2, 712, 768, 521, 4, 528, 32, 585, 713, 521, 544, 16, 128, 768, 64, 521, 4,
+                514, 712, 528, 544, 256, 649, 521, 2, 577, 4, 712, 712, 514, 16, 649, 516, 833, 32,
+                833, 256, 1, 4, 576, 514, 544, 640, 536, 536,
+            ],
+            vec![(2, 4)],                                         //exp_actions
+            vec![1],                                              //exp_action_targets
+            vec![Col(3), Col(7), Box(7), Box(1), Row(4), Row(6)], //exp_house_clues
+            vec![
+                (1, 3),
+                (4, 3),
+                (4, 7),
+                (6, 4),
+                (6, 5),
+                (6, 7),
+                (0, 0),
+                (2, 7),
+                (7, 4),
+            ], //exp_candidate_clues
+            vec![1, 1, 1, 1, 1, 1, 65535, 1, 1],                  //exp_candidate_masks
+        );
+    }
+
+    #[test]
+    fn test_mutant_fish() {
+        test_function_e(
+            MutantFish,
+            [
+                640, 1, 516, 64, 520, 768, 2, 528, 544, 256, 578, 8, 528, 32, 519, 580, 577, 128,
+                594, 594, 32, 640, 519, 519, 836, 833, 8, 592, 752, 770, 551, 583, 615, 912, 8,
+                848, 8, 624, 770, 546, 128, 610, 1, 4, 848, 580, 708, 513, 520, 256, 16, 704, 32,
+                514, 32, 778, 128, 769, 16, 587, 840, 834, 4, 519, 782, 16, 806, 582, 128, 872,
+                834, 833, 519, 782, 64, 806, 518, 558, 824, 640, 785,
+            ],
+            vec![(3, 0), (3, 1)],                                 //exp_actions
+            vec![64, 64],                                         //exp_action_targets
+            vec![Row(5), Col(4), Col(8), Row(3), Box(5), Row(7)], //exp_house_clues
+            vec![
+                (3, 4),
+                (3, 8),
+                (4, 8),
+                (5, 6),
+                (7, 4),
+                (7, 8),
+                (0, 0),
+                (5, 0),
+                (5, 1),
+            ], //exp_candidate_clues
+            vec![64, 64, 64, 64, 64, 64, 65535, 64, 64],          //exp_candidate_masks
+        );
+    }
+}
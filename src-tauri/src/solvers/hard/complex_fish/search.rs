@@ -0,0 +1,274 @@
+use crate::{
+    game_board::GameBoard,
+    solvers::{
+        Solver, SolverIdentifier,
+        solution::{
+            Action, Candidate, EliminationDetails, Solution, Visitor, distinct_visited,
+            first_visited,
+        },
+    },
+    utils::{BitMap, CellSet, Coord, House},
+};
+
+use super::{FishShape, FrankenFish, MAX_FIN_CNT, MAX_FISH_SIZE, MutantFish};
+
+/// The appearances of a single candidate in every house
+struct FishBoard {
+    houses: Vec<(House, CellSet)>,
+    // house_index[dim][id] : the index of the house in houses, if the candidate appears in it
+    house_index: [[Option<usize>; 9]; 3],
+}
+
+impl FishBoard {
+    fn new(game_board: &GameBoard, target: usize) -> Self {
+        let appearance = game_board.candidate_cells(target);
+        let mut houses = vec![];
+        let mut house_index = [[None; 9]; 3];
+        for (dim, indices) in house_index.iter_mut().enumerate() {
+            for (id, house_index) in indices.iter_mut().enumerate() {
+                let house = House::from_dim_id(dim, id);
+                let cells = CellSet::house(&house).intersect(appearance);
+                if !cells.is_empty() {
+                    *house_index = Some(houses.len());
+                    houses.push((house, cells));
+                }
+            }
+        }
+        Self {
+            houses,
+            house_index,
+        }
+    }
+
+    /// The houses containing cell (x,y), in which the candidate appears
+    fn houses_of(&self, x: usize, y: usize) -> impl Iterator<Item = usize> + '_ {
+        [x, y, Coord::get_box_id(x, y)]
+            .into_iter()
+            .enumerate()
+            .filter_map(|(dim, id)| self.house_index[dim][id])
+    }
+}
+
+struct FishSearch<'a> {
+    board: &'a FishBoard,
+    target: usize,
+    n: usize,
+    shape: FishShape,
+    accept: fn(&FishShape) -> bool,
+    solver_id: SolverIdentifier,
+    base: Vec<usize>,
+    base_cells: CellSet,
+    cover: Vec<usize>,
+}
+
+impl<'a> FishSearch<'a> {
+    fn allowed(allowed_types: &[bool; 3], house: &House) -> bool {
+        allowed_types[house.get_dim()]
+    }
+
+    /// Choose the base sets in ascending order, returns true once the visitor stops the search
+    fn search_base(&mut self, start: usize, visit: Visitor) -> bool {
+        if self.base.len() == self.n {
+            return self.search_cover(CellSet::new(), CellSet::new(), CellSet::ALL, visit);
+        }
+        for index in start..self.board.houses.len() {
+            let (house, cells) = self.board.houses[index];
+            if !Self::allowed(&self.shape.base, &house)
+                || !cells.intersect(self.base_cells).is_empty()
+            {
+                continue;
+            }
+            self.base.push(index);
+            self.base_cells = self.base_cells.union(cells);
+            let stopped = self.search_base(index + 1, visit);
+            self.base_cells = self.base_cells.difference(cells);
+            self.base.pop();
+            if stopped {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Cover the first uncovered base cell, or take it as a fin
+    fn search_cover(
+        &mut self,
+        covered: CellSet,
+        fins: CellSet,
+        fin_peers: CellSet,
+        visit: Visitor,
+    ) -> bool {
+        let uncovered = self.base_cells.difference(covered).difference(fins);
+        if self.cover.len() == self.n {
+            let fins = fins.union(uncovered);
+            return fins.count() <= MAX_FIN_CNT
+                && self
+                    .try_get_solution(covered, fins)
+                    .is_some_and(&mut *visit);
+        }
+        let Some((x, y)) = uncovered.first() else {
+            return false;
+        };
+        let candidate_houses: Vec<_> = self
+            .board
+            .houses_of(x, y)
+            .filter(|&house_index| {
+                Self::allowed(&self.shape.cover, &self.board.houses[house_index].0)
+                    && !self.base.contains(&house_index)
+                    && !self.cover.contains(&house_index)
+            })
+            .collect();
+        for house_index in candidate_houses {
+            self.cover.push(house_index);
+            let stopped = self.search_cover(
+                covered.union(self.board.houses[house_index].1),
+                fins,
+                fin_peers,
+                visit,
+            );
+            self.cover.pop();
+            if stopped {
+                return true;
+            }
+        }
+        let fin_peers = fin_peers.intersect(CellSet::peers(x, y));
+        if fins.count() < MAX_FIN_CNT && !fin_peers.is_empty() {
+            let mut fins = fins;
+            fins.insert(x, y);
+            return self.search_cover(covered, fins, fin_peers, visit);
+        }
+        false
+    }
+
+    fn houses(&self, indices: &[usize]) -> Vec<House> {
+        indices
+            .iter()
+            .map(|&index| self.board.houses[index].0)
+            .collect()
+    }
+
+    fn try_get_solution(&self, covered: CellSet, fins: CellSet) -> Option<Solution> {
+        let base_houses = self.houses(&self.base);
+        let cover_houses = self.houses(&self.cover);
+        let shape = FishShape::from_houses(&base_houses, &cover_houses);
+        if !(self.accept)(&shape) {
+            return None;
+        }
+        // a fin may be covered by a cover set chosen later
+        let fins = fins.difference(covered);
+        let fin_peers = fins.iter().fold(CellSet::ALL, |acc, (x, y)| {
+            acc.intersect(CellSet::peers(x, y))
+        });
+        let eliminable = covered.difference(self.base_cells).intersect(fin_peers);
+        if eliminable.is_empty() {
+            return None;
+        }
+        let to_clues = |cells: CellSet| {
+            cells
+                .iter()
+                .map(|(x, y)| Candidate::new_single(x, y, self.target))
+        };
+        let mut candidate_clues: Vec<_> = to_clues(self.base_cells.difference(fins)).collect();
+        if !fins.is_empty() {
+            candidate_clues.push(Candidate::SEPARATOR);
+            candidate_clues.extend(to_clues(fins));
+        }
+        Some(Solution {
+            actions: eliminable
+                .iter()
+                .map(|(x, y)| {
+                    Action::Elimination(EliminationDetails {
+                        x,
+                        y,
+                        target: BitMap::from(self.target),
+                    })
+                })
+                .collect(),
+            house_clues: base_houses.into_iter().chain(cover_houses).collect(),
+            candidate_clues,
+            solver_id: self.solver_id,
+        })
+    }
+}
+
+fn search_complex_fish(
+    game_board: &GameBoard,
+    shapes: &[FishShape],
+    accept: fn(&FishShape) -> bool,
+    solver_id: SolverIdentifier,
+    visit: Visitor,
+) {
+    (2..=MAX_FISH_SIZE).any(|n| {
+        (0..9)
+            .take_while(|_| !game_board.cancelled())
+            .any(|target| {
+                let board = FishBoard::new(game_board, target);
+                shapes.iter().any(|&shape| {
+                    FishSearch {
+                        board: &board,
+                        target,
+                        n,
+                        shape,
+                        accept,
+                        solver_id,
+                        base: vec![],
+                        base_cells: CellSet::new(),
+                        cover: vec![],
+                    }
+                    .search_base(0, visit)
+                })
+            })
+    });
+}
+
+impl FrankenFish {
+    fn search(&self, game_board: &GameBoard, visit: Visitor) {
+        search_complex_fish(
+            game_board,
+            &[FishShape::FRANKEN_ROW, FishShape::FRANKEN_COL],
+            FishShape::is_franken,
+            self.solver_id(),
+            visit,
+        );
+    }
+}
+
+impl Solver for FrankenFish {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        first_visited(|visit| self.search(game_board, visit))
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_visited(|visit| self.search(game_board, visit))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::FrankenFish
+    }
+}
+
+impl MutantFish {
+    fn search(&self, game_board: &GameBoard, visit: Visitor) {
+        search_complex_fish(
+            game_board,
+            &[FishShape::MUTANT],
+            FishShape::is_mutant,
+            self.solver_id(),
+            visit,
+        );
+    }
+}
+
+impl Solver for MutantFish {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        first_visited(|visit| self.search(game_board, visit))
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_visited(|visit| self.search(game_board, visit))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::MutantFish
+    }
+}
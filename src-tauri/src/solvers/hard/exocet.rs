@@ -0,0 +1,268 @@
+use std::iter::once;
+
+use crate::{
+    game_board::GameBoard,
+    solvers::{
+        Solver, SolverIdentifier,
+        solution::{Action, Candidate, EliminationDetails, Solution, distinct_by_actions},
+    },
+    utils::{BitMap, House},
+};
+
+/// [Sudopedia explanations on Exocet](http://sudopedia.enjoysudoku.com/Exocet.html)
+/// ## Terminology
+/// - Two unsolved cells in the intersection of a box and a line are the **Base Cells**, and their 3 or 4 candidates are the **Base Digits**.
+/// - Two unsolved **Target Cells** lie in the other two boxes of the band, one in each of the other two lines of the band.
+/// - The **Cross-lines** are the perpendicular lines through the target cells and through the third cell of the base intersection.
+/// - The cells of the band in the cross-lines of the targets, which are neither the targets nor in the base line, are the **Companion Cells**.
+///   They can't hold a base digit.
+/// - Each base digit in the cross-lines outside the band can be covered by 2 lines, so it must appear in the band part of the
+///   cross-lines as well, which leaves only the target cells. The 2 digits of the base cells are then the digits of the target cells,
+///   and the other candidates are eliminated from the target cells.
+/// ## Return Format
+/// - **Actions**: Contains 1 or 2 elements, representing the non-base candidates eliminated in each target cell.
+/// - **House Clues**: Contains 3 elements, representing the cross-lines.
+/// - **Candidate Clues**: Contains 2 groups of elements, seperated by the Candidate::SEPARATOR:
+///     - The base cells with the base digits
+///     - The target cells with the base digits
+pub struct JuniorExocet;
+
+/// The cell in the line of dimension dim, at the index cross of the perpendicular dimension
+fn cell(dim: usize, line: usize, cross: usize) -> (usize, usize) {
+    if dim == 0 {
+        (line, cross)
+    } else {
+        (cross, line)
+    }
+}
+
+/// The other two members of the group of 3 containing i
+fn others_in_group(i: usize) -> [usize; 2] {
+    let start = i / 3 * 3;
+    [start + (i + 1) % 3, start + (i + 2) % 3]
+}
+
+/// Returns true if the cells can be covered by at most 2 rows or columns
+fn covered_by_two_lines(cells: &[(usize, usize)]) -> bool {
+    let lines: Vec<_> = cells.iter().flat_map(|&(x, y)| [(0, x), (1, y)]).collect();
+    cells.len() <= 2
+        || lines.iter().any(|&first| {
+            lines.iter().any(|&second| {
+                cells
+                    .iter()
+                    .all(|&(x, y)| [first, second].iter().any(|&(dim, id)| [x, y][dim] == id))
+            })
+        })
+}
+
+struct Exocet {
+    dim: usize,
+    line: usize,
+    base: [(usize, usize); 2],
+    base_digits: BitMap,
+    targets: [(usize, usize); 2],
+    cross_lines: [usize; 3],
+}
+
+impl Exocet {
+    fn holds(game_board: &GameBoard, (x, y): (usize, usize), target: usize) -> bool {
+        game_board.contains_candidate(x, y, target) || game_board.is_clue(x, y, target)
+    }
+
+    fn holds_base_digit(&self, game_board: &GameBoard, cell: (usize, usize)) -> bool {
+        self.base_digits
+            .iter_ones()
+            .any(|target| Self::holds(game_board, cell, target))
+    }
+
+    /// Both targets are unsolved and can hold a base digit, while the companion cells can't
+    fn valid_targets(&self, game_board: &GameBoard, companions: [(usize, usize); 2]) -> bool {
+        self.targets.iter().all(|&(x, y)| {
+            game_board
+                .get_candidates(x, y)
+                .is_some_and(|candidates| candidates.intersect(self.base_digits).count() > 0)
+        }) && companions
+            .iter()
+            .all(|&cell| !self.holds_base_digit(game_board, cell))
+    }
+
+    /// Each base digit appears at most twice in the cross-lines outside the band
+    fn valid_cross_lines(&self, game_board: &GameBoard) -> bool {
+        self.base_digits.iter_ones().all(|target| {
+            let cells: Vec<_> = self
+                .cross_lines
+                .iter()
+                .flat_map(|&cross| {
+                    (0..9)
+                        .filter(|&line| line / 3 != self.line / 3)
+                        .map(move |line| cell(self.dim, line, cross))
+                })
+                .filter(|&cell| Self::holds(game_board, cell, target))
+                .collect();
+            covered_by_two_lines(&cells)
+        })
+    }
+
+    fn try_get_solution(
+        &self,
+        game_board: &GameBoard,
+        solver_id: SolverIdentifier,
+    ) -> Option<Solution> {
+        let actions: Vec<_> = self
+            .targets
+            .iter()
+            .filter_map(|&(x, y)| {
+                let target = game_board
+                    .get_candidates(x, y)?
+                    .difference(self.base_digits);
+                (target.count() > 0).then_some(Action::Elimination(EliminationDetails {
+                    x,
+                    y,
+                    target,
+                }))
+            })
+            .collect();
+        if actions.is_empty() || !self.valid_cross_lines(game_board) {
+            return None;
+        }
+        let to_clue = |&(x, y): &(usize, usize)| {
+            game_board
+                .get_candidates(x, y)
+                .map(|candidates| Candidate::new(x, y, candidates.intersect(self.base_digits)))
+        };
+        Some(Solution {
+            actions,
+            house_clues: self
+                .cross_lines
+                .iter()
+                .map(|&cross| House::from_dim_id(1 - self.dim, cross))
+                .collect(),
+            candidate_clues: self
+                .base
+                .iter()
+                .filter_map(to_clue)
+                .chain(once(Candidate::SEPARATOR))
+                .chain(self.targets.iter().filter_map(to_clue))
+                .collect(),
+            solver_id,
+        })
+    }
+}
+
+impl JuniorExocet {
+    /// Every pair of base cells in the intersection of a line of dimension dim and a box,
+    /// along with the index of the third cell in the intersection
+    fn iter_base(
+        game_board: &GameBoard,
+        dim: usize,
+    ) -> impl Iterator<Item = ([(usize, usize); 2], BitMap, usize, usize)> + '_ {
+        (0..9).flat_map(move |line| {
+            (0..9).filter_map(move |third| {
+                let [first, second] = others_in_group(third);
+                let base = [cell(dim, line, first), cell(dim, line, second)];
+                let base_digits = base
+                    .iter()
+                    .map(|&(x, y)| game_board.get_candidates(x, y))
+                    .try_fold(BitMap::new(), |acc, candidates| {
+                        Some(acc.union(candidates?))
+                    })?;
+                (3..=4)
+                    .contains(&base_digits.count())
+                    .then_some((base, base_digits, line, third))
+            })
+        })
+    }
+
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        (0..2).flat_map(move |dim| {
+            Self::iter_base(game_board, dim).flat_map(move |(base, base_digits, line, third)| {
+                let [first_line, second_line] = others_in_group(line);
+                // the first crosses of the other two boxes in the band
+                let [first_block, second_block] = others_in_group(third / 3).map(|block| block * 3);
+                let target_lines = [(first_line, second_line), (second_line, first_line)];
+                target_lines.into_iter().flat_map(move |(line_1, line_2)| {
+                    (first_block..first_block + 3).flat_map(move |cross_1| {
+                        (second_block..second_block + 3).filter_map(move |cross_2| {
+                            let exocet = Exocet {
+                                dim,
+                                line,
+                                base,
+                                base_digits,
+                                targets: [cell(dim, line_1, cross_1), cell(dim, line_2, cross_2)],
+                                cross_lines: [cross_1, cross_2, third],
+                            };
+                            let companions =
+                                [cell(dim, line_2, cross_1), cell(dim, line_1, cross_2)];
+                            if !exocet.valid_targets(game_board, companions) {
+                                return None;
+                            }
+                            exocet.try_get_solution(game_board, self.solver_id())
+                        })
+                    })
+                })
+            })
+        })
+    }
+}
+
+impl Solver for JuniorExocet {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::JuniorExocet
+    }
+}
+
+#[cfg(test)]
+mod junior_exocet_test {
+    use super::*;
+    use crate::tests::common::test_function_e;
+    use crate::utils::House::{Col, Row};
+
+    #[test]
+    fn junior_exocet_test_1() {
+        test_function_e(
+            JuniorExocet,
+            [
+                531, 786, 516, 769, 128, 576, 544, 520, 771, 640, 802, 803, 769, 516, 8, 576, 16,
+                771, 520, 64, 769, 2, 528, 544, 4, 640, 769, 256, 516, 576, 640, 544, 1, 16, 514,
+                8, 530, 530, 8, 516, 64, 768, 128, 513, 544, 32, 1, 128, 536, 522, 530, 768, 576,
+                516, 578, 128, 546, 616, 1, 4, 522, 768, 528, 593, 824, 817, 632, 522, 530, 521,
+                516, 128, 4, 538, 531, 536, 256, 640, 523, 32, 64,
+            ],
+            vec![(7, 3), (8, 6)],                         //exp_actions
+            vec![24, 9],                                  //exp_action_targets
+            vec![Col(3), Col(6), Col(1)],                 //exp_house_clues
+            vec![(6, 2), (6, 0), (0, 0), (7, 3), (8, 6)], //exp_candidate_clues
+            vec![34, 66, 65535, 96, 2],                   //exp_candidate_masks
+        );
+    }
+
+    #[test]
+    fn junior_exocet_test_2() {
+        test_function_e(
+            JuniorExocet,
+            [
+                640, 517, 773, 772, 544, 8, 64, 528, 514, 2, 520, 772, 528, 576, 513, 896, 32, 644,
+                800, 16, 64, 772, 514, 128, 769, 520, 517, 513, 64, 522, 642, 16, 32, 640, 516,
+                256, 784, 514, 784, 642, 8, 516, 673, 704, 673, 4, 544, 128, 576, 513, 256, 528, 2,
+                520, 520, 256, 514, 544, 640, 528, 4, 1, 576, 576, 640, 544, 1, 516, 2, 520, 256,
+                16, 528, 519, 535, 8, 768, 576, 674, 640, 672,
+            ],
+            vec![(4, 0), (8, 1)],                         //exp_actions
+            vec![16, 2],                                  //exp_action_targets
+            vec![Row(4), Row(8), Row(2)],                 //exp_house_clues
+            vec![(0, 2), (1, 2), (0, 0), (4, 0), (8, 1)], //exp_candidate_clues
+            vec![261, 260, 65535, 256, 5],                //exp_candidate_masks
+        );
+    }
+}
@@ -0,0 +1,219 @@
+use std::collections::HashMap;
+
+use crate::{
+    game_board::GameBoard,
+    solvers::{
+        Solver, SolverIdentifier,
+        implication::{Implications, Literal},
+        medium::finned::{FinnedFish, iter_finned_fish},
+        solution::{
+            Action, Candidate, EliminationDetails, Solution, Visitor, distinct_visited,
+            first_visited,
+        },
+    },
+    utils::{BitMap, HouseType},
+};
+
+use super::complex_fish::MAX_FISH_SIZE;
+
+/// [HoDoKu explanations on Kraken Fish](https://hodoku.sourceforge.net/en/tech_fishc.php#kf)
+/// ## Terminology
+/// - A basic fish of size n (2 to 4) with fins, following the terminology of the finned fish.
+/// - Either the fish itself holds, then the candidates in the cover sets but outside the base sets are false,
+///   or one of the fins is true. A candidate **C** in the cover sets can be eliminated if each fin being true
+///   forces C to be false through a chain.
+/// - Each link of the chain flips between true and false: a true candidate turns off the other candidates in its cell and its peers,
+///   a false candidate turns on the other candidate of a bi-value cell, and the other end of a hard link.
+/// - The fish where all the fins see C directly is a plain finned fish, thus not reported.
+/// ## Return Format
+/// - **Actions**: Contains 1 element, representing C.
+/// - **House Clues**: Contains 2n elements, representing the base sets followed by the cover sets.
+/// - **Candidate Clues**: Contains groups of elements, seperated by the Candidate::SEPARATOR:
+///     - The body
+///     - The fins
+///     - For each fin, the chain from the fin to C. The candidates in the chain are true and false alternately, starting from the fin being true.
+///       A false candidate is represented by the complement mask.
+pub struct KrakenFish {
+    max_chain_length: usize,
+}
+
+impl KrakenFish {
+    /// Default limit on the links from a fin to the eliminated candidate, editable in the solver configuration.
+    /// A chain is followed from every fin of every fish tried, so it stays shorter than the extreme tier's forcing chains.
+    pub const DEFAULT_MAX_CHAIN_LENGTH: usize = 8;
+
+    pub fn new(max_chain_length: usize) -> Self {
+        Self { max_chain_length }
+    }
+
+    /// The chains from every fin to C being false
+    fn try_get_chains(
+        &self,
+        game_board: &GameBoard,
+        fish: &FinnedFish,
+        (x, y): (usize, usize),
+        cache: &mut HashMap<(usize, usize, usize), Implications>,
+    ) -> Option<Vec<Vec<Literal>>> {
+        let end = Literal {
+            x,
+            y,
+            target: fish.target,
+            on: false,
+        };
+        fish.iter_along_base(fish.fins)
+            .map(|(fx, fy)| {
+                cache
+                    .entry((fx, fy, fish.target))
+                    .or_insert_with(|| {
+                        let start = Literal {
+                            x: fx,
+                            y: fy,
+                            target: fish.target,
+                            on: true,
+                        };
+                        Implications::new(game_board, start, self.max_chain_length)
+                    })
+                    .chain_to(end)
+            })
+            .collect()
+    }
+
+    /// Visits the elimination of every C in the cover sets forced false by all the fins
+    fn visit_solutions(
+        &self,
+        game_board: &GameBoard,
+        fish: &FinnedFish,
+        cache: &mut HashMap<(usize, usize, usize), Implications>,
+        visit: Visitor,
+    ) -> bool {
+        fish.iter_along_base(fish.cover_only()).any(|(x, y)| {
+            let Some(chains) = self.try_get_chains(game_board, fish, (x, y), cache) else {
+                return false;
+            };
+            if chains.iter().all(|chain| chain.len() == 2) {
+                return false;
+            }
+            let chain_clues = chains
+                .iter()
+                .map(|chain| chain.iter().map(Literal::as_candidate).collect::<Vec<_>>())
+                .collect::<Vec<_>>()
+                .join(&Candidate::SEPARATOR);
+            visit(Solution {
+                actions: vec![Action::Elimination(EliminationDetails {
+                    x,
+                    y,
+                    target: BitMap::from(fish.target),
+                })],
+                house_clues: fish.house_clues(),
+                candidate_clues: [
+                    fish.candidate_clues(),
+                    vec![Candidate::SEPARATOR],
+                    chain_clues,
+                ]
+                .concat(),
+                solver_id: self.solver_id(),
+            })
+        })
+    }
+
+    fn search(&self, game_board: &GameBoard, visit: Visitor) {
+        let mut cache = HashMap::new();
+        (2..=MAX_FISH_SIZE).any(|n| {
+            [HouseType::Row, HouseType::Col]
+                .into_iter()
+                .any(|base_dim| {
+                    iter_finned_fish(game_board, base_dim, n)
+                        .take_while(|_| !game_board.cancelled())
+                        .any(|fish| self.visit_solutions(game_board, &fish, &mut cache, visit))
+                })
+        });
+    }
+}
+
+impl Default for KrakenFish {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_MAX_CHAIN_LENGTH)
+    }
+}
+
+impl Solver for KrakenFish {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        first_visited(|visit| self.search(game_board, visit))
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_visited(|visit| self.search(game_board, visit))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::KrakenFish
+    }
+}
+
+#[cfg(test)]
+mod kraken_fish_test {
+    use super::*;
+    use crate::solvers::SolverConfig;
+    use crate::tests::common::test_function_e;
+    use crate::utils::House::{Col, Row};
+
+    const BOARD: [u16; 81] = [
+        580, 2, 520, 580, 640, 32, 513, 528, 768, 256, 128, 564, 596, 1, 540, 608, 514, 616, 609,
+        624, 561, 514, 600, 256, 4, 128, 616, 620, 776, 804, 128, 2, 580, 16, 1, 612, 711, 628,
+        663, 8, 628, 533, 768, 608, 740, 741, 628, 693, 768, 628, 597, 736, 520, 2, 682, 1, 674,
+        624, 256, 730, 736, 4, 752, 678, 548, 64, 1, 532, 662, 8, 256, 688, 16, 776, 932, 612, 588,
+        652, 2, 608, 513,
+    ];
+
+    #[test]
+    fn test_kraken_fish() {
+        test_function_e(
+            KrakenFish::default(),
+            BOARD,
+            vec![(7, 5)],                         //exp_actions
+            vec![4],                              //exp_action_targets
+            vec![Row(1), Row(8), Col(2), Col(5)], //exp_house_clues
+            vec![
+                (1, 2),
+                (1, 5),
+                (8, 2),
+                (8, 5),
+                (0, 0),
+                (1, 3),
+                (8, 3),
+                (8, 4),
+                (0, 0),
+                (1, 3),
+                (1, 3),
+                (6, 3),
+                (7, 4),
+                (7, 4),
+                (7, 5),
+                (0, 0),
+                (8, 3),
+                (7, 5),
+                (0, 0),
+                (8, 4),
+                (7, 5),
+            ], //exp_candidate_clues
+            vec![
+                4, 4, 4, 4, 65535, 4, 4, 4, 65535, 4, 495, 16, 495, 4, 507, 65535, 4, 507, 65535,
+                4, 507,
+            ], //exp_candidate_masks
+        );
+    }
+
+    #[test]
+    fn test_configured_chain_length() {
+        let game_board = GameBoard::from_array(BOARD);
+        let mut config = SolverConfig::default();
+        let setting = config
+            .solvers
+            .iter_mut()
+            .find(|setting| setting.solver_id == SolverIdentifier::KrakenFish)
+            .unwrap();
+        assert!(setting.solver().solve(&game_board).is_some());
+        setting.max_chain_length = Some(0);
+        assert!(setting.solver().solve(&game_board).is_none());
+    }
+}
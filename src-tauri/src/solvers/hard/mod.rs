@@ -3,15 +3,40 @@
 // XY-Chain
 // Nice Loop
 
-pub(super) use crate::solvers::medium::finned::{FinnedJellyfish, FinnedSwordfish};
+pub(super) use crate::solvers::medium::finned::{
+    FinnedJellyfish, FinnedSwordfish, SashimiJellyfish, SashimiSwordfish,
+};
 use crate::solvers::solver_enum::SolverEnum;
 mod sue_de_coq;
 pub(super) use sue_de_coq::SueDeCoq;
+mod als_chains;
+pub(super) use als_chains::{AlsChain, DeathBlossom};
+mod complex_fish;
+pub(super) use complex_fish::{FrankenFish, MutantFish};
+mod aligned_exclusion;
+pub(super) use aligned_exclusion::{AlignedPairExclusion, AlignedTripleExclusion};
+mod kraken_fish;
+pub(super) use kraken_fish::KrakenFish;
+mod exocet;
+pub(super) use exocet::JuniorExocet;
+mod pattern_overlay;
+pub(super) use pattern_overlay::PatternOverlay;
 #[rustfmt::skip]
 pub fn get_hard_solvers() -> Vec<SolverEnum> {
     vec![
-        SolverEnum::from(FinnedSwordfish),
-        SolverEnum::from(FinnedJellyfish),
-        SolverEnum::from(SueDeCoq)
+        SolverEnum::from(FinnedSwordfish       ),
+        SolverEnum::from(SashimiSwordfish      ),
+        SolverEnum::from(FinnedJellyfish       ),
+        SolverEnum::from(SashimiJellyfish      ),
+        SolverEnum::from(FrankenFish           ),
+        SolverEnum::from(MutantFish            ),
+        SolverEnum::from(SueDeCoq              ),
+        SolverEnum::from(AlsChain              ),
+        SolverEnum::from(DeathBlossom          ),
+        SolverEnum::from(AlignedPairExclusion  ),
+        SolverEnum::from(AlignedTripleExclusion),
+        SolverEnum::from(KrakenFish::default() ),
+        SolverEnum::from(JuniorExocet          ),
+        SolverEnum::from(PatternOverlay        ),
     ]
 }
@@ -0,0 +1,281 @@
+use crate::{
+    game_board::GameBoard,
+    solvers::{
+        Solver, SolverIdentifier,
+        solution::{
+            Action, Candidate, ConfirmationDetails, EliminationDetails, Solution,
+            distinct_by_actions,
+        },
+    },
+    utils::{BitMap, Coord},
+};
+
+/// [SudokuWiki explanations on Pattern Overlay](https://www.sudokuwiki.org/Pattern_Overlay)
+/// ## Terminology
+/// - A **Template** is one of the 46656 ways to place a digit 9 times, once in each row, column and box.
+/// - Only the templates going through the placed digit and the candidates of the digit in every row are kept.
+/// - A candidate covered by none of the templates is eliminated, and a cell covered by all of them is confirmed.
+/// - A digit without any template means the board can't be finished anymore.
+/// ## Return Format
+/// - **Actions**: Contains variable number of elements, representing the eliminated candidates of a single digit,
+///   or the confirmed cells if nothing is eliminated.
+/// - **House Clues**: An empty vector.
+/// - **Candidate Clues**: Contains variable number of elements, representing the candidates of the digit covered by at least one template.
+pub struct PatternOverlay;
+
+/// The templates of a digit consistent with the board, each row represented by a BitMap of the columns
+pub struct Templates {
+    count: usize,
+    covered: [BitMap; 9],
+    common: [BitMap; 9],
+}
+
+impl Templates {
+    pub fn new(game_board: &GameBoard, target: usize) -> Self {
+        let allowed: [BitMap; 9] =
+            std::array::from_fn(
+                |x| match (0..9).find(|&y| game_board.is_clue(x, y, target)) {
+                    Some(y) => BitMap::from(y),
+                    None => (0..9)
+                        .filter(|&y| game_board.contains_candidate(x, y, target))
+                        .collect(),
+                },
+            );
+        let mut templates = Self {
+            count: 0,
+            covered: [BitMap::new(); 9],
+            common: [BitMap::all(); 9],
+        };
+        templates.search(
+            &allowed,
+            &mut Vec::with_capacity(9),
+            BitMap::new(),
+            BitMap::new(),
+        );
+        templates
+    }
+
+    /// Depth first search placing the digit row by row
+    fn search(
+        &mut self,
+        allowed: &[BitMap; 9],
+        template: &mut Vec<usize>,
+        used_cols: BitMap,
+        used_boxes: BitMap,
+    ) {
+        let x = template.len();
+        if x == 9 {
+            self.count += 1;
+            for (row, &y) in template.iter().enumerate() {
+                self.covered[row].insert(y);
+                self.common[row] = self.common[row].intersect(BitMap::from(y));
+            }
+            return;
+        }
+        for y in allowed[x].difference(used_cols).iter_ones() {
+            let box_id = x / 3 * 3 + y / 3;
+            if used_boxes.contains(box_id) {
+                continue;
+            }
+            template.push(y);
+            self.search(
+                allowed,
+                template,
+                used_cols.union(BitMap::from(y)),
+                used_boxes.union(BitMap::from(box_id)),
+            );
+            template.pop();
+        }
+    }
+
+    /// The number of templates
+    pub fn count(&self) -> usize {
+        self.count
+    }
+
+    /// Returns true if cell (x,y) is covered by at least one template
+    pub fn covers(&self, x: usize, y: usize) -> bool {
+        self.covered[x].contains(y)
+    }
+
+    /// Returns true if cell (x,y) is covered by every template
+    pub fn always_covers(&self, x: usize, y: usize) -> bool {
+        self.count > 0 && self.common[x].contains(y)
+    }
+}
+
+impl PatternOverlay {
+    /// Returns true if every digit still has a template, a cheap consistency check of the candidates
+    pub fn is_consistent(game_board: &GameBoard) -> bool {
+        (0..9).all(|target| Templates::new(game_board, target).count() > 0)
+    }
+
+    fn try_get_solution(&self, game_board: &GameBoard, target: usize) -> Option<Solution> {
+        let templates = Templates::new(game_board, target);
+        if templates.count() == 0 {
+            return None;
+        }
+        let candidates: Vec<_> = Coord::all_cells()
+            .filter(|&(x, y)| game_board.contains_candidate(x, y, target))
+            .collect();
+        let eliminations: Vec<_> = candidates
+            .iter()
+            .filter(|&&(x, y)| !templates.covers(x, y))
+            .map(|&(x, y)| {
+                Action::Elimination(EliminationDetails {
+                    x,
+                    y,
+                    target: BitMap::from(target),
+                })
+            })
+            .collect();
+        let actions = if eliminations.is_empty() {
+            candidates
+                .iter()
+                .filter(|&&(x, y)| templates.always_covers(x, y))
+                .map(|&(x, y)| Action::Confirmation(ConfirmationDetails { x, y, target }))
+                .collect()
+        } else {
+            eliminations
+        };
+        (!actions.is_empty()).then(|| Solution {
+            actions,
+            house_clues: vec![],
+            candidate_clues: candidates
+                .iter()
+                .filter(|&&(x, y)| templates.covers(x, y))
+                .map(|&(x, y)| Candidate::new_single(x, y, target))
+                .collect(),
+            solver_id: self.solver_id(),
+        })
+    }
+}
+
+impl Solver for PatternOverlay {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        (0..9).find_map(|target| self.try_get_solution(game_board, target))
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions((0..9).filter_map(|target| self.try_get_solution(game_board, target)))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::PatternOverlay
+    }
+}
+
+#[cfg(test)]
+mod pattern_overlay_test {
+    use super::*;
+    use crate::tests::common::test_function_e;
+
+    #[test]
+    fn pattern_overlay_test_1() {
+        test_function_e(
+            PatternOverlay,
+            [
+                940, 808, 2, 1, 576, 816, 524, 692, 944, 16, 1, 928, 928, 8, 4, 514, 672, 64, 64,
+                808, 940, 944, 786, 818, 525, 677, 944, 803, 528, 801, 832, 771, 520, 580, 710,
+                642, 643, 578, 705, 516, 32, 515, 16, 256, 8, 778, 4, 776, 848, 786, 128, 32, 578,
+                1, 803, 866, 865, 8, 640, 817, 577, 627, 4, 557, 128, 621, 2, 533, 561, 256, 633,
+                560, 815, 810, 16, 800, 773, 64, 128, 555, 546,
+            ],
+            vec![(6, 0), (6, 2)], //exp_actions
+            vec![256, 256],       //exp_action_targets
+            vec![],               //exp_house_clues
+            vec![
+                (0, 0),
+                (0, 1),
+                (0, 5),
+                (0, 8),
+                (1, 2),
+                (1, 3),
+                (2, 1),
+                (2, 2),
+                (2, 3),
+                (2, 4),
+                (2, 5),
+                (2, 8),
+                (3, 0),
+                (3, 2),
+                (3, 3),
+                (3, 4),
+                (5, 0),
+                (5, 2),
+                (5, 3),
+                (5, 4),
+                (6, 1),
+                (6, 5),
+                (8, 0),
+                (8, 1),
+                (8, 3),
+                (8, 4),
+            ], //exp_candidate_clues
+            vec![
+                256, 256, 256, 256, 256, 256, 256, 256, 256, 256, 256, 256, 256, 256, 256, 256,
+                256, 256, 256, 256, 256, 256, 256, 256, 256, 256,
+            ], //exp_candidate_masks
+        );
+    }
+
+    #[test]
+    fn pattern_overlay_test_2() {
+        test_function_e(
+            PatternOverlay,
+            [
+                720, 529, 745, 256, 650, 642, 610, 4, 674, 706, 610, 744, 1, 654, 646, 866, 832,
+                16, 4, 256, 642, 32, 528, 64, 8, 513, 642, 520, 608, 16, 128, 837, 773, 865, 2,
+                545, 1, 610, 866, 520, 834, 786, 880, 640, 4, 770, 128, 516, 594, 835, 32, 785,
+                832, 8, 722, 4, 705, 594, 707, 8, 515, 544, 256, 914, 529, 899, 530, 544, 915, 516,
+                520, 64, 32, 8, 835, 516, 834, 771, 128, 528, 515,
+            ],
+            vec![(1, 1), (1, 2), (4, 2)], //exp_actions
+            vec![64, 64, 64],             //exp_action_targets
+            vec![],                       //exp_house_clues
+            vec![
+                (0, 0),
+                (0, 2),
+                (0, 6),
+                (1, 0),
+                (1, 6),
+                (1, 7),
+                (3, 1),
+                (3, 4),
+                (3, 6),
+                (4, 1),
+                (4, 4),
+                (4, 6),
+                (5, 3),
+                (5, 4),
+                (5, 7),
+                (6, 0),
+                (6, 2),
+                (6, 3),
+                (6, 4),
+                (8, 2),
+                (8, 4),
+            ], //exp_candidate_clues
+            vec![
+                64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64, 64,
+            ], //exp_candidate_masks
+        );
+    }
+
+    #[test]
+    fn pattern_overlay_consistency_test() {
+        let mut raws = [
+            940, 808, 2, 1, 576, 816, 524, 692, 944, 16, 1, 928, 928, 8, 4, 514, 672, 64, 64, 808,
+            940, 944, 786, 818, 525, 677, 944, 803, 528, 801, 832, 771, 520, 580, 710, 642, 643,
+            578, 705, 516, 32, 515, 16, 256, 8, 778, 4, 776, 848, 786, 128, 32, 578, 1, 803, 866,
+            865, 8, 640, 817, 577, 627, 4, 557, 128, 621, 2, 533, 561, 256, 633, 560, 815, 810, 16,
+            800, 773, 64, 128, 555, 546,
+        ];
+        assert!(PatternOverlay::is_consistent(&GameBoard::from_array(raws)));
+        // without the candidate 9 in the first row, the digit has no template
+        for raw in raws.iter_mut().take(9).filter(|raw| **raw & 512 != 0) {
+            *raw &= !256;
+        }
+        assert!(!PatternOverlay::is_consistent(&GameBoard::from_array(raws)));
+    }
+}
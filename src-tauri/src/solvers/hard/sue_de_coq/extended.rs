@@ -0,0 +1,203 @@
+use std::iter::once;
+
+use crate::{
+    game_board::GameBoard,
+    solvers::{
+        Solver, SolverIdentifier,
+        solution::{Action, Candidate, Solution},
+    },
+    utils::{BitMap, Coord, House},
+};
+
+use super::{SueDeCoq, Yoke};
+
+/// The cells in the box (or the line) other than the yoke, which are part of the pattern
+#[derive(Clone, Copy)]
+struct Wing {
+    house: House,
+    indices: BitMap,
+    candidates: BitMap,
+}
+
+impl Wing {
+    fn new(game_board: &GameBoard, house: House, indices: BitMap) -> Self {
+        Self {
+            house,
+            indices,
+            candidates: indices
+                .iter_ones()
+                .filter_map(|i| {
+                    let (x, y) = Coord::from_house_and_index(&house, i);
+                    game_board.get_candidates(x, y)
+                })
+                .fold(BitMap::new(), |acc, candidates| acc.union(candidates)),
+        }
+    }
+
+    fn cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.indices
+            .iter_ones()
+            .map(|i| Coord::from_house_and_index(&self.house, i))
+    }
+}
+
+/// Every combination of the cells in the house outside the intersection, which share at least one
+/// candidate with the yoke
+fn iter_wings(
+    game_board: &GameBoard,
+    house: House,
+    intersection: BitMap,
+    yoke: Yoke,
+) -> impl Iterator<Item = Wing> + '_ {
+    let pool: BitMap = (0..9)
+        .filter(|&i| {
+            let (x, y) = Coord::from_house_and_index(&house, i);
+            !intersection.contains(i)
+                && game_board
+                    .get_candidates(x, y)
+                    .is_some_and(|candidates| candidates.intersect(yoke.candidates()).count() > 0)
+        })
+        .collect();
+    (1..=pool.count())
+        .flat_map(move |n| BitMap::get_combos_in_subset(n, pool))
+        .map(move |indices| Wing::new(game_board, house, indices))
+}
+
+struct ExtendedSdC {
+    yoke: Yoke,
+    box_wing: Wing,
+    line_wing: Wing,
+}
+
+impl ExtendedSdC {
+    fn line(&self) -> House {
+        House::from_dim_id(self.yoke.line_dim(), self.yoke.line_id())
+    }
+
+    /// Candidates in both wings can appear twice, once in each wing, the others can appear once.
+    /// The pattern holds when the cells can only be filled by using up every candidate.
+    fn is_locked(&self) -> bool {
+        let shared = self
+            .box_wing
+            .candidates
+            .intersect(self.line_wing.candidates);
+        let all = self
+            .yoke
+            .candidates()
+            .union(self.box_wing.candidates)
+            .union(self.line_wing.candidates);
+        all.count() + shared.count()
+            == self.yoke.indices_in_box().count()
+                + self.box_wing.indices.count()
+                + self.line_wing.indices.count()
+    }
+
+    fn yoke_cells(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..9)
+            .filter(|&i| self.yoke.indices_in_box().contains(i))
+            .map(|i| Coord::from_house_and_index(&House::Box(self.yoke.box_id()), i))
+    }
+
+    fn actions(&self, game_board: &GameBoard) -> Vec<Action> {
+        let box_candidates = self.box_wing.candidates;
+        let line_candidates = self.line_wing.candidates;
+        let shared = box_candidates.intersect(line_candidates);
+        // candidates only in the yoke are locked in the yoke
+        let yoke_only = self
+            .yoke
+            .candidates()
+            .difference(box_candidates)
+            .difference(line_candidates);
+        let box_mask = box_candidates.difference(line_candidates).union(yoke_only);
+        let line_mask = line_candidates.difference(box_candidates).union(yoke_only);
+        let box_rest = (0..9)
+            .filter(|&i| {
+                !self.yoke.indices_in_box().contains(i) && !self.box_wing.indices.contains(i)
+            })
+            .map(|i| Coord::from_house_and_index(&House::Box(self.yoke.box_id()), i))
+            .map(|(x, y)| (x, y, box_mask.union(shared)));
+        let line_rest = (0..9)
+            .filter(|&i| {
+                !self.yoke.indices_in_line().contains(i) && !self.line_wing.indices.contains(i)
+            })
+            .map(|i| Coord::from_house_and_index(&self.line(), i))
+            .map(|(x, y)| (x, y, line_mask.union(shared)));
+        // candidates in both wings are used up by the wings, so they can't be in the yoke
+        let yoke_cells = self.yoke_cells().map(|(x, y)| (x, y, shared));
+        box_rest
+            .chain(line_rest)
+            .chain(yoke_cells)
+            .filter_map(|(x, y, mask)| SueDeCoq::valid_target(game_board, x, y, mask))
+            .collect()
+    }
+
+    fn candidate_clues(&self, game_board: &GameBoard) -> Vec<Candidate> {
+        let to_clue = |(x, y): (usize, usize)| {
+            game_board
+                .get_candidates(x, y)
+                .map(|candidates| Candidate::new(x, y, candidates))
+        };
+        self.box_wing
+            .cells()
+            .filter_map(to_clue)
+            .chain(once(Candidate::SEPARATOR))
+            .chain(self.yoke_cells().filter_map(to_clue))
+            .chain(once(Candidate::SEPARATOR))
+            .chain(self.line_wing.cells().filter_map(to_clue))
+            .collect()
+    }
+
+    fn try_get_solution(
+        &self,
+        game_board: &GameBoard,
+        solver_id: SolverIdentifier,
+    ) -> Option<Solution> {
+        if !self.is_locked() {
+            return None;
+        }
+        let actions = self.actions(game_board);
+        (!actions.is_empty()).then(|| Solution {
+            actions,
+            house_clues: vec![House::Box(self.yoke.box_id()), self.line()],
+            candidate_clues: self.candidate_clues(game_board),
+            solver_id,
+        })
+    }
+}
+
+impl SueDeCoq {
+    /// The generalized form, where the box cells and the line cells don't have to be ALSs and may
+    /// share candidates
+    pub(super) fn find_extended(&self, game_board: &GameBoard, yoke: Yoke) -> Option<Solution> {
+        // the yoke still needs at least 2 more candidates than cells
+        if yoke.candidates().count() < yoke.indices_in_box().count() + 2 {
+            return None;
+        }
+        let box_house = House::Box(yoke.box_id());
+        let line = House::from_dim_id(yoke.line_dim(), yoke.line_id());
+        // cells in the intersection but not in the yoke are in neither wing
+        let box_intersection: BitMap = (0..9)
+            .filter(|&i| {
+                let (x, y) = Coord::from_house_and_index(&box_house, i);
+                Coord::is_in_house(x, y, &line)
+            })
+            .collect();
+        let line_intersection: BitMap = (0..9)
+            .filter(|&i| {
+                let (x, y) = Coord::from_house_and_index(&line, i);
+                Coord::get_box_id(x, y) == yoke.box_id()
+            })
+            .collect();
+        let line_wings: Vec<_> = iter_wings(game_board, line, line_intersection, yoke).collect();
+        iter_wings(game_board, box_house, box_intersection, yoke).find_map(|box_wing| {
+            line_wings.iter().find_map(|&line_wing| {
+                ExtendedSdC {
+                    yoke,
+                    box_wing,
+                    line_wing,
+                }
+                .try_get_solution(game_board, self.solver_id())
+            })
+        })
+    }
+}
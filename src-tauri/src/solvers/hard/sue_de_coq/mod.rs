@@ -4,7 +4,7 @@ use crate::{
     game_board::GameBoard,
     solvers::{
         Solver, SolverIdentifier,
-        solution::{Action, Candidate, EliminationDetails, Solution},
+        solution::{Action, Candidate, EliminationDetails, Solution, distinct_by_actions},
     },
     utils::{BitMap, Coord, House, HouseType},
 };
@@ -17,10 +17,20 @@ use crate::{
 /// - If the Yoke contains 3 cells and an extra candidate doesn't appear in nither Orion nor
 ///   Scorpius, the extra candidate is locked in the Yoke and shouldn't be anywher else in the box
 ///   nor the line. It's called rotten yoke.
+/// - If Orion and Scorpius share candidates and every candidate is used up, each shared candidate
+///   is placed once in the Orion and once in the Scorpius, so it's eliminated from the Yoke. It's
+///   called cannibalism.
+///
+/// ## Extended Form
+/// When no classic Sue De Coq is found, the box cells and the line cells may be any group of cells
+/// sharing candidates with the Yoke rather than a single ALS. A candidate in both groups can be
+/// placed twice, so the pattern holds when the number of candidates plus the number of shared
+/// candidates equals the number of cells.
 ///
 /// ## Return Format
 /// - **Actions**: Contains variable number of elements,representing the all the candidates to be
-///   eliminated in each cell, box cells first and followed by line cells.
+///   eliminated in each cell, box cells first and followed by line cells, then the Yoke cells for
+///   cannibalism.
 /// - **House Clues**: Contains 2 elements, representing the box and the line.
 /// - **Candidate Clues**: Contains 4 or 5 groups of elements, seperated by the Candidate::SEPARATOR:
 ///     - Orion candidates
@@ -28,7 +38,10 @@ use crate::{
 ///     - Scorpius candidates
 ///     - Yoke candidates which overlap with the Scorpius
 ///     - (optional) rotten yoke candidates in each yoke cell
+///
+///   The extended form contains 3 groups instead: the box cells, the Yoke cells and the line cells.
 pub struct SueDeCoq;
+mod extended;
 mod yoke;
 use scorpius::Scorpius;
 use yoke::Yoke;
@@ -37,12 +50,11 @@ use orion::Orion;
 mod scorpius;
 impl Solver for SueDeCoq {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        Self::iter_possible_yokes(game_board)
-            .flat_map(|yoke| Self::iter_valid_orion(game_board, yoke))
-            .flat_map(|(orion, yoke)| Self::iter_valid_scorpius(game_board, yoke, orion))
-            .find_map(|(yoke, orion, scorpius)| {
-                self.generate_solution(game_board, yoke, orion, scorpius)
-            })
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
     }
 
     fn solver_id(&self) -> crate::solvers::SolverIdentifier {
@@ -51,6 +63,23 @@ impl Solver for SueDeCoq {
 }
 
 impl SueDeCoq {
+    /// The classic patterns first, then the extended ones
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        Self::iter_possible_yokes(game_board)
+            .flat_map(move |yoke| Self::iter_valid_orion(game_board, yoke))
+            .flat_map(move |(orion, yoke)| Self::iter_valid_scorpius(game_board, yoke, orion))
+            .filter_map(move |(yoke, orion, scorpius)| {
+                self.generate_solution(game_board, yoke, orion, scorpius)
+            })
+            .chain(
+                Self::iter_possible_yokes(game_board)
+                    .filter_map(move |yoke| self.find_extended(game_board, yoke)),
+            )
+    }
+
     fn iter_possible_yokes(game_board: &GameBoard) -> impl Iterator<Item = Yoke> + '_ {
         (0..2).flat_map(move |line_dim| {
             (0..9).flat_map(move |line_id| {
@@ -117,6 +146,26 @@ impl SueDeCoq {
                 Self::valid_target(game_board, x, y, scorpius.candidates().union(rotten_yoke))
             })
     }
+    /// When Orion and Scorpius share candidates and every candidate is used up, the shared
+    /// candidates are placed once in each of them, so they can't be in the yoke
+    fn cannibal_targets(
+        game_board: &GameBoard,
+        yoke: Yoke,
+        orion: Orion,
+        scorpius: Scorpius,
+    ) -> impl Iterator<Item = Action> + '_ {
+        let shared = orion.candidates().intersect(scorpius.candidates());
+        let all = yoke
+            .candidates()
+            .union(orion.candidates())
+            .union(scorpius.candidates());
+        let locked = all.count() + shared.count()
+            == yoke.indices_in_box().count() + orion.indices().count() + scorpius.indices().count();
+        (0..9)
+            .filter(move |&i| locked && yoke.indices_in_box().contains(i))
+            .map(move |i| Coord::from_house_and_index(&House::Box(yoke.box_id()), i))
+            .filter_map(move |(x, y)| Self::valid_target(game_board, x, y, shared))
+    }
     fn cell_to_candidate_clue(
         game_board: &GameBoard,
         x: usize,
@@ -219,6 +268,7 @@ impl SueDeCoq {
             .difference(scorpius.candidates());
         let actions: Vec<Action> = Self::box_targets(game_board, yoke, orion, rotten_yoke)
             .chain(Self::line_targets(game_board, yoke, scorpius, rotten_yoke))
+            .chain(Self::cannibal_targets(game_board, yoke, orion, scorpius))
             .collect();
         if actions.is_empty() {
             None
@@ -455,4 +505,95 @@ mod sue_de_coq_test {
             ], //exp candidate_clue masks
         )
     }
+    #[test]
+    fn sue_de_coq_cannibalism_test() {
+        test_function_e(
+            SueDeCoq,
+            [
+                676, 256, 16, 678, 8, 679, 545, 613, 709, 1, 548, 672, 932, 788, 64, 8, 564, 2,
+                520, 576, 514, 676, 661, 677, 561, 256, 660, 644, 519, 256, 520, 710, 16, 515, 583,
+                32, 64, 567, 672, 932, 902, 932, 787, 8, 789, 564, 566, 8, 1, 838, 804, 128, 582,
+                852, 800, 128, 576, 16, 773, 781, 803, 547, 777, 784, 536, 516, 770, 32, 779, 64,
+                128, 793, 2, 568, 1, 576, 896, 904, 4, 560, 792,
+            ],
+            vec![(4, 6), (0, 8), (2, 8), (4, 8)], //exp_actions
+            vec![3, 1, 16, 1],                    //exp_action_targets
+            vec![Box(5), Col(8)],                 //exp_house_clues
+            vec![
+                (3, 6),
+                (3, 7),
+                (5, 7),
+                (0, 0),
+                (4, 8),
+                (5, 8),
+                (0, 0),
+                (6, 8),
+                (7, 8),
+                (8, 8),
+                (0, 0),
+                (4, 8),
+                (5, 8),
+                (0, 0),
+            ], //exp_candidate_clues
+            vec![
+                3, 71, 70, 65535, 5, 68, 65535, 265, 281, 280, 65535, 273, 272, 65535,
+            ], //exp_candidate_masks
+        )
+    }
+    #[test]
+    fn sue_de_coq_extended_test_1() {
+        test_function_e(
+            SueDeCoq,
+            [
+                673, 4, 930, 896, 899, 770, 16, 8, 576, 64, 529, 896, 32, 657, 520, 773, 2, 901,
+                665, 531, 906, 516, 787, 64, 769, 641, 32, 548, 256, 610, 656, 8, 547, 613, 709,
+                661, 568, 128, 616, 784, 4, 801, 2, 577, 537, 572, 562, 1, 64, 642, 674, 556, 256,
+                668, 673, 609, 676, 2, 896, 528, 845, 581, 781, 2, 577, 644, 8, 32, 896, 837, 16,
+                773, 256, 520, 16, 513, 576, 516, 128, 544, 514,
+            ],
+            vec![(4, 8), (3, 2), (3, 5)], //exp_actions
+            vec![1, 32, 32],              //exp_action_targets
+            vec![Box(5), Row(3)],         //exp_house_clues
+            vec![
+                (4, 7),
+                (0, 0),
+                (3, 6),
+                (3, 7),
+                (3, 8),
+                (0, 0),
+                (3, 0),
+                (3, 3),
+            ], //exp_candidate_clues
+            vec![65, 65535, 101, 197, 149, 65535, 36, 144], //exp_candidate_masks
+        )
+    }
+    #[test]
+    fn sue_de_coq_extended_test_2() {
+        test_function_e(
+            SueDeCoq,
+            [
+                513, 16, 32, 704, 712, 712, 2, 516, 768, 900, 900, 2, 532, 560, 1, 8, 64, 672, 64,
+                652, 648, 518, 256, 550, 1, 528, 672, 904, 904, 1, 960, 4, 968, 32, 514, 16, 514,
+                680, 4, 656, 568, 696, 768, 513, 576, 800, 64, 16, 513, 2, 800, 516, 128, 520, 540,
+                525, 584, 32, 593, 598, 128, 256, 518, 660, 2, 896, 8, 656, 916, 64, 544, 1, 676,
+                677, 960, 838, 705, 962, 528, 520, 518,
+            ],
+            vec![(8, 3), (3, 5), (4, 5)], //exp_actions
+            vec![64, 256, 32],            //exp_action_targets
+            vec![Box(7), Col(5)],         //exp_house_clues
+            vec![
+                (6, 4),
+                (7, 4),
+                (8, 4),
+                (0, 0),
+                (6, 5),
+                (7, 5),
+                (8, 5),
+                (0, 0),
+                (2, 5),
+                (5, 5),
+            ], //exp_candidate_clues
+            vec![81, 144, 193, 65535, 86, 404, 450, 65535, 38, 288], //exp_candidate_masks
+        )
+    }
 }
@@ -0,0 +1,157 @@
+use std::collections::VecDeque;
+
+use crate::{
+    game_board::GameBoard,
+    solvers::solution::Candidate,
+    utils::{BitMap, Coord, HouseType},
+};
+
+/// Candidate `target` in cell (x,y) being true (`on`) or false
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) struct Literal {
+    pub x: usize,
+    pub y: usize,
+    pub target: usize,
+    pub on: bool,
+}
+
+const LITERAL_CNT: usize = 81 * 9 * 2;
+
+impl Literal {
+    pub fn new(x: usize, y: usize, target: usize, on: bool) -> Self {
+        Self { x, y, target, on }
+    }
+
+    fn index(&self) -> usize {
+        ((self.x * 9 + self.y) * 9 + self.target) * 2 + self.on as usize
+    }
+
+    pub fn negate(&self) -> Self {
+        Self {
+            on: !self.on,
+            ..*self
+        }
+    }
+
+    /// A true candidate is represented by its own mask, a false one by the complement
+    pub fn as_candidate(&self) -> Candidate {
+        let mask = BitMap::from(self.target);
+        Candidate::new(
+            self.x,
+            self.y,
+            if self.on { mask } else { mask.complement() },
+        )
+    }
+
+    /// The literals directly implied by this one
+    fn implications(&self, game_board: &GameBoard) -> Vec<Literal> {
+        let (x, y, target) = (self.x, self.y, self.target);
+        let Some(candidates) = game_board.get_candidates(x, y) else {
+            return vec![];
+        };
+        let mut res = vec![];
+        if self.on {
+            // the other candidates in the cell, and the same candidate in the peers are false
+            res.extend(
+                candidates
+                    .iter_ones()
+                    .filter(|&other| other != target)
+                    .map(|other| Literal::new(x, y, other, false)),
+            );
+            res.extend(
+                Coord::seeable_cells(x, y)
+                    .filter(|&(sx, sy)| game_board.contains_candidate(sx, sy, target))
+                    .map(|(sx, sy)| Literal::new(sx, sy, target, false)),
+            );
+        } else {
+            // the other candidate of a bi-value cell, and the other end of a hard link are true
+            if candidates.count() == 2 {
+                res.extend(
+                    candidates
+                        .iter_ones()
+                        .filter(|&other| other != target)
+                        .map(|other| Literal::new(x, y, other, true)),
+                );
+            }
+            res.extend(
+                (0..3)
+                    .filter_map(|dim| {
+                        game_board.get_hard_link(x, y, target, HouseType::from_dim(dim))
+                    })
+                    .map(|(hx, hy)| Literal::new(hx, hy, target, true)),
+            );
+        }
+        res
+    }
+}
+
+/// The shortest implication chains starting from a single assumption, each link flips between true and false.
+pub(crate) struct Implications {
+    literals: Vec<Option<Literal>>,
+    parent: Vec<usize>,
+    dist: Vec<Option<usize>>,
+}
+
+impl Implications {
+    /// Breadth first search of all the literals implied by `start` with at most `max_length` links
+    pub fn new(game_board: &GameBoard, start: Literal, max_length: usize) -> Self {
+        let mut res = Self {
+            literals: vec![None; LITERAL_CNT],
+            parent: vec![start.index(); LITERAL_CNT],
+            dist: vec![None; LITERAL_CNT],
+        };
+        res.literals[start.index()] = Some(start);
+        res.dist[start.index()] = Some(0);
+        let mut queue = VecDeque::from([start]);
+        while let Some(cur) = queue.pop_front() {
+            let dist = res.dist[cur.index()].expect("queued literals are reached");
+            if dist == max_length {
+                continue;
+            }
+            for next in cur.implications(game_board) {
+                if res.dist[next.index()].is_none() {
+                    res.literals[next.index()] = Some(next);
+                    res.dist[next.index()] = Some(dist + 1);
+                    res.parent[next.index()] = cur.index();
+                    queue.push_back(next);
+                }
+            }
+        }
+        res
+    }
+
+    pub fn implies(&self, literal: Literal) -> bool {
+        self.dist[literal.index()].is_some()
+    }
+
+    /// All the implied literals, except the assumption itself
+    pub fn iter_implied(&self) -> impl Iterator<Item = Literal> + '_ {
+        self.literals
+            .iter()
+            .zip(&self.dist)
+            .filter(|&(_, dist)| dist.is_some_and(|dist| dist > 0))
+            .filter_map(|(literal, _)| *literal)
+    }
+
+    /// A literal implied to be both true and false, which means the assumption is false
+    pub fn contradiction(&self) -> Option<Literal> {
+        self.literals
+            .iter()
+            .flatten()
+            .find(|literal| literal.on && self.implies(literal.negate()))
+            .copied()
+    }
+
+    /// The chain from the assumption to the given literal, if it's implied
+    pub fn chain_to(&self, end: Literal) -> Option<Vec<Literal>> {
+        let mut cur = end.index();
+        self.dist[cur]?;
+        let mut chain = vec![end];
+        while self.dist[cur] != Some(0) {
+            cur = self.parent[cur];
+            chain.push(self.literals[cur].expect("parents are reached"));
+        }
+        chain.reverse();
+        Some(chain)
+    }
+}
@@ -1,13 +1,13 @@
 use crate::{
     game_board::GameBoard,
     solvers::{
-        solution::{Action, Candidate, EliminationDetails, Solution},
         SolverIdentifier,
+        solution::{Action, Candidate, EliminationDetails, Solution},
     },
-    utils::{BitMap, Coord},
+    utils::{BitMap, CellSet, Coord},
 };
 
-use super::{colorizer::Color, Colorizer};
+use super::{Colorizer, colorizer::Color};
 #[derive(Clone)]
 struct ExclusiveMap {
     pub map: Vec<bool>,
@@ -23,11 +23,6 @@ impl ExclusiveMap {
     pub fn set(&mut self, color: Color) {
         self.map[color.as_index()] = true
     }
-    pub fn union_with(&mut self, other: &ExclusiveMap) {
-        for i in 0..self.map.len() {
-            self.map[i] |= other.map[i]
-        }
-    }
 
     pub fn check(&self, color: Color) -> bool {
         self.map[color.as_index()]
@@ -35,6 +30,10 @@ impl ExclusiveMap {
 }
 pub struct Analyzer {
     exclusions: Vec<ExclusiveMap>,
+    /// The cells of each color
+    cells: Vec<CellSet>,
+    /// The cells seeing at least one cell of each color
+    seen: Vec<CellSet>,
     colorizer: Colorizer,
 }
 
@@ -44,8 +43,19 @@ impl Analyzer {
     }
     pub fn new(colorizer: Colorizer) -> Self {
         let cnt = colorizer.color_cnt;
+        let mut cells = vec![CellSet::new(); cnt * 2];
+        let mut seen = vec![CellSet::new(); cnt * 2];
+        for (x, y) in Coord::all_cells() {
+            let color = colorizer.color[x][y];
+            if color.colored() {
+                cells[color.as_index()].insert(x, y);
+                seen[color.as_index()] = seen[color.as_index()].union(CellSet::peers(x, y));
+            }
+        }
         let mut res = Self {
             exclusions: vec![ExclusiveMap::new(cnt); cnt * 2],
+            cells,
+            seen,
             colorizer,
         };
         for i in 0..cnt {
@@ -55,43 +65,118 @@ impl Analyzer {
         res
     }
 
+    /// All the colors of the clusters
+    fn colors(&self) -> impl Iterator<Item = Color> + use<> {
+        (0..self.colorizer.color_cnt)
+            .flat_map(|cluster| [Color::Light(cluster), Color::Dark(cluster)])
+    }
+
+    /// Returns true if some cell of the color sees some cell of the other color
+    fn sees_color(&self, color: Color, other: Color) -> bool {
+        !self.seen[color.as_index()]
+            .intersect(self.cells[other.as_index()])
+            .is_empty()
+    }
+
     pub fn calculate_exclusions(&mut self) {
-        for (x, y) in Coord::all_cells() {
-            if self.colorizer.color[x][y].colored() {
-                let cur_color_flip = self.colorizer.color[x][y]
-                    .other()
-                    .expect("this cell is guaranteed to be colored");
-                for (sx, sy) in Coord::seeable_cells(x, y) {
-                    if self.colorizer.color[sx][sy].colored() {
-                        let see_color_flip = self.colorizer.color[sx][sy]
-                            .other()
-                            .expect("this cell is guaranteed to be colored");
-                        self.exclusions[cur_color_flip.as_index()].set(see_color_flip);
-                    }
+        for color in self.colors() {
+            for other in self.colors() {
+                if self.sees_color(color, other) {
+                    let cur_color_flip =
+                        color.other().expect("colors are guaranteed to be colored");
+                    let see_color_flip =
+                        other.other().expect("colors are guaranteed to be colored");
+                    self.exclusions[cur_color_flip.as_index()].set(see_color_flip);
                 }
             }
         }
     }
 
-    pub fn try_find_contradiction(&self, game_board: &GameBoard) -> Option<(usize, usize)> {
-        for (x, y) in Coord::all_cells() {
-            if game_board.contains_candidate(x, y, self.colorizer.target) {
-                let mut exclusive = ExclusiveMap::new(self.colorizer.color_cnt);
-                for (sx, sy) in Coord::seeable_cells(x, y) {
-                    let color = self.colorizer.color[sx][sy];
-                    if color.colored() {
-                        if exclusive.check(color) {
-                            return Some((x, y));
-                        }
-                        exclusive.union_with(self.get_exclusion(color));
-                    }
-                }
+    /// All the colors seen by cell (x,y)
+    fn seen_colors(&self, x: usize, y: usize) -> ExclusiveMap {
+        let mut seen = ExclusiveMap::new(self.colorizer.color_cnt);
+        for color in self.colors() {
+            if self.seen[color.as_index()].contains(x, y) {
+                seen.set(color);
             }
         }
-        None
+        seen
     }
 
-    pub fn get_coloring_vector(&self) -> Vec<Candidate> {
+    /// Returns true if both colors of the cluster appear in the map
+    fn both_colors(map: &ExclusiveMap, cluster: usize) -> bool {
+        map.check(Color::Light(cluster)) && map.check(Color::Dark(cluster))
+    }
+
+    fn own_cluster(&self, x: usize, y: usize) -> Option<usize> {
+        let color = self.colorizer.color[x][y];
+        color.colored().then(|| color.cluster())
+    }
+
+    /// The candidates which see both colors of a cluster other than their own
+    fn iter_traps<'a>(
+        &'a self,
+        game_board: &GameBoard,
+    ) -> impl Iterator<Item = (usize, usize, usize)> + 'a {
+        game_board
+            .candidate_cells(self.colorizer.target)
+            .iter()
+            .filter_map(|(x, y)| {
+                let seen = self.seen_colors(x, y);
+                (0..self.colorizer.color_cnt)
+                    .filter(|&cluster| self.own_cluster(x, y) != Some(cluster))
+                    .find(|&cluster| Self::both_colors(&seen, cluster))
+                    .map(|cluster| (x, y, cluster))
+            })
+    }
+
+    /// The colors which appear twice in a house, once per cell of the color seeing another one
+    fn iter_wraps(&self) -> impl Iterator<Item = Color> + '_ {
+        Coord::all_cells().filter_map(|(x, y)| {
+            let color = self.colorizer.color[x][y];
+            (color.colored() && self.seen[color.as_index()].contains(x, y)).then_some(color)
+        })
+    }
+
+    /// The colors which see both colors of another cluster,
+    /// along with the other cluster
+    fn iter_false_colors(&self) -> impl Iterator<Item = (Color, usize)> + '_ {
+        self.colors().filter_map(|color| {
+            (0..self.colorizer.color_cnt)
+                .filter(|&other| other != color.cluster())
+                .find(|&other| {
+                    self.sees_color(color, Color::Light(other))
+                        && self.sees_color(color, Color::Dark(other))
+                })
+                .map(|other| (color, other))
+        })
+    }
+
+    /// The candidates which see two colors from different clusters, which can't be false at the same time,
+    /// along with the two clusters
+    fn iter_weak_linked_colors<'a>(
+        &'a self,
+        game_board: &GameBoard,
+    ) -> impl Iterator<Item = (usize, usize, usize, usize)> + 'a {
+        game_board
+            .candidate_cells(self.colorizer.target)
+            .iter()
+            .filter_map(|(x, y)| {
+                let seen = self.seen_colors(x, y);
+                let seen_colors: Vec<Color> =
+                    self.colors().filter(|&color| seen.check(color)).collect();
+                seen_colors.iter().find_map(|&first| {
+                    seen_colors
+                        .iter()
+                        .filter(|second| second.cluster() != first.cluster())
+                        .find(|&&second| self.get_exclusion(first).check(second))
+                        .map(|second| (x, y, first.cluster(), second.cluster()))
+                })
+            })
+    }
+
+    /// Collect the colored candidates of the given clusters
+    pub fn get_coloring_vector(&self, clusters: &[usize]) -> Vec<Candidate> {
         let candidates = BitMap::from(self.colorizer.target);
         let mut collection: Vec<Vec<Candidate>> = vec![vec![]; self.colorizer.color_cnt * 2];
         for (x, y) in Coord::all_cells() {
@@ -106,24 +191,92 @@ impl Analyzer {
                 Color::Uncolored => (),
             }
         }
-        collection.into_iter().flatten().collect()
+        clusters
+            .iter()
+            .flat_map(|&cluster| {
+                [
+                    collection[Color::Light(cluster).as_index()].clone(),
+                    collection[Color::Dark(cluster).as_index()].clone(),
+                ]
+            })
+            .flatten()
+            .collect()
     }
 
-    pub fn try_get_solution(
+    fn single_elimination(
         &self,
-        game_board: &GameBoard,
+        x: usize,
+        y: usize,
+        clusters: &[usize],
         solver_id: SolverIdentifier,
-    ) -> Option<Solution> {
-        let (ax, ay) = self.try_find_contradiction(game_board)?;
-        Some(Solution {
+    ) -> Solution {
+        Solution {
             actions: vec![Action::Elimination(EliminationDetails {
-                x: ax,
-                y: ay,
+                x,
+                y,
                 target: BitMap::from(self.colorizer.target),
             })],
             house_clues: vec![],
-            candidate_clues: self.get_coloring_vector(),
+            candidate_clues: self.get_coloring_vector(clusters),
             solver_id,
-        })
+        }
+    }
+
+    fn color_elimination(
+        &self,
+        color: Color,
+        clusters: &[usize],
+        solver_id: SolverIdentifier,
+    ) -> Solution {
+        Solution {
+            actions: self.cells[color.as_index()]
+                .iter()
+                .map(|(x, y)| {
+                    Action::Elimination(EliminationDetails {
+                        x,
+                        y,
+                        target: BitMap::from(self.colorizer.target),
+                    })
+                })
+                .collect(),
+            house_clues: vec![],
+            candidate_clues: self.get_coloring_vector(clusters),
+            solver_id,
+        }
+    }
+
+    pub fn trap_solutions(
+        &self,
+        game_board: &GameBoard,
+        solver_id: SolverIdentifier,
+    ) -> Vec<Solution> {
+        self.iter_traps(game_board)
+            .map(|(x, y, cluster)| self.single_elimination(x, y, &[cluster], solver_id))
+            .collect()
+    }
+
+    pub fn wrap_solutions(&self, solver_id: SolverIdentifier) -> Vec<Solution> {
+        self.iter_wraps()
+            .map(|color| self.color_elimination(color, &[color.cluster()], solver_id))
+            .collect()
+    }
+
+    /// The false colors of type 1 first, then the candidates eliminated by type 2
+    pub fn multi_colors_solutions(
+        &self,
+        game_board: &GameBoard,
+        solver_id: SolverIdentifier,
+    ) -> Vec<Solution> {
+        self.iter_false_colors()
+            .map(|(color, other)| {
+                self.color_elimination(color, &[color.cluster(), other], solver_id)
+            })
+            .chain(
+                self.iter_weak_linked_colors(game_board)
+                    .map(|(x, y, first, second)| {
+                        self.single_elimination(x, y, &[first, second], solver_id)
+                    }),
+            )
+            .collect()
     }
 }
@@ -29,6 +29,14 @@ impl Color {
         !matches!(self, Self::Uncolored)
     }
 
+    /// The index of the light-dark pair this color belongs to
+    pub fn cluster(&self) -> usize {
+        match self {
+            Color::Light(num) | Color::Dark(num) => *num,
+            Color::Uncolored => unreachable!("uncolored cell doesn't belong to any cluster"),
+        }
+    }
+
     pub fn as_index(&self) -> usize {
         match self {
             Color::Light(num) => *num << 1,
@@ -0,0 +1,265 @@
+use crate::{
+    game_board::GameBoard,
+    solvers::{
+        Solver, SolverIdentifier,
+        solution::{Action, Candidate, EliminationDetails, Solution, distinct_by_actions},
+    },
+    utils::{BitMap, Coord, HouseType},
+};
+
+use super::{ThreeDMedusa, colorizer::Color};
+
+/// Colors every candidate on the board, following hard links inside houses as well as bi-value cells
+struct MedusaColorizer {
+    // color[x][y][target] : the color of candidate target in cell (x,y)
+    color: [[[Color; 9]; 9]; 9],
+    color_cnt: usize,
+}
+
+impl MedusaColorizer {
+    fn new() -> Self {
+        Self {
+            color: [[[Color::Uncolored; 9]; 9]; 9],
+            color_cnt: 0,
+        }
+    }
+
+    /// All the candidates strongly linked to candidate target in cell (x,y)
+    fn strong_links(
+        game_board: &GameBoard,
+        x: usize,
+        y: usize,
+        target: usize,
+    ) -> impl Iterator<Item = (usize, usize, usize)> + '_ {
+        let bi_value_partner = game_board
+            .get_candidates(x, y)
+            .filter(|candidates| candidates.count() == 2 && candidates.contains(target))
+            .map(move |mut candidates| {
+                candidates.remove(target);
+                (x, y, candidates.trailing_zeros())
+            });
+        (0..3)
+            .filter_map(move |dim| {
+                game_board
+                    .get_hard_link(x, y, target, HouseType::from_dim(dim))
+                    .map(|(nx, ny)| (nx, ny, target))
+            })
+            .chain(bi_value_partner)
+    }
+
+    fn colorize_cluster(&mut self, game_board: &GameBoard, x: usize, y: usize, target: usize) {
+        let mut stack = vec![(x, y, target)];
+        self.color[x][y][target] = Color::Light(self.color_cnt);
+        while let Some((cx, cy, ct)) = stack.pop() {
+            let flipped = self.color[cx][cy][ct]
+                .other()
+                .expect("this candidate is guaranteed to be colored");
+            for (nx, ny, nt) in Self::strong_links(game_board, cx, cy, ct) {
+                if !self.color[nx][ny][nt].colored() {
+                    self.color[nx][ny][nt] = flipped;
+                    stack.push((nx, ny, nt));
+                }
+            }
+        }
+        self.color_cnt += 1;
+    }
+
+    fn colorize(&mut self, game_board: &GameBoard) {
+        for (x, y) in Coord::all_cells() {
+            for target in 0..9 {
+                if game_board.contains_candidate(x, y, target)
+                    && !self.color[x][y][target].colored()
+                    && Self::strong_links(game_board, x, y, target)
+                        .next()
+                        .is_some()
+                {
+                    self.colorize_cluster(game_board, x, y, target);
+                }
+            }
+        }
+    }
+}
+
+/// A single light-dark cluster of the medusa coloring
+struct Cluster<'a> {
+    colorizer: &'a MedusaColorizer,
+    light: Color,
+    dark: Color,
+}
+
+impl<'a> Cluster<'a> {
+    fn new(colorizer: &'a MedusaColorizer, index: usize) -> Self {
+        Self {
+            colorizer,
+            light: Color::Light(index),
+            dark: Color::Dark(index),
+        }
+    }
+
+    fn color(&self, x: usize, y: usize, target: usize) -> Color {
+        self.colorizer.color[x][y][target]
+    }
+
+    fn in_cluster(&self, color: Color) -> bool {
+        color == self.light || color == self.dark
+    }
+
+    /// The candidates in cell (x,y) with the given color
+    fn colored_in_cell(&self, x: usize, y: usize, color: Color) -> BitMap {
+        (0..9).filter(|&t| self.color(x, y, t) == color).collect()
+    }
+
+    /// Returns true if candidate target in cell (x,y) sees a target with the given color
+    fn sees_color(&self, x: usize, y: usize, target: usize, color: Color) -> bool {
+        Coord::seeable_cells(x, y).any(|(sx, sy)| self.color(sx, sy, target) == color)
+    }
+
+    /// Rule 1: a color appears twice in a cell
+    fn twice_in_cell(&self, color: Color) -> bool {
+        Coord::all_cells().any(|(x, y)| self.colored_in_cell(x, y, color).count() > 1)
+    }
+
+    /// Rule 2: a color appears twice in a house with the same candidate
+    fn twice_in_house(&self, color: Color) -> bool {
+        Coord::all_cells().any(|(x, y)| {
+            (0..9).any(|target| {
+                self.color(x, y, target) == color && self.sees_color(x, y, target, color)
+            })
+        })
+    }
+
+    /// Rule 6: all the candidates in an uncolored cell see the same color
+    fn cell_emptied_by_color(&self, game_board: &GameBoard, color: Color) -> bool {
+        Coord::all_cells().any(|(x, y)| {
+            game_board.get_candidates(x, y).is_some_and(|candidates| {
+                candidates
+                    .iter_ones()
+                    .all(|target| !self.in_cluster(self.color(x, y, target)))
+                    && candidates
+                        .iter_ones()
+                        .all(|target| self.sees_color(x, y, target, color))
+            })
+        })
+    }
+
+    fn contradicted(&self, game_board: &GameBoard, color: Color) -> bool {
+        self.twice_in_cell(color)
+            || self.twice_in_house(color)
+            || self.cell_emptied_by_color(game_board, color)
+    }
+
+    /// Eliminate every candidate with the contradicted color
+    fn eliminate_color(&self, color: Color) -> Vec<Action> {
+        Coord::all_cells()
+            .filter_map(|(x, y)| {
+                let target = self.colored_in_cell(x, y, color);
+                (target.count() > 0).then_some(Action::Elimination(EliminationDetails {
+                    x,
+                    y,
+                    target,
+                }))
+            })
+            .collect()
+    }
+
+    /// Returns true if an uncolored candidate target in cell (x,y) could be eliminated by
+    /// - Rule 3: both colors appear in the cell
+    /// - Rule 4: it sees both colors
+    /// - Rule 5: one color appears in the cell, and it sees the other color
+    fn uncolored_eliminated(&self, x: usize, y: usize, target: usize) -> bool {
+        let light_in_cell = self.colored_in_cell(x, y, self.light).count() > 0;
+        let dark_in_cell = self.colored_in_cell(x, y, self.dark).count() > 0;
+        let sees_light = self.sees_color(x, y, target, self.light);
+        let sees_dark = self.sees_color(x, y, target, self.dark);
+        // whichever color is true, the candidate is denied either by its own cell or by its peers
+        (light_in_cell || sees_light) && (dark_in_cell || sees_dark)
+    }
+
+    fn eliminate_uncolored(&self, game_board: &GameBoard) -> Vec<Action> {
+        Coord::all_cells()
+            .filter_map(|(x, y)| {
+                let target: BitMap = game_board
+                    .get_candidates(x, y)?
+                    .iter_ones()
+                    .filter(|&target| !self.in_cluster(self.color(x, y, target)))
+                    .filter(|&target| self.uncolored_eliminated(x, y, target))
+                    .collect();
+                (target.count() > 0).then_some(Action::Elimination(EliminationDetails {
+                    x,
+                    y,
+                    target,
+                }))
+            })
+            .collect()
+    }
+
+    fn get_actions(&self, game_board: &GameBoard) -> Vec<Action> {
+        if self.contradicted(game_board, self.light) {
+            self.eliminate_color(self.light)
+        } else if self.contradicted(game_board, self.dark) {
+            self.eliminate_color(self.dark)
+        } else {
+            self.eliminate_uncolored(game_board)
+        }
+    }
+
+    fn get_coloring_vector(&self) -> Vec<Candidate> {
+        let collect_color = |color: Color| {
+            Coord::all_cells().flat_map(move |(x, y)| {
+                (0..9)
+                    .filter(move |&target| self.color(x, y, target) == color)
+                    .map(move |target| match color {
+                        Color::Dark(_) => Candidate::new(x, y, BitMap::from(target).complement()),
+                        _ => Candidate::new_single(x, y, target),
+                    })
+            })
+        };
+        collect_color(self.light)
+            .chain(collect_color(self.dark))
+            .collect()
+    }
+
+    fn try_get_solution(
+        &self,
+        game_board: &GameBoard,
+        solver_id: SolverIdentifier,
+    ) -> Option<Solution> {
+        let actions = self.get_actions(game_board);
+        (!actions.is_empty()).then(|| Solution {
+            actions,
+            house_clues: vec![],
+            candidate_clues: self.get_coloring_vector(),
+            solver_id,
+        })
+    }
+}
+
+impl ThreeDMedusa {
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+        colorizer: &'a MedusaColorizer,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        (0..colorizer.color_cnt).filter_map(move |index| {
+            Cluster::new(colorizer, index).try_get_solution(game_board, self.solver_id())
+        })
+    }
+}
+
+impl Solver for ThreeDMedusa {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        let mut colorizer = MedusaColorizer::new();
+        colorizer.colorize(game_board);
+        self.iter_solutions(game_board, &colorizer).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        let mut colorizer = MedusaColorizer::new();
+        colorizer.colorize(game_board);
+        distinct_by_actions(self.iter_solutions(game_board, &colorizer))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::ThreeDMedusa
+    }
+}
@@ -1,42 +1,171 @@
 use crate::{
     game_board::GameBoard,
-    solvers::{solution::Solution, Solver, SolverIdentifier},
+    solvers::{
+        Solver, SolverIdentifier,
+        solution::{Solution, distinct_by_actions},
+    },
 };
 
 mod colorizer;
 use colorizer::Colorizer;
 
-/// This solver assigns colors to single digits based on hard links and identifies one candidate that conflicts with the assigned coloring pattern.
+/// [HoDoKu explanations on Simple Colors](https://hodoku.sourceforge.net/en/tech_col.php#sc)
+///
+/// This solver assigns colors to single digits based on hard links, and finds a candidate which sees
+/// both colors of a cluster. Whichever color is true, the candidate is eliminated.
 ///
 /// ## Return Format
-/// - **Actions**: Contains a single element— the candidate that conflicts with the coloring pattern and should be removed.
+/// - **Actions**: Contains a single element— the candidate trapped by the two colors.
 /// - **House Clues**: An empty vector.
 /// - **Candidate Clues**: Contains a variable number of elements. Each candidate clue is represented as a bitmap, which is either single (only one `1` bit set) or inverted single (only one `0` bit set).
 ///     - These bitmaps are organized into 2n segments, with a segment of single bitmaps appearing first, followed by a segment of inverted single bitmaps, and so on.
 ///     - Each pair of segments represents a pair of light-dark colorings.
-pub struct Coloring;
+///     - For this solver, there is exactly one pair of segments: the cluster trapping the candidate.
+pub struct SimpleColorsTrap;
+
+/// [HoDoKu explanations on Simple Colors](https://hodoku.sourceforge.net/en/tech_col.php#sc)
+///
+/// This solver assigns colors to single digits based on hard links, and finds a color which appears
+/// twice in a house. The color must be false, so all the candidates with this color are eliminated.
+///
+/// ## Return Format
+/// - **Actions**: Contains a variable number of elements— all the candidates with the false color.
+/// - **House Clues**: An empty vector.
+/// - **Candidate Clues**: Follows the same convention as [`SimpleColorsTrap`], with exactly one pair of segments:
+///   the cluster containing the false color.
+pub struct SimpleColorsWrap;
+
+/// [HoDoKu explanations on Multi-Colors](https://hodoku.sourceforge.net/en/tech_col.php#mc)
+///
+/// This solver assigns colors to single digits based on hard links, and combines two clusters.
+/// ## Rules
+/// - Type 1: A color of one cluster sees both colors of the other cluster. The color must be false,
+///   so all the candidates with this color are eliminated.
+/// - Type 2: Two colors from different clusters see each other, so at least one of their partner colors is true.
+///   A candidate seeing both partner colors is eliminated.
+///
+/// ## Return Format
+/// - **Actions**: Contains a variable number of elements.
+///     - Type 1: All the candidates with the false color.
+///     - Type 2: A single element— the candidate seeing both partner colors.
+/// - **House Clues**: An empty vector.
+/// - **Candidate Clues**: Follows the same convention as [`SimpleColorsTrap`], with exactly two pairs of segments.
+///     - Type 1: The cluster containing the false color comes first.
+///     - Type 2: The cluster of the first color seen by the eliminated candidate comes first.
+pub struct MultiColors;
 mod analyzer;
 use analyzer::Analyzer;
 
-impl Solver for Coloring {
-    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        for target in 0..9 {
+/// [SudokuWiki explanations on 3D Medusa](https://www.sudokuwiki.org/3D_Medusa)
+///
+/// This solver extends the coloring across digits: besides the hard links of a single digit, the
+/// two candidates of a bi-value cell are linked as well, so one cluster may contain many digits.
+/// ## Rules
+/// - A color appears twice in a cell: the color is false.
+/// - A color appears twice in a house with the same candidate: the color is false.
+/// - All the candidates of an uncolored cell see the same color: the color is false.
+/// - Both colors appear in a cell: all the uncolored candidates in the cell are eliminated.
+/// - An uncolored candidate sees both colors: it's eliminated.
+/// - An uncolored candidate sees one color, and the other color appears in its cell: it's eliminated.
+///
+/// ## Return Format
+/// - **Actions**: Contains a variable number of elements, representing candidates eliminated in each cell.
+///     - If a color is false, all the candidates with this color are eliminated.
+///     - Otherwise, all the uncolored candidates eliminated by the cluster.
+/// - **House Clues**: An empty vector.
+/// - **Candidate Clues**: Contains a variable number of elements, following the same convention as [`SimpleColorsTrap`],
+///   with exactly one pair of segments representing the light-dark coloring of the cluster.
+pub struct ThreeDMedusa;
+mod medusa;
+
+/// Colorize each digit and analyze the clusters with the given technique
+fn iter_by_coloring<'a>(
+    game_board: &'a GameBoard,
+    analyze: impl Fn(&Analyzer) -> Vec<Solution> + 'a,
+) -> impl Iterator<Item = Solution> + 'a {
+    (0..9)
+        .map_while(move |target| {
             let mut colorizer = Colorizer::new(target);
-            let res = colorizer.colorize(game_board);
-            if res.is_err() {
-                return None;
-            }
+            colorizer.colorize(game_board).ok()?;
             let mut analyzer = Analyzer::new(colorizer);
             analyzer.calculate_exclusions();
-            if let Some(solution) = analyzer.try_get_solution(game_board, self.solver_id()) {
-                return Some(solution);
-            }
-        }
-        None
+            Some(analyze(&analyzer))
+        })
+        .flatten()
+}
+
+impl SimpleColorsTrap {
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        iter_by_coloring(game_board, move |analyzer| {
+            analyzer.trap_solutions(game_board, self.solver_id())
+        })
+    }
+}
+
+impl Solver for SimpleColorsTrap {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::SimpleColorsTrap
+    }
+}
+
+impl SimpleColorsWrap {
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        iter_by_coloring(game_board, move |analyzer| {
+            analyzer.wrap_solutions(self.solver_id())
+        })
+    }
+}
+
+impl Solver for SimpleColorsWrap {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::SimpleColorsWrap
+    }
+}
+
+impl MultiColors {
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        iter_by_coloring(game_board, move |analyzer| {
+            analyzer.multi_colors_solutions(game_board, self.solver_id())
+        })
+    }
+}
+
+impl Solver for MultiColors {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
-        SolverIdentifier::Coloring
+        SolverIdentifier::MultiColors
     }
 }
 
@@ -44,10 +173,16 @@ impl Solver for Coloring {
 mod coloring_test {
     use super::*;
     use crate::tests::common::test_function_e;
+    const SIMPLE_COLORS_TRAP_BOARD: [u16; 81] = [
+        32, 4, 128, 10, 1, 256, 16, 64, 10, 19, 259, 272, 136, 64, 132, 32, 258, 140, 8, 258, 64,
+        34, 176, 148, 384, 1, 6, 401, 32, 4, 129, 384, 8, 2, 400, 64, 400, 8, 272, 64, 2, 32, 4,
+        400, 1, 64, 385, 2, 4, 400, 17, 384, 8, 32, 4, 16, 8, 161, 160, 131, 64, 130, 256, 258,
+        386, 1, 16, 4, 64, 8, 32, 130, 130, 64, 32, 256, 8, 130, 1, 4, 16,
+    ];
     #[test]
     fn test_multi_color() {
         test_function_e(
-            Coloring,
+            MultiColors,
             [
                 112, 8, 112, 2, 96, 128, 256, 1, 4, 96, 128, 256, 1, 4, 96, 2, 24, 24, 5, 5, 2, 8,
                 256, 16, 64, 32, 128, 128, 256, 80, 96, 8, 4, 48, 2, 1, 84, 2, 1, 352, 128, 320, 8,
@@ -66,26 +201,15 @@ mod coloring_test {
                 (6, 5),
                 (1, 5),
                 (6, 8),
-                (3, 3),
-                (4, 8),
-                (8, 6),
-                (3, 6),
-                (4, 4),
             ], //exp_candi_clues
-            vec![32, 32, 479, 32, 32, 32, 479, 479, 32, 32, 32, 479, 479], //exp_candi_masks
+            vec![32, 32, 479, 32, 32, 32, 479, 479], //exp_candi_masks
         )
     }
     #[test]
     fn test_single_color() {
         test_function_e(
-            Coloring,
-            [
-                32, 4, 128, 10, 1, 256, 16, 64, 10, 19, 259, 272, 136, 64, 132, 32, 258, 140, 8,
-                258, 64, 34, 176, 148, 384, 1, 6, 401, 32, 4, 129, 384, 8, 2, 400, 64, 400, 8, 272,
-                64, 2, 32, 4, 400, 1, 64, 385, 2, 4, 400, 17, 384, 8, 32, 4, 16, 8, 161, 160, 131,
-                64, 130, 256, 258, 386, 1, 16, 4, 64, 8, 32, 130, 130, 64, 32, 256, 8, 130, 1, 4,
-                16,
-            ],
+            SimpleColorsTrap,
+            SIMPLE_COLORS_TRAP_BOARD,
             vec![(2, 5)], //exp_actions
             vec![128],    //exp_action_targets
             vec![],       //exp_house_clues
@@ -99,10 +223,213 @@ mod coloring_test {
                 (5, 1),
                 (7, 8),
                 (8, 0),
-                (4, 0),
-                (4, 7),
             ], //exp_candi_clues
-            vec![128, 128, 128, 128, 128, 383, 383, 383, 383, 128, 383], //exp_candi_masks
+            vec![128, 128, 128, 128, 128, 383, 383, 383, 383], //exp_candi_masks
         )
     }
+
+    #[test]
+    fn test_single_color_all() {
+        let game_board = GameBoard::from_array(SIMPLE_COLORS_TRAP_BOARD);
+        let solutions = SimpleColorsTrap.solve_all(&game_board);
+        assert!(solutions.len() > 1);
+        assert_eq!(
+            format!("{:?}", solutions[0].actions),
+            format!("{:?}", SimpleColorsTrap.solve(&game_board).unwrap().actions)
+        );
+        assert!(
+            solutions
+                .iter()
+                .all(|solution| solution.solver_id == SolverIdentifier::SimpleColorsTrap)
+        );
+    }
+    #[test]
+    fn test_simple_colors_wrap() {
+        test_function_e(
+            SimpleColorsWrap,
+            [
+                964, 833, 2, 708, 16, 840, 672, 649, 929, 976, 849, 8, 706, 544, 834, 658, 4, 915,
+                32, 784, 788, 1, 646, 778, 658, 650, 64, 513, 520, 592, 256, 706, 32, 662, 642,
+                662, 848, 4, 848, 706, 8, 578, 1, 32, 658, 2, 128, 544, 528, 513, 4, 64, 256, 520,
+                8, 626, 596, 582, 256, 529, 678, 707, 679, 836, 834, 1, 32, 582, 128, 520, 16, 518,
+                596, 626, 128, 520, 582, 529, 256, 579, 551,
+            ],
+            vec![(0, 0), (2, 4), (6, 2), (6, 3)], //exp_actions
+            vec![4, 4, 4, 4],                     //exp_action_targets
+            vec![],                               //exp_house_clues
+            vec![(0, 0), (2, 4), (6, 2), (6, 3), (0, 3), (2, 2)], //exp_candidate_clues
+            vec![4, 4, 4, 4, 507, 507],           //exp_candidate_masks
+        );
+    }
+
+    #[test]
+    fn test_medusa_uncolored_candidate() {
+        test_function_e(
+            ThreeDMedusa,
+            [
+                544, 256, 514, 720, 721, 577, 593, 4, 8, 1, 524, 640, 2, 848, 524, 32, 592, 848,
+                540, 540, 64, 524, 769, 32, 2, 641, 896, 770, 1, 16, 588, 32, 770, 128, 584, 580,
+                836, 580, 32, 732, 720, 844, 604, 2, 1, 582, 128, 520, 596, 595, 583, 256, 32, 596,
+                536, 568, 513, 256, 578, 578, 540, 664, 692, 600, 514, 768, 545, 4, 128, 601, 601,
+                624, 640, 608, 4, 545, 8, 16, 577, 768, 2,
+            ],
+            vec![(7, 8)], //exp_actions
+            vec![64],     //exp_action_targets
+            vec![],       //exp_house_clues
+            vec![
+                (4, 1),
+                (6, 1),
+                (7, 3),
+                (7, 8),
+                (8, 1),
+                (8, 3),
+                (8, 6),
+                (4, 1),
+                (6, 8),
+                (7, 0),
+                (7, 3),
+                (8, 1),
+                (8, 3),
+                (8, 6),
+            ], //exp_candidate_clues
+            vec![4, 32, 1, 32, 64, 32, 1, 447, 479, 447, 479, 479, 510, 447], //exp_candidate_masks
+        );
+    }
+
+    #[test]
+    fn test_medusa_cell_with_both_colors() {
+        test_function_e(
+            ThreeDMedusa,
+            [
+                520, 576, 256, 513, 656, 32, 2, 656, 516, 32, 2, 528, 960, 960, 516, 648, 1, 776,
+                640, 516, 513, 786, 786, 520, 784, 32, 64, 834, 128, 8, 4, 544, 578, 784, 530, 513,
+                513, 16, 578, 962, 8, 834, 644, 646, 32, 4, 800, 546, 658, 1, 658, 64, 650, 776,
+                832, 8, 128, 544, 848, 849, 517, 836, 2, 16, 800, 610, 834, 4, 771, 521, 840, 128,
+                834, 1, 516, 8, 962, 962, 544, 832, 528,
+            ],
+            vec![(4, 7), (5, 7), (7, 7)], //exp_actions
+            vec![2, 128, 256],            //exp_action_targets
+            vec![],                       //exp_house_clues
+            vec![
+                (0, 4),
+                (0, 7),
+                (1, 6),
+                (1, 8),
+                (2, 6),
+                (3, 6),
+                (3, 7),
+                (4, 6),
+                (4, 7),
+                (5, 1),
+                (5, 2),
+                (5, 8),
+                (6, 5),
+                (6, 6),
+                (7, 1),
+                (7, 6),
+                (7, 7),
+                (0, 4),
+                (0, 7),
+                (1, 6),
+                (1, 8),
+                (2, 6),
+                (3, 0),
+                (3, 6),
+                (3, 7),
+                (4, 6),
+                (5, 1),
+                (5, 2),
+                (5, 7),
+                (5, 8),
+                (6, 6),
+                (6, 7),
+                (7, 1),
+                (7, 2),
+                (7, 5),
+                (7, 6),
+            ], //exp_candidate_clues
+            vec![
+                16, 128, 8, 256, 16, 256, 16, 128, 4, 256, 32, 8, 1, 4, 32, 1, 8, 383, 495, 383,
+                503, 255, 255, 495, 509, 507, 479, 509, 503, 255, 510, 507, 255, 479, 510, 503,
+            ], //exp_candidate_masks
+        );
+    }
+
+    #[test]
+    fn test_medusa_contradicted_color() {
+        test_function_e(
+            ThreeDMedusa,
+            [
+                256, 8, 640, 528, 516, 32, 514, 64, 513, 516, 560, 576, 520, 514, 513, 800, 816,
+                128, 562, 562, 1, 960, 704, 960, 4, 560, 520, 513, 516, 800, 800, 8, 528, 576, 128,
+                2, 674, 802, 16, 704, 1, 706, 8, 4, 800, 642, 64, 520, 4, 672, 898, 800, 1, 528,
+                520, 784, 4, 736, 720, 704, 513, 514, 800, 64, 128, 514, 1, 800, 520, 16, 800, 516,
+                560, 1, 800, 2, 784, 516, 640, 8, 576,
+            ],
+            vec![
+                (1, 6),
+                (1, 7),
+                (2, 0),
+                (2, 5),
+                (3, 2),
+                (3, 3),
+                (4, 1),
+                (4, 8),
+                (5, 4),
+                (5, 6),
+                (6, 1),
+                (6, 3),
+                (6, 8),
+                (7, 4),
+                (7, 7),
+                (8, 0),
+                (8, 2),
+                (8, 4),
+            ], //exp_actions
+            vec![
+                32, 256, 16, 256, 32, 256, 256, 32, 32, 256, 16, 32, 256, 256, 32, 32, 256, 16,
+            ], //exp_action_targets
+            vec![], //exp_house_clues
+            vec![
+                (1, 6),
+                (1, 7),
+                (2, 0),
+                (2, 5),
+                (3, 2),
+                (3, 3),
+                (4, 1),
+                (4, 8),
+                (5, 4),
+                (5, 6),
+                (6, 1),
+                (6, 3),
+                (6, 8),
+                (7, 4),
+                (7, 7),
+                (8, 0),
+                (8, 2),
+                (8, 4),
+                (1, 6),
+                (2, 3),
+                (3, 2),
+                (3, 3),
+                (4, 8),
+                (5, 4),
+                (5, 5),
+                (5, 6),
+                (6, 1),
+                (6, 4),
+                (6, 8),
+                (7, 4),
+                (7, 7),
+                (8, 0),
+                (8, 2),
+                (8, 4),
+            ], //exp_candidate_clues
+            vec![
+                32, 256, 16, 256, 32, 256, 256, 32, 32, 256, 16, 32, 256, 256, 32, 32, 256, 16,
+                255, 255, 255, 479, 255, 383, 255, 479, 255, 495, 479, 479, 255, 495, 479, 255,
+            ], //exp_candidate_masks
+        );
+    }
 }
@@ -2,141 +2,211 @@ use crate::{
     game_board::GameBoard,
     solvers::{
         Solver, SolverIdentifier,
-        solution::{Action, Candidate, EliminationDetails, Solution},
+        solution::{Action, Candidate, EliminationDetails, Solution, distinct_by_actions},
     },
-    utils::{AllEqualValue, BitMap, Coord, HouseType},
+    utils::{BitMap, CellSet, Coord, House, HouseType},
 };
 
-fn get_coords_with_target_by_masks<'a>(
-    game_board: &'a GameBoard,
-    first: &'a BitMap,
-    second: &'a BitMap,
-    first_dim: &'a HouseType,
-    target: usize,
-) -> impl Iterator<Item = (usize, usize)> + 'a {
-    first
-        .iter_ones()
-        .flat_map(move |first_index| {
-            second.iter_ones().map(move |second_index| {
-                Coord::from_house_and_index(&first_dim.house(first_index), second_index)
-            })
+/// The cells of the houses of the given type whose index is in the mask
+fn cells_by_mask(dim: HouseType, mask: BitMap) -> CellSet {
+    mask.iter_ones().fold(CellSet::new(), |acc, index| {
+        acc.union(CellSet::house(&dim.house(index)))
+    })
+}
+
+/// A basic fish of size n on a single candidate, whose base candidates outside the cover sets are the fins
+pub(crate) struct FinnedFish {
+    pub target: usize,
+    pub base_dim: HouseType,
+    pub base: BitMap,
+    pub cover: BitMap,
+    pub body: CellSet,
+    pub fins: CellSet,
+    base_cells: CellSet,
+    cover_cells: CellSet,
+    // the cells containing the target
+    appearance: CellSet,
+}
+
+impl FinnedFish {
+    /// Iterate through the cells of the set house by house along the base dimension
+    pub fn iter_along_base(&self, cells: CellSet) -> impl Iterator<Item = (usize, usize)> + '_ {
+        (0..9).flat_map(move |index| {
+            self.base_dim
+                .house(index)
+                .as_iter()
+                .filter(move |&(x, y)| cells.contains(x, y))
         })
-        .filter(move |&(x, y)| game_board.contains_candidate(x, y, target))
+    }
+
+    /// The candidates in the cover sets but outside the base sets
+    pub fn cover_only(&self) -> CellSet {
+        self.cover_cells
+            .difference(self.base_cells)
+            .intersect(self.appearance)
+    }
+
+    /// Returns true if a corner of the fish inside the fin box is missing, so the fins take its place
+    fn is_sashimi(&self, fin_box: CellSet) -> bool {
+        !self
+            .base_cells
+            .intersect(self.cover_cells)
+            .intersect(fin_box)
+            .is_subset(self.appearance)
+    }
+
+    pub fn house_clues(&self) -> Vec<House> {
+        self.base
+            .iter_ones()
+            .map(|base_index| self.base_dim.house(base_index))
+            .chain(
+                self.cover
+                    .iter_ones()
+                    .map(|cover_index| self.base_dim.other().house(cover_index)),
+            )
+            .collect()
+    }
+
+    /// The body followed by the fins, seperated by the Candidate::SEPARATOR
+    pub fn candidate_clues(&self) -> Vec<Candidate> {
+        let to_clues = |cells: CellSet| {
+            self.iter_along_base(cells)
+                .map(|(x, y)| Candidate::new_single(x, y, self.target))
+                .collect::<Vec<_>>()
+        };
+        [
+            to_clues(self.body),
+            vec![Candidate::SEPARATOR],
+            to_clues(self.fins),
+        ]
+        .concat()
+    }
 }
 
-fn find_finned_fish(
+/// Enumerates all the fish of size n with at least one fin
+pub(crate) fn iter_finned_fish(
     game_board: &GameBoard,
-    base_dim: &HouseType,
+    base_dim: HouseType,
     n: usize,
-    solver_id: SolverIdentifier,
-) -> Option<Solution> {
-    for target in 0..9 {
-        for base in BitMap::get_masked_combo(n, *game_board.house_occupied_by(base_dim, target)) {
-            for cover in BitMap::get_masked_combo(
-                n,
-                *game_board.house_occupied_by(&base_dim.other(), target),
-            ) {
-                let cover_comp = cover.complement();
-                let base_comp = base.complement();
-                let body_clues: Vec<_> =
-                    get_coords_with_target_by_masks(game_board, &base, &cover, base_dim, target)
-                        .map(|(x, y)| Candidate {
-                            x,
-                            y,
-                            candidates: BitMap::from(target),
-                        })
-                        .collect();
-
-                if body_clues.is_empty() {
-                    continue;
-                }
-
-                let fin_clues: Vec<_> = get_coords_with_target_by_masks(
-                    game_board,
-                    &base,
-                    &cover_comp,
-                    base_dim,
-                    target,
+) -> impl Iterator<Item = FinnedFish> + '_ {
+    (0..9).flat_map(move |target| {
+        let appearance = game_board.candidate_cells(target);
+        BitMap::get_masked_combo(n, *game_board.house_occupied_by(&base_dim, target)).flat_map(
+            move |base| {
+                let base_cells = cells_by_mask(base_dim, base);
+                BitMap::get_masked_combo(
+                    n,
+                    *game_board.house_occupied_by(&base_dim.other(), target),
                 )
-                .map(|(x, y)| Candidate {
-                    x,
-                    y,
-                    candidates: BitMap::from(target),
+                .filter_map(move |cover| {
+                    let cover_cells = cells_by_mask(base_dim.other(), cover);
+                    let body = base_cells.intersect(cover_cells).intersect(appearance);
+                    let fins = base_cells.difference(cover_cells).intersect(appearance);
+                    (!body.is_empty() && !fins.is_empty()).then_some(FinnedFish {
+                        target,
+                        base_dim,
+                        base,
+                        cover,
+                        body,
+                        fins,
+                        base_cells,
+                        cover_cells,
+                        appearance,
+                    })
                 })
-                .collect();
+            },
+        )
+    })
+}
 
-                if fin_clues.is_empty() {
-                    continue;
-                }
-
-                let fin_box = fin_clues
-                    .clone()
-                    .into_iter()
-                    .map(
-                        |Candidate {
-                             x,
-                             y,
-                             candidates: _,
-                         }| Coord::get_box_id(x, y),
-                    )
-                    .all_equal_value();
-
-                if let Some(fin_box) = fin_box {
-                    let eliminable: Vec<_> = get_coords_with_target_by_masks(
-                        game_board, &base_comp, &cover, base_dim, target,
-                    )
-                    .filter(|&(x, y)| Coord::get_box_id(x, y) == fin_box)
-                    .map(|(x, y)| {
-                        Action::Elimination(EliminationDetails {
-                            x,
-                            y,
-                            target: BitMap::from(target),
-                        })
-                    })
-                    .collect();
-                    if !eliminable.is_empty() {
-                        return Some(Solution {
-                            actions: eliminable,
-                            candidate_clues: [body_clues, fin_clues].concat(),
-                            house_clues: base
-                                .iter_ones()
-                                .map(|base_index| base_dim.house(base_index))
-                                .chain(
-                                    cover
-                                        .iter_ones()
-                                        .map(|cover_index| base_dim.other().house(cover_index)),
-                                )
-                                .collect(),
-                            solver_id,
-                        });
-                    }
-                }
+/// Every finned (or sashimi) fish of size n with eliminations, with row then column base sets
+fn iter_finned_solutions(
+    game_board: &GameBoard,
+    n: usize,
+    sashimi: bool,
+    solver_id: SolverIdentifier,
+) -> impl Iterator<Item = Solution> + '_ {
+    [HouseType::Row, HouseType::Col]
+        .into_iter()
+        .flat_map(move |base_dim| iter_finned_fish(game_board, base_dim, n))
+        .filter_map(move |fish| {
+            let (fx, fy) = fish.fins.first()?;
+            let fin_box = CellSet::house(&House::Box(Coord::get_box_id(fx, fy)));
+            if !fish.fins.is_subset(fin_box) || fish.is_sashimi(fin_box) != sashimi {
+                return None;
             }
-        }
-    }
-    None
+            let eliminable: Vec<_> = fish
+                .iter_along_base(fish.cover_only().intersect(fin_box))
+                .map(|(x, y)| {
+                    Action::Elimination(EliminationDetails {
+                        x,
+                        y,
+                        target: BitMap::from(fish.target),
+                    })
+                })
+                .collect();
+            (!eliminable.is_empty()).then(|| Solution {
+                actions: eliminable,
+                candidate_clues: fish.candidate_clues(),
+                house_clues: fish.house_clues(),
+                solver_id,
+            })
+        })
 }
 
+/// Only the fish with every corner inside the fin box, the sashimi ones are left to [`SashimiXWing`].
+/// The same goes for the Finned Swordfish and Jellyfish.
 pub struct FinnedXWing;
 
 impl Solver for FinnedXWing {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        [HouseType::Row, HouseType::Col]
-            .into_iter()
-            .find_map(|base_dim| find_finned_fish(game_board, &base_dim, 2, self.solver_id()))
+        iter_finned_solutions(game_board, 2, false, self.solver_id()).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(iter_finned_solutions(
+            game_board,
+            2,
+            false,
+            self.solver_id(),
+        ))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
         SolverIdentifier::FinnedXWing
     }
 }
+
+/// The [`FinnedXWing`] whose corner next to the fins is missing, so the fins take its place
+pub struct SashimiXWing;
+
+impl Solver for SashimiXWing {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        iter_finned_solutions(game_board, 2, true, self.solver_id()).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(iter_finned_solutions(game_board, 2, true, self.solver_id()))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::SashimiXWing
+    }
+}
 pub struct FinnedSwordfish;
 
 impl Solver for FinnedSwordfish {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        [HouseType::Row, HouseType::Col]
-            .into_iter()
-            .find_map(|base_dim| find_finned_fish(game_board, &base_dim, 3, self.solver_id()))
+        iter_finned_solutions(game_board, 3, false, self.solver_id()).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(iter_finned_solutions(
+            game_board,
+            3,
+            false,
+            self.solver_id(),
+        ))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
@@ -144,13 +214,37 @@ impl Solver for FinnedSwordfish {
     }
 }
 
+/// The [`FinnedSwordfish`] whose corner next to the fins is missing, so the fins take its place
+pub struct SashimiSwordfish;
+
+impl Solver for SashimiSwordfish {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        iter_finned_solutions(game_board, 3, true, self.solver_id()).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(iter_finned_solutions(game_board, 3, true, self.solver_id()))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::SashimiSwordfish
+    }
+}
+
 pub struct FinnedJellyfish;
 
 impl Solver for FinnedJellyfish {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        [HouseType::Row, HouseType::Col]
-            .into_iter()
-            .find_map(|base_dim| find_finned_fish(game_board, &base_dim, 4, self.solver_id()))
+        iter_finned_solutions(game_board, 4, false, self.solver_id()).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(iter_finned_solutions(
+            game_board,
+            4,
+            false,
+            self.solver_id(),
+        ))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
@@ -158,10 +252,28 @@ impl Solver for FinnedJellyfish {
     }
 }
 
+/// The [`FinnedJellyfish`] whose corner next to the fins is missing, so the fins take its place
+pub struct SashimiJellyfish;
+
+impl Solver for SashimiJellyfish {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        iter_finned_solutions(game_board, 4, true, self.solver_id()).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(iter_finned_solutions(game_board, 4, true, self.solver_id()))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::SashimiJellyfish
+    }
+}
+
 #[cfg(test)]
 mod finned_test {
     use super::*;
     use crate::solvers::solution::Action::Elimination;
+    use crate::utils::BitMap;
     use crate::utils::House::{Col, Row};
     use crate::{game_board::GameBoard, utils::House};
     use assert_matches::assert_matches;
@@ -171,7 +283,8 @@ mod finned_test {
         target: u16,
         exp_actions: Vec<(usize, usize)>,
         exp_house_clues: Vec<House>,
-        exp_candidate_clues: Vec<(usize, usize)>,
+        exp_body_clues: Vec<(usize, usize)>,
+        exp_fin_clues: Vec<(usize, usize)>,
     ) {
         // raws
         let game_board = GameBoard::from_array(raws);
@@ -196,7 +309,7 @@ mod finned_test {
             let (x, y, raw) = action_std[i];
             let action = &actions[i];
 
-            assert_matches!(action, Elimination(EliminationDetails{x,y,target})if target.get_raw()==raw);
+            assert_matches!(action, Elimination(EliminationDetails{x: ex,y: ey,target})if *ex==x && *ey==y && target.get_raw()==raw);
         }
         // // if confirmation
         // assert_eq!(actions.len(), action_len);
@@ -214,17 +327,19 @@ mod finned_test {
             assert_eq!(house_clues[i], exp_house_clues[i]);
         }
 
-        // candidate_clue data
-        let clues_len = exp_candidate_clues.len();
-        let clues_std: Vec<_> = exp_candidate_clues
+        // candidate_clue data, the body and the fins are seperated
+        let clues_std: Vec<_> = exp_body_clues
             .into_iter()
             .map(|(a, b)| (a, b, target))
+            .chain(std::iter::once((0, 0, BitMap::NAN.get_raw())))
+            .chain(exp_fin_clues.into_iter().map(|(a, b)| (a, b, target)))
             .collect();
+        let clues_len = clues_std.len();
         assert_eq!(candidate_clues.len(), clues_len);
         for i in 0..clues_len {
             let (x, y, raw) = clues_std[i];
             let clue = &candidate_clues[i];
-            assert_matches!(clue,Candidate{x,y,candidates} if candidates.get_raw()==raw);
+            assert_matches!(clue,Candidate{x: cx,y: cy,candidates} if *cx==x && *cy==y && candidates.get_raw()==raw);
         }
     }
 
@@ -242,7 +357,8 @@ mod finned_test {
             128,
             vec![(6, 1)],
             vec![Row(2), Row(8), Col(1), Col(6)],
-            vec![(2, 1), (2, 6), (8, 1), (8, 6), (8, 2)],
+            vec![(2, 1), (2, 6), (8, 1), (8, 6)],
+            vec![(8, 2)],
         );
     }
     #[test]
@@ -262,14 +378,14 @@ mod finned_test {
             vec![
                 (1, 3),
                 (1, 5),
-                (1, 7),
+                (1, 6),
                 (4, 3),
                 (4, 5),
-                (4, 7),
+                (4, 6),
                 (7, 3),
                 (7, 5),
-                (1, 8),
             ],
+            vec![(1, 7)],
         );
     }
     #[test]
@@ -304,15 +420,15 @@ mod finned_test {
                 (7, 5),
                 (8, 2),
                 (8, 5),
-                (3, 6),
             ],
+            vec![(3, 6)],
         );
     }
 
     #[test]
     fn test_s2() {
         test_function(
-            FinnedXWing,
+            SashimiXWing,
             [
                 1, 32, 260, 64, 18, 128, 274, 20, 8, 336, 2, 8, 48, 49, 4, 336, 128, 289, 128, 80,
                 68, 256, 59, 41, 82, 85, 55, 66, 320, 128, 8, 324, 16, 1, 32, 262, 90, 280, 32,
@@ -323,14 +439,15 @@ mod finned_test {
             16,
             vec![(2, 8)],
             vec![Row(0), Row(7), Col(4), Col(8)],
-            vec![(0, 4), (7, 4), (7, 8), (0, 6), (0, 7)],
+            vec![(0, 4), (7, 4), (7, 8)],
+            vec![(0, 6), (0, 7)],
         );
     }
 
     #[test]
     fn test_s3() {
         test_function(
-            FinnedSwordfish,
+            SashimiSwordfish,
             [
                 34, 42, 256, 64, 128, 36, 16, 5, 9, 128, 16, 64, 265, 257, 12, 32, 268, 2, 40, 4,
                 1, 312, 304, 2, 128, 328, 320, 96, 96, 2, 128, 8, 1, 4, 272, 272, 5, 256, 12, 48,
@@ -340,13 +457,14 @@ mod finned_test {
             8,
             vec![(7, 0)],
             vec![Col(1), Col(2), Col(8), Row(0), Row(4), Row(7)],
-            vec![(0, 1), (7, 1), (4, 2), (0, 8), (4, 8), (6, 1), (6, 2)],
+            vec![(0, 1), (7, 1), (4, 2), (0, 8), (4, 8)],
+            vec![(6, 1), (6, 2)],
         );
     }
     #[test]
     fn test_s4() {
         test_function(
-            FinnedJellyfish,
+            SashimiJellyfish,
             [
                 14, 32, 256, 68, 70, 1, 128, 72, 16, 1, 136, 64, 136, 16, 256, 32, 2, 4, 16, 132,
                 14, 196, 10, 32, 73, 256, 65, 12, 64, 44, 44, 256, 128, 16, 1, 2, 14, 1, 128, 16,
@@ -365,17 +483,8 @@ mod finned_test {
                 Col(5),
                 Col(6),
             ],
-            vec![
-                (1, 1),
-                (1, 3),
-                (5, 3),
-                (5, 5),
-                (5, 6),
-                (6, 5),
-                (7, 1),
-                (7, 7),
-                (8, 8),
-            ],
+            vec![(1, 1), (1, 3), (5, 3), (5, 5), (5, 6), (7, 5), (8, 1)],
+            vec![(7, 7), (8, 8)],
         );
     }
 }
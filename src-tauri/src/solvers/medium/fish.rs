@@ -2,11 +2,12 @@ use crate::{
     game_board::GameBoard,
     solvers::{
         Solver, SolverIdentifier,
-        solution::{Action, Candidate, EliminationDetails, Solution},
+        solution::{Action, Candidate, EliminationDetails, Solution, distinct_by_actions},
     },
-    utils::{BitMap, Coord, House, HouseType},
+    utils::{BitMap, House, HouseType},
 };
 
+/// The candidates in the cover sets outside the base sets
 fn find_eliminable(
     game_board: &GameBoard,
     base_sets: &BitMap,
@@ -16,20 +17,19 @@ fn find_eliminable(
     cover_sets
         .into_iter()
         .flat_map(|house| {
-            base_sets.iter_zeros().filter_map(move |x| {
-                let (x, y) = Coord::from_house_and_index(&house, x);
-                game_board.get_candidates(x, y).and_then(|candidates| {
-                    if candidates.contains(target) {
-                        Some(Action::Elimination(EliminationDetails {
-                            x,
-                            y,
-                            target: BitMap::from(target),
-                        }))
-                    } else {
-                        None
-                    }
+            game_board
+                .candidate_positions(&house, target)
+                .difference(*base_sets)
+                .iter_ones()
+                .map(move |index| {
+                    let (x, y) = house.ith_cell(index);
+                    Action::Elimination(EliminationDetails {
+                        x,
+                        y,
+                        target: BitMap::from(target),
+                    })
                 })
-            })
+                .collect::<Vec<_>>()
         })
         .collect()
 }
@@ -46,90 +46,75 @@ fn check_base_set_combo(
     combo: &BitMap,
     solver_id: SolverIdentifier,
 ) -> Option<Solution> {
-    let mut appearance = BitMap::new();
-    for base in combo.iter_ones() {
-        for cover in (0..9).filter(|index| {
-            let (x, y) = Coord::from_house_and_index(&base_dim.house(base), *index);
-            game_board.contains_candidate(x, y, target)
-        }) {
-            appearance.insert(cover);
-            if appearance.count() > n {
-                return None;
-            };
-        }
+    let appearance = combo.iter_ones().fold(BitMap::new(), |acc, base| {
+        acc.union(game_board.candidate_positions(&base_dim.house(base), target))
+    });
+    if appearance.count() != n {
+        return None;
     }
-    if appearance.count() == n {
-        let cover_sets: Vec<House> = appearance
+    let cover_sets: Vec<House> = appearance
+        .iter_ones()
+        .map(|x| base_dim.other().house(x))
+        .collect();
+    let elimination = find_eliminable(game_board, combo, cover_sets, target);
+    (!elimination.is_empty()).then(|| Solution {
+        actions: elimination,
+        house_clues: combo
             .iter_ones()
-            .map(|x| base_dim.other().house(x))
-            .collect();
-        let elimination = find_eliminable(game_board, combo, cover_sets, target);
-        if !elimination.is_empty() {
-            return Some(Solution {
-                actions: elimination,
-                house_clues: combo
+            .map(|base_index| base_dim.house(base_index))
+            .chain(
+                appearance
                     .iter_ones()
-                    .map(|base_index| base_dim.house(base_index))
-                    .chain(
-                        appearance
-                            .iter_ones()
-                            .map(|cover_index| base_dim.other().house(cover_index)),
-                    )
-                    .collect(),
-                candidate_clues: combo
+                    .map(|cover_index| base_dim.other().house(cover_index)),
+            )
+            .collect(),
+        candidate_clues: combo
+            .iter_ones()
+            .flat_map(|base_index| {
+                let base = base_dim.house(base_index);
+                game_board
+                    .candidate_positions(&base, target)
                     .iter_ones()
-                    .flat_map(|base_index| {
-                        appearance.iter_ones().map({
-                            move |cover_index| {
-                                Coord::from_house_and_index(
-                                    &base_dim.house(base_index),
-                                    cover_index,
-                                )
-                            }
-                        })
-                    })
-                    .filter(|(x, y)| game_board.contains_candidate(*x, *y, target))
-                    .map(|(x, y)| Candidate {
-                        x,
-                        y,
-                        candidates: BitMap::from(target),
-                    })
-                    .collect(),
-                solver_id,
-            });
-        } else {
-            return None;
-        }
-    }
-    None
+                    .map(|index| base.ith_cell(index))
+                    .collect::<Vec<_>>()
+            })
+            .map(|(x, y)| Candidate {
+                x,
+                y,
+                candidates: BitMap::from(target),
+            })
+            .collect(),
+        solver_id,
+    })
 }
 
-fn check_fish_with_dim(
+/// Every fish of size n with eliminations, with row then column base sets
+fn iter_fish(
     game_board: &GameBoard,
     n: usize,
-    base_dim: &HouseType,
     solver_id: SolverIdentifier,
-) -> Option<Solution> {
-    for target in 0..9 {
-        let mask = game_board.occupied()[base_dim.as_dim()][target];
-        for combo in BitMap::get_masked_combo(n, mask) {
-            if let Some(solution) =
-                check_base_set_combo(game_board, n, base_dim, target, &combo, solver_id)
-            {
-                return Some(solution);
-            }
-        }
-    }
-    None
+) -> impl Iterator<Item = Solution> + '_ {
+    [HouseType::Row, HouseType::Col]
+        .into_iter()
+        .flat_map(move |base_dim| {
+            (0..9).flat_map(move |target| {
+                let mask = game_board.occupied()[base_dim.as_dim()][target];
+                BitMap::get_masked_combo(n, mask).filter_map(move |combo| {
+                    check_base_set_combo(game_board, n, &base_dim, target, &combo, solver_id)
+                })
+            })
+        })
 }
 
 pub struct XWing;
 
 impl Solver for XWing {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        [HouseType::Row, HouseType::Col]
-            .into_iter()
-            .find_map(|base_dim| check_fish_with_dim(game_board, 2, &base_dim, self.solver_id()))
+        iter_fish(game_board, 2, self.solver_id()).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(iter_fish(game_board, 2, self.solver_id()))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
@@ -141,9 +126,11 @@ pub struct Swordfish;
 
 impl Solver for Swordfish {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        [HouseType::Row, HouseType::Col]
-            .into_iter()
-            .find_map(|base_dim| check_fish_with_dim(game_board, 3, &base_dim, self.solver_id()))
+        iter_fish(game_board, 3, self.solver_id()).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(iter_fish(game_board, 3, self.solver_id()))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
@@ -155,9 +142,11 @@ pub struct Jellyfish;
 
 impl Solver for Jellyfish {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        [HouseType::Row, HouseType::Col]
-            .into_iter()
-            .find_map(|base_dim| check_fish_with_dim(game_board, 4, &base_dim, self.solver_id()))
+        iter_fish(game_board, 4, self.solver_id()).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(iter_fish(game_board, 4, self.solver_id()))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
@@ -10,17 +10,21 @@ mod single_digit_patterns;
 mod uniqueness;
 pub(super) use single_digit_patterns::{EmptyRectangle, Skyscraper, TurbotFish, TwoStringKite};
 pub(super) use uniqueness::{
-    AvoidableRectangle1, AvoidableRectangle2, BiValueUniversalGravePlusOne, UniquenessTest1,
-    UniquenessTest2, UniquenessTest3, UniquenessTest4, UniquenessTest5, UniquenessTest6,
+    AvoidableRectangle1, AvoidableRectangle2, AvoidableRectangle3, BiValueUniversalGravePlusN,
+    BiValueUniversalGravePlusOne, HiddenRectangle, UniqueLoop, UniquenessTest1, UniquenessTest2,
+    UniquenessTest3, UniquenessTest4, UniquenessTest5, UniquenessTest6, UniquenessTest7,
+    UniquenessTest8,
 };
 mod wings;
 pub(super) use super::easy::hidden_subset::HiddenQuadruple;
 pub(super) use super::easy::naked_subset::NakedQuadruple;
-pub(super) use finned::FinnedXWing;
+pub(super) use finned::{FinnedXWing, SashimiXWing};
 pub(super) use fish::{Jellyfish, Swordfish, XWing};
-pub(super) use wings::{WWing, XYWing, XYZWing};
+pub(super) use wings::{VWXYZWing, WWing, WXYZWing, XYWing, XYZWing};
+mod remote_pairs;
+pub(super) use remote_pairs::RemotePairs;
 mod color;
-pub(super) use color::Coloring;
+pub(super) use color::{MultiColors, SimpleColorsTrap, SimpleColorsWrap, ThreeDMedusa};
 #[rustfmt::skip]
 pub fn get_medium_solvers() -> Vec<SolverEnum> {
     vec![
@@ -38,15 +42,28 @@ pub fn get_medium_solvers() -> Vec<SolverEnum> {
         SolverEnum::from(UniquenessTest5             ),
         SolverEnum::from(UniquenessTest6             ),
         SolverEnum::from(FinnedXWing                 ),
+        SolverEnum::from(SashimiXWing                ),
         SolverEnum::from(AvoidableRectangle1         ),
         SolverEnum::from(AvoidableRectangle2         ),
+        SolverEnum::from(UniquenessTest7             ),
+        SolverEnum::from(UniquenessTest8             ),
+        SolverEnum::from(HiddenRectangle             ),
+        SolverEnum::from(AvoidableRectangle3         ),
+        SolverEnum::from(UniqueLoop                  ),
+        SolverEnum::from(BiValueUniversalGravePlusN  ),
         SolverEnum::from(NakedQuadruple              ), 
         SolverEnum::from(HiddenQuadruple             ), 
         SolverEnum::from(Jellyfish                   ),
         SolverEnum::from(XYWing                      ),
         SolverEnum::from(XYZWing                     ),
         SolverEnum::from(WWing                       ),
-        SolverEnum::from(Coloring                    ),
+        SolverEnum::from(WXYZWing                    ),
+        SolverEnum::from(VWXYZWing                   ),
+        SolverEnum::from(RemotePairs                 ),
+        SolverEnum::from(SimpleColorsTrap            ),
+        SolverEnum::from(SimpleColorsWrap            ),
+        SolverEnum::from(MultiColors                 ),
+        SolverEnum::from(ThreeDMedusa                ),
     ]
 }
 #[derive(Copy, Clone)]
@@ -0,0 +1,160 @@
+use std::collections::VecDeque;
+
+use crate::{
+    game_board::GameBoard,
+    solvers::{
+        Solver, SolverIdentifier,
+        solution::{Action, Candidate, EliminationDetails, Solution, distinct_by_actions},
+    },
+    utils::Coord,
+};
+
+use super::{BiValueCell, iter_valid_bi_value};
+
+/// [HoDoKu explanations on Remote Pairs](https://hodoku.sourceforge.net/en/tech_chains.php#rp)
+///
+/// ## Terminology
+/// - A chain of bi-value cells with the same two candidates x and y, where consecutive cells see each other.
+/// - The cells in the chain take x and y alternately, so two cells with odd distance in the chain can't hold the same digit.
+/// - The two ends of the chain are denoted by S and T, and the chain contains at least 4 cells.
+/// ## Return Format
+/// - **Actions**: Contains variable number of elements, representing x and y in the cells seeing both S and T.
+/// - **House Clues**: An empty vector.
+/// - **Candidate Clues**: Contains variable number of elements, representing x and y in each cell of the chain, from S to T.
+pub struct RemotePairs;
+
+/// The shortest paths from S to the other bi-value cells with the same candidates
+struct ChainSearch {
+    cells: Vec<BiValueCell>,
+    dist: Vec<Option<usize>>,
+    parent: Vec<usize>,
+}
+
+impl ChainSearch {
+    fn new(cells: Vec<BiValueCell>, start: usize) -> Self {
+        let mut res = Self {
+            dist: vec![None; cells.len()],
+            parent: vec![start; cells.len()],
+            cells,
+        };
+        res.dist[start] = Some(0);
+        let mut queue = VecDeque::from([start]);
+        while let Some(cur) = queue.pop_front() {
+            let (cx, cy) = (res.cells[cur].x, res.cells[cur].y);
+            for next in 0..res.cells.len() {
+                if res.dist[next].is_none()
+                    && Coord::sees(cx, cy, res.cells[next].x, res.cells[next].y)
+                {
+                    res.dist[next] = res.dist[cur].map(|d| d + 1);
+                    res.parent[next] = cur;
+                    queue.push_back(next);
+                }
+            }
+        }
+        res
+    }
+
+    /// The chain cells from S to the given end
+    fn chain_to(&self, end: usize) -> Vec<BiValueCell> {
+        let mut chain = vec![self.cells[end]];
+        let mut cur = end;
+        while self.dist[cur] != Some(0) {
+            cur = self.parent[cur];
+            chain.push(self.cells[cur]);
+        }
+        chain.reverse();
+        chain
+    }
+
+    /// Ends with odd distance, which can't be linked by a naked pair directly
+    fn remote_ends(&self) -> impl Iterator<Item = usize> + '_ {
+        (0..self.cells.len()).filter(|&end| self.dist[end].is_some_and(|d| d >= 3 && d % 2 == 1))
+    }
+}
+
+fn try_get_solution(
+    game_board: &GameBoard,
+    chain: &[BiValueCell],
+    solver_id: SolverIdentifier,
+) -> Option<Solution> {
+    let (s, t) = (chain[0], chain[chain.len() - 1]);
+    let actions: Vec<_> = Coord::pinched_by(s.x, s.y, t.x, t.y)
+        .filter_map(|(x, y)| {
+            let target = game_board.get_candidates(x, y)?.intersect(s.bi_value);
+            (target.count() > 0).then_some(Action::Elimination(EliminationDetails { x, y, target }))
+        })
+        .collect();
+    (!actions.is_empty()).then(|| Solution {
+        actions,
+        house_clues: vec![],
+        candidate_clues: chain
+            .iter()
+            .map(|cell| Candidate::new(cell.x, cell.y, cell.bi_value))
+            .collect(),
+        solver_id,
+    })
+}
+
+impl RemotePairs {
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        let bi_values: Vec<_> = iter_valid_bi_value(game_board).collect();
+        bi_values.clone().into_iter().flat_map(move |start| {
+            let cells: Vec<_> = bi_values
+                .iter()
+                .filter(|cell| cell.bi_value == start.bi_value)
+                .copied()
+                .collect();
+            let start = cells
+                .iter()
+                .position(|cell| Coord::same(cell.x, cell.y, start.x, start.y))
+                .expect("start cell is in its own group");
+            let search = ChainSearch::new(cells, start);
+            search
+                .remote_ends()
+                .filter_map(|end| {
+                    try_get_solution(game_board, &search.chain_to(end), self.solver_id())
+                })
+                .collect::<Vec<_>>()
+        })
+    }
+}
+
+impl Solver for RemotePairs {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::RemotePairs
+    }
+}
+
+#[cfg(test)]
+mod remote_pairs_test {
+    use super::*;
+    use crate::tests::common::test_function_e;
+    #[test]
+    fn test_remote_pairs() {
+        test_function_e(
+            RemotePairs,
+            [
+                648, 1, 64, 2, 648, 32, 16, 4, 256, 256, 8, 32, 648, 4, 16, 2, 64, 1, 2, 4, 16,
+                256, 1, 64, 128, 32, 8, 672, 64, 256, 648, 16, 2, 1, 128, 4, 16, 128, 1, 4, 64,
+                256, 32, 8, 2, 4, 2, 8, 1, 32, 128, 256, 16, 64, 8, 256, 2, 32, 128, 4, 64, 1, 16,
+                64, 32, 4, 16, 2, 1, 8, 256, 128, 1, 16, 128, 64, 256, 8, 4, 2, 32,
+            ],
+            vec![(3, 0)],                         //exp_actions
+            vec![128],                            //exp_action_targets
+            vec![],                               //exp_house_clues
+            vec![(0, 0), (0, 4), (1, 3), (3, 3)], //exp_candi_clues
+            vec![136, 136, 136, 136],             //exp_candi_masks
+        );
+    }
+}
@@ -4,191 +4,193 @@ use crate::{
     game_board::GameBoard,
     solvers::{
         Solver, SolverIdentifier,
-        solution::{Action, Candidate, EliminationDetails, Solution},
+        solution::{Action, Candidate, EliminationDetails, Solution, distinct_by_actions},
     },
-    utils::{BitMap, Coord, House, HouseType},
+    utils::{BitMap, CellSet, Coord, House, HouseType},
 };
 static EMPTY_RECTANGLE_MASK: [u16; 9] = [79, 151, 295, 121, 186, 316, 457, 466, 484];
 
-fn check_empty_rectangle(ids: BitMap) -> Option<(usize, usize)> {
+/// The row and the column through the box covering the cells of ids, the indices of the cells in the box
+fn check_empty_rectangle(box_id: usize, ids: BitMap) -> Option<(usize, usize)> {
     EMPTY_RECTANGLE_MASK
         .iter()
         .position(|&mask| ids.subset_of_raw(mask))
-        .map(|index| (index / 3, index % 3))
+        .map(|index| (box_id / 3 * 3 + index / 3, box_id % 3 * 3 + index % 3))
 }
 
-fn check_turbot(
+/// Every pair of cells with the target in a house of dimension soft_dim, each being the end of a strong link
+/// in a house of dimension hard1 and hard2 respectively, which eliminates the target from the cells seeing both
+/// other ends
+fn iter_turbot(
     game_board: &GameBoard,
     soft_dim: usize,
     hard1: usize,
     hard2: usize,
     solver_id: SolverIdentifier,
-) -> Option<Solution> {
-    for target in 0..9 {
-        for soft_house_index in 0..9 {
-            let appearance: Vec<_> =
-                Coord::house(&HouseType::from_dim(soft_dim).house(soft_house_index))
-                    .filter(|&(x, y)| game_board.contains_candidate(x, y, target))
-                    .collect();
-            for i in 0..appearance.len() {
-                let (x1, y1) = appearance[i];
-                if let Some((p1, q1)) =
-                    game_board.get_hard_link(x1, y1, target, HouseType::from_dim(hard1))
-                {
-                    for (j, &(x2, y2)) in appearance.iter().enumerate() {
-                        if j == i {
-                            continue;
-                        }
-                        if let Some((p2, q2)) =
-                            game_board.get_hard_link(x2, y2, target, HouseType::from_dim(hard2))
-                        {
-                            let actions: Vec<_> = Coord::all_cells()
-                                .filter(|&(u, v)| Coord::sees(p1, q1, u, v))
-                                .filter(|&(u, v)| Coord::sees(p2, q2, u, v))
-                                .filter(|&(u, v)| (u != x1 || v != y1) && (u != x2 || v != y2))
-                                .filter(|&(u, v)| game_board.contains_candidate(u, v, target))
-                                .map(|(u, v)| {
-                                    Action::Elimination(EliminationDetails {
-                                        x: u,
-                                        y: v,
-                                        target: BitMap::from(target),
-                                    })
-                                })
-                                .collect();
-
-                            if !actions.is_empty() {
-                                return Some(Solution {
-                                    actions,
-                                    house_clues: vec![
-                                        HouseType::from_dim(soft_dim).house(soft_house_index),
-                                        HouseType::from_dim(hard1)
-                                            .house(Coord::components_proj(x1, y1, hard1)),
-                                        HouseType::from_dim(hard2)
-                                            .house(Coord::components_proj(x2, y2, hard2)),
-                                    ],
-                                    candidate_clues: vec![
-                                        Candidate::new_single(x1, y1, target),
-                                        Candidate::new_single(x2, y2, target),
-                                        Candidate::new_single(p1, q1, target),
-                                        Candidate::new_single(p2, q2, target),
-                                    ],
-                                    solver_id,
-                                });
-                            }
-                        }
-                    }
-                }
-            }
-        }
-    }
-
+) -> impl Iterator<Item = Solution> + '_ {
     //   skyscraper: weak in 0/1, pincers = weak.other
     //   2-string kite: week in 2, pincers in 0&1
     //   turbot fish:  weak in 0/1, pincers in 2 & weak.other
     //
-    None
+    (0..9).flat_map(move |target| {
+        (0..9).flat_map(move |soft_house_index| {
+            let soft_house = HouseType::from_dim(soft_dim).house(soft_house_index);
+            let appearance: Vec<_> = game_board
+                .candidate_positions(&soft_house, target)
+                .iter_ones()
+                .map(|index| soft_house.ith_cell(index))
+                .collect();
+            let pairs: Vec<_> = appearance
+                .iter()
+                .flat_map(|&first| {
+                    appearance
+                        .iter()
+                        .filter(move |&&second| second != first)
+                        .map(move |&second| (first, second))
+                })
+                .collect();
+            pairs.into_iter().filter_map(move |((x1, y1), (x2, y2))| {
+                let (p1, q1) =
+                    game_board.get_hard_link(x1, y1, target, HouseType::from_dim(hard1))?;
+                let (p2, q2) =
+                    game_board.get_hard_link(x2, y2, target, HouseType::from_dim(hard2))?;
+                let mut eliminable = game_board
+                    .candidate_cells(target)
+                    .intersect(CellSet::peers(p1, q1))
+                    .intersect(CellSet::peers(p2, q2));
+                eliminable.remove(x1, y1);
+                eliminable.remove(x2, y2);
+                let actions: Vec<_> = eliminable
+                    .iter()
+                    .map(|(u, v)| {
+                        Action::Elimination(EliminationDetails {
+                            x: u,
+                            y: v,
+                            target: BitMap::from(target),
+                        })
+                    })
+                    .collect();
+
+                (!actions.is_empty()).then(|| Solution {
+                    actions,
+                    house_clues: vec![
+                        HouseType::from_dim(soft_dim).house(soft_house_index),
+                        HouseType::from_dim(hard1).house(Coord::components_proj(x1, y1, hard1)),
+                        HouseType::from_dim(hard2).house(Coord::components_proj(x2, y2, hard2)),
+                    ],
+                    candidate_clues: vec![
+                        Candidate::new_single(x1, y1, target),
+                        Candidate::new_single(x2, y2, target),
+                        Candidate::new_single(p1, q1, target),
+                        Candidate::new_single(p2, q2, target),
+                    ],
+                    solver_id,
+                })
+            })
+        })
+    })
 }
 
 pub struct EmptyRectangle;
 
-impl Solver for EmptyRectangle {
-    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        (0..9).find_map(|box_id| {
+impl EmptyRectangle {
+    /// Every empty rectangle with an elimination, box by box
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        (0..9).flat_map(move |box_id| {
             game_board
                 .house_occupied_by(&HouseType::Box, box_id)
                 .iter_zeros()
-                .find_map(|target| {
-                    let (clues, ids) = (0..9)
-                        .filter_map(|cell_id| {
-                            let (cx, cy) =
-                                Coord::from_house_and_index(&House::Box(box_id), cell_id);
-                            game_board
-                                .contains_candidate(cx, cy, target)
-                                .then_some((cx, cy, cell_id))
-                        })
-                        .fold(
-                            (Vec::new(), BitMap::new()),
-                            |(mut clues, mut ids), (cx, cy, cell_id)| {
-                                clues.push((cx, cy));
-                                ids.insert(cell_id);
-                                (clues, ids)
-                            },
-                        );
+                .flat_map(move |target| {
+                    let ids = game_board.candidate_positions(&House::Box(box_id), target);
+                    let clues: Vec<_> = ids
+                        .iter_ones()
+                        .map(|cell_id| House::Box(box_id).ith_cell(cell_id))
+                        .collect();
                     (clues.len() > 1)
-                        .then(|| check_empty_rectangle(ids))
+                        .then(|| check_empty_rectangle(box_id, ids))
                         .flatten()
-                        .and_then(|(row_val, col_val)| {
-                            (0..2).find_map(|dim| {
+                        .into_iter()
+                        .flat_map(move |(row_val, col_val)| {
+                            let clues = clues.clone();
+                            (0..2).flat_map(move |dim| {
+                                let clues = clues.clone();
                                 let p_house_type = HouseType::from_dim(dim);
                                 let p_house = p_house_type
                                     .house(Coord::components_proj(row_val, col_val, dim));
 
-                                Coord::house(&p_house)
-                                    .filter(|&(px, py)| {
-                                        game_board.contains_candidate(px, py, target)
-                                            && Coord::get_box_id(px, py) != box_id
-                                    })
-                                    .find_map(|(px, py)| {
-                                        game_board
-                                            .get_hard_link(px, py, target, p_house_type.other())
-                                            .and_then(|(qx, qy)| {
-                                                let r_house_type = HouseType::from_dim(1 - dim);
-                                                let r_house =
-                                                    r_house_type.house(Coord::components_proj(
-                                                        row_val,
-                                                        col_val,
-                                                        1 - dim,
-                                                    ));
-                                                let (rx, ry) = Coord::from_house_and_index(
-                                                    &r_house,
-                                                    Coord::components_proj(qx, qy, dim),
-                                                );
+                                game_board
+                                    .candidate_cells(target)
+                                    .intersect(CellSet::house(&p_house))
+                                    .difference(CellSet::house(&House::Box(box_id)))
+                                    .iter()
+                                    .filter_map(move |(px, py)| {
+                                        let (qx, qy) = game_board.get_hard_link(
+                                            px,
+                                            py,
+                                            target,
+                                            p_house_type.other(),
+                                        )?;
+                                        let r_house_type = HouseType::from_dim(1 - dim);
+                                        let r_house = r_house_type.house(Coord::components_proj(
+                                            row_val,
+                                            col_val,
+                                            1 - dim,
+                                        ));
+                                        let (rx, ry) = Coord::from_house_and_index(
+                                            &r_house,
+                                            Coord::components_proj(qx, qy, dim),
+                                        );
 
-                                                game_board.contains_candidate(rx, ry, target).then(
-                                                    || {
-                                                        let candidate_clues = clues
-                                                            .iter()
-                                                            .map(|&(cx, cy)| Candidate {
-                                                                x: cx,
-                                                                y: cy,
-                                                                candidates: BitMap::from(target),
-                                                            })
-                                                            .chain([(px, py), (qx, qy)].iter().map(
-                                                                |&(x, y)| Candidate {
-                                                                    x,
-                                                                    y,
-                                                                    candidates: BitMap::from(
-                                                                        target,
-                                                                    ),
-                                                                },
-                                                            ))
-                                                            .collect();
+                                        // the rectangle itself may still hold the target in r
+                                        (Coord::get_box_id(rx, ry) != box_id
+                                            && game_board.candidate_cells(target).contains(rx, ry))
+                                        .then(|| {
+                                            let candidate_clues = clues
+                                                .iter()
+                                                .chain([(px, py), (qx, qy)].iter())
+                                                .map(|&(x, y)| Candidate {
+                                                    x,
+                                                    y,
+                                                    candidates: BitMap::from(target),
+                                                })
+                                                .collect();
 
-                                                        Solution {
-                                                            actions: vec![Action::Elimination(
-                                                                EliminationDetails {
-                                                                    x: rx,
-                                                                    y: ry,
-                                                                    target: BitMap::from(target),
-                                                                },
-                                                            )],
-                                                            house_clues: vec![
-                                                                House::Box(box_id),
-                                                                p_house,
-                                                                r_house,
-                                                            ],
-                                                            candidate_clues,
-                                                            solver_id: self.solver_id(),
-                                                        }
+                                            Solution {
+                                                actions: vec![Action::Elimination(
+                                                    EliminationDetails {
+                                                        x: rx,
+                                                        y: ry,
+                                                        target: BitMap::from(target),
                                                     },
-                                                )
-                                            })
+                                                )],
+                                                house_clues: vec![
+                                                    House::Box(box_id),
+                                                    p_house,
+                                                    r_house,
+                                                ],
+                                                candidate_clues,
+                                                solver_id: self.solver_id(),
+                                            }
+                                        })
                                     })
                             })
                         })
                 })
         })
     }
+}
+
+impl Solver for EmptyRectangle {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
+    }
 
     fn solver_id(&self) -> SolverIdentifier {
         SolverIdentifier::EmptyRectangle
@@ -198,7 +200,15 @@ pub struct Skyscraper;
 
 impl Solver for Skyscraper {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        (0..2).find_map(|x| check_turbot(game_board, x, 1 - x, 1 - x, self.solver_id()))
+        (0..2)
+            .flat_map(|x| iter_turbot(game_board, x, 1 - x, 1 - x, self.solver_id()))
+            .next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(
+            (0..2).flat_map(|x| iter_turbot(game_board, x, 1 - x, 1 - x, self.solver_id())),
+        )
     }
 
     fn solver_id(&self) -> SolverIdentifier {
@@ -210,7 +220,11 @@ pub struct TwoStringKite;
 
 impl Solver for TwoStringKite {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        check_turbot(game_board, 2, 0, 1, self.solver_id())
+        iter_turbot(game_board, 2, 0, 1, self.solver_id()).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(iter_turbot(game_board, 2, 0, 1, self.solver_id()))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
@@ -222,7 +236,15 @@ pub struct TurbotFish;
 
 impl Solver for TurbotFish {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        (0..2).find_map(|x| check_turbot(game_board, x, 2, 1 - x, self.solver_id()))
+        (0..2)
+            .flat_map(|x| iter_turbot(game_board, x, 2, 1 - x, self.solver_id()))
+            .next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(
+            (0..2).flat_map(|x| iter_turbot(game_board, x, 2, 1 - x, self.solver_id())),
+        )
     }
 
     fn solver_id(&self) -> SolverIdentifier {
@@ -314,6 +336,40 @@ mod single_digit_patterns_test {
         );
     }
     #[test]
+    fn test_empty_rectangle_soundness() {
+        // the 8 of r3c4 is in the box of the rectangle, and the 1 of r1c4 is taken from a rectangle of box 1
+        let boards = [
+            [
+                528, 4, 128, 577, 584, 769, 2, 617, 800, 768, 578, 577, 16, 32, 526, 716, 717, 524,
+                8, 579, 544, 711, 646, 774, 836, 16, 773, 2, 897, 769, 8, 16, 548, 676, 645, 64,
+                576, 32, 520, 647, 646, 515, 660, 256, 529, 641, 528, 4, 673, 256, 64, 552, 514,
+                553, 4, 8, 592, 256, 1, 640, 624, 608, 2, 672, 896, 848, 614, 582, 574, 1, 588,
+                796, 545, 833, 2, 612, 588, 568, 852, 588, 128,
+            ],
+            [
+                528, 4, 128, 577, 584, 769, 2, 552, 800, 768, 578, 577, 16, 32, 526, 648, 641, 524,
+                8, 515, 544, 518, 640, 774, 576, 16, 773, 2, 896, 769, 8, 16, 544, 516, 641, 64,
+                576, 32, 520, 647, 518, 515, 656, 256, 529, 641, 528, 4, 641, 256, 64, 552, 514,
+                552, 4, 8, 592, 256, 1, 640, 560, 608, 2, 672, 896, 848, 614, 582, 526, 1, 580,
+                536, 545, 577, 2, 612, 588, 528, 768, 588, 128,
+            ],
+        ];
+        for (raws, (x, y, target)) in boards.into_iter().zip([(2, 3, 7), (0, 3, 0)]) {
+            let game_board = GameBoard::from_array(raws);
+            assert!(game_board.contains_candidate(x, y, target));
+            let eliminations: Vec<_> = EmptyRectangle
+                .solve_all(&game_board)
+                .into_iter()
+                .flat_map(|solution| solution.actions)
+                .collect();
+            assert!(!eliminations.iter().any(|action| matches!(
+                action,
+                Elimination(EliminationDetails { x: ex, y: ey, target: t })
+                    if (*ex, *ey) == (x, y) && t.contains(target)
+            )));
+        }
+    }
+    #[test]
     fn test_skyscraper() {
         test_function(
             Skyscraper,
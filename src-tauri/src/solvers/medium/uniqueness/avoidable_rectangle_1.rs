@@ -5,13 +5,13 @@
 use crate::{
     game_board::GameBoard,
     solvers::{
-        solution::{Action, EliminationDetails, Solution},
         Solver, SolverIdentifier,
+        solution::{Action, EliminationDetails, Solution, distinct_by_actions},
     },
     utils::{BitMap, House},
 };
 
-use super::{iter_pen_cell, AvoidableRectangle1, PenCell};
+use super::{AvoidableRectangle1, PenCell, iter_pen_cell};
 
 #[derive(Clone, Copy)]
 struct BaseRow {
@@ -96,19 +96,30 @@ impl AvoidableRectangle1 {
                     .and_then(|num| (num == row.pincer).then_some(AR1::new(row, rx)))
             })
     }
-}
 
-impl Solver for AvoidableRectangle1 {
-    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
         iter_pen_cell(game_board)
-            .flat_map(|p| Self::iter_q(game_board, p))
-            .flat_map(|row| Self::iter_ar(game_board, row))
-            .find_map(|ar| {
+            .flat_map(move |p| Self::iter_q(game_board, p))
+            .flat_map(move |row| Self::iter_ar(game_board, row))
+            .filter_map(move |ar| {
                 game_board
                     .contains_candidate(ar.sx, ar.sy, ar.target)
                     .then(|| ar.get_solution(self.solver_id()))
             })
     }
+}
+
+impl Solver for AvoidableRectangle1 {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
+    }
 
     fn solver_id(&self) -> SolverIdentifier {
         SolverIdentifier::AvoidableRectangle1
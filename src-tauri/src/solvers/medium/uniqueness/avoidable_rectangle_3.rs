@@ -0,0 +1,169 @@
+use crate::{
+    game_board::GameBoard,
+    solvers::{
+        Solver, SolverIdentifier,
+        solution::{Action, Candidate, EliminationDetails, Solution, distinct_by_actions},
+    },
+    utils::{BitMap, Coord, House},
+};
+
+use super::{
+    AvoidableRectangle3,
+    avoidable_rectangles_2::{BaseHouse, iter_base_house, iter_span_house},
+    iter_pen_cell,
+};
+
+struct AR3 {
+    base: BaseHouse,
+    span_house: House,
+    r_extra: BitMap,
+    s_extra: BitMap,
+}
+
+impl AR3 {
+    fn new(game_board: &GameBoard, base: BaseHouse, span_house: House) -> Option<Self> {
+        let (rx, ry) = span_house.ith_cell(base.pi);
+        let (sx, sy) = span_house.ith_cell(base.qi);
+        let r_candidates = game_board.get_candidates(rx, ry)?;
+        let s_candidates = game_board.get_candidates(sx, sy)?;
+        let r_extra = r_candidates.difference(BitMap::from(base.qv));
+        let s_extra = s_candidates.difference(BitMap::from(base.pv));
+        (r_candidates.contains(base.qv)
+            && s_candidates.contains(base.pv)
+            && r_extra.count() > 0
+            && s_extra.count() > 0)
+            .then_some(Self {
+                base,
+                span_house,
+                r_extra,
+                s_extra,
+            })
+    }
+
+    /// The extra candidates of R and S, one of which must be true
+    fn virtual_cell(&self) -> BitMap {
+        self.r_extra.union(self.s_extra)
+    }
+
+    fn r(&self) -> (usize, usize) {
+        self.span_house.ith_cell(self.base.pi)
+    }
+
+    fn s(&self) -> (usize, usize) {
+        self.span_house.ith_cell(self.base.qi)
+    }
+
+    /// The houses containing both R and S
+    fn investigate_houses(&self) -> impl Iterator<Item = House> {
+        [
+            Some(self.span_house),
+            (self.base.pi / 3 == self.base.qi / 3)
+                .then(|| House::Box(Coord::get_box_id_by_tuple(self.r()))),
+        ]
+        .into_iter()
+        .flatten()
+    }
+
+    /// Find a naked subset formed by the virtual cell and other cells in the house
+    fn try_get_solution(
+        &self,
+        game_board: &GameBoard,
+        house: House,
+        solver_id: SolverIdentifier,
+    ) -> Option<Solution> {
+        let virtual_cell = self.virtual_cell();
+        let mask: BitMap = (0..9)
+            .filter(|&i| {
+                let cell = house.ith_cell(i);
+                !game_board.not_filled(cell.0, cell.1) || cell == self.r() || cell == self.s()
+            })
+            .collect();
+        (virtual_cell.count().max(2) - 1..9 - mask.count())
+            .flat_map(|subset_size| BitMap::get_masked_combo(subset_size, mask))
+            .find_map(|combo| {
+                let subset_candidates = combo
+                    .iter_ones()
+                    .filter_map(|i| {
+                        let (cx, cy) = house.ith_cell(i);
+                        game_board.get_candidates(cx, cy)
+                    })
+                    .fold(virtual_cell, |acc, candidates| acc.union(candidates));
+                if subset_candidates.count() != combo.count() + 1 {
+                    return None;
+                }
+                let actions: Vec<_> = mask
+                    .complement()
+                    .difference(combo)
+                    .iter_ones()
+                    .map(|i| house.ith_cell(i))
+                    .filter_map(|(cx, cy)| {
+                        let target = game_board
+                            .get_candidates(cx, cy)?
+                            .intersect(subset_candidates);
+                        (target.count() > 0).then_some(Action::Elimination(EliminationDetails {
+                            x: cx,
+                            y: cy,
+                            target,
+                        }))
+                    })
+                    .collect();
+                (!actions.is_empty()).then(|| Solution {
+                    actions,
+                    house_clues: vec![
+                        self.base.house,
+                        self.span_house,
+                        self.base.house.get_perpendicular(self.base.pi),
+                        self.base.house.get_perpendicular(self.base.qi),
+                        house,
+                    ],
+                    candidate_clues: vec![
+                        Candidate::from_coord_single(self.r(), self.base.qv),
+                        Candidate::from_coord_single(self.s(), self.base.pv),
+                        Candidate::from_coord(self.r(), self.r_extra),
+                        Candidate::from_coord(self.s(), self.s_extra),
+                    ]
+                    .into_iter()
+                    .chain(combo.iter_ones().filter_map(|i| {
+                        let (cx, cy) = house.ith_cell(i);
+                        game_board.get_candidates(cx, cy).map(|candidates| {
+                            Candidate::new(cx, cy, candidates.intersect(subset_candidates))
+                        })
+                    }))
+                    .collect(),
+                    solver_id,
+                })
+            })
+    }
+}
+
+impl AvoidableRectangle3 {
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        iter_pen_cell(game_board)
+            .flat_map(move |p| iter_base_house(game_board, p))
+            .flat_map(move |base| {
+                iter_span_house(base)
+                    .filter_map(move |span_house| AR3::new(game_board, base, span_house))
+            })
+            .filter_map(move |ar| {
+                ar.investigate_houses()
+                    .find_map(|house| ar.try_get_solution(game_board, house, self.solver_id()))
+            })
+    }
+}
+
+impl Solver for AvoidableRectangle3 {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::AvoidableRectangle3
+    }
+}
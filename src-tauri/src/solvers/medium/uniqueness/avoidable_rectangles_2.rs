@@ -1,13 +1,13 @@
 use crate::{
     game_board::GameBoard,
     solvers::{
-        solution::{Action, Candidate, EliminationDetails, Solution},
         Solver, SolverIdentifier,
+        solution::{Action, Candidate, EliminationDetails, Solution, distinct_by_actions},
     },
     utils::{BitMap, Coord, House, HouseType},
 };
 
-use super::{iter_pen_cell, AvoidableRectangle2, PenCell};
+use super::{AvoidableRectangle2, PenCell, iter_pen_cell};
 
 impl PenCell {
     pub fn get_house_id(&self, dim: HouseType) -> usize {
@@ -22,12 +22,12 @@ impl PenCell {
     }
 }
 #[derive(Copy, Clone)]
-struct BaseHouse {
-    house: House,
-    pi: usize,
-    qi: usize,
-    pv: usize,
-    qv: usize,
+pub(super) struct BaseHouse {
+    pub house: House,
+    pub pi: usize,
+    pub qi: usize,
+    pub pv: usize,
+    pub qv: usize,
 }
 
 impl BaseHouse {
@@ -42,6 +42,34 @@ impl BaseHouse {
     }
 }
 
+/// Used in Avoidable Rectangle type 2 and 3
+/// find another pen mark Q in a line of pen mark P
+pub(super) fn iter_base_house(
+    game_board: &GameBoard,
+    p: PenCell,
+) -> impl Iterator<Item = BaseHouse> + '_ {
+    (0..2).flat_map(move |dim| {
+        let base_house = p.get_base_house(dim);
+        let pi = p.get_cell_id(HouseType::from_dim(dim));
+        (0..9).filter(move |&qi| qi != pi).filter_map(move |qi| {
+            let (qx, qy) = base_house.ith_cell(qi);
+            game_board
+                .get_pen_mark(qx, qy)
+                .map(|qv| BaseHouse::new(p, HouseType::from_dim(dim), qi, qv))
+        })
+    })
+}
+
+/// The span lines forming a rectangle in exactly two boxes with the base house
+pub(super) fn iter_span_house(house: BaseHouse) -> impl Iterator<Item = House> {
+    (0..9)
+        .filter(move |&span| {
+            let base = house.house.get_index();
+            span != base && (span / 3 == base / 3) != (house.pi / 3 == house.qi / 3)
+        })
+        .map(move |span| house.house.get_parallel(span))
+}
+
 struct AR2 {
     base_house: House,
     span_house: House,
@@ -124,19 +152,6 @@ impl AR2 {
     }
 }
 impl AvoidableRectangle2 {
-    fn iter_q(game_board: &GameBoard, p: PenCell) -> impl Iterator<Item = BaseHouse> + '_ {
-        (0..2).flat_map(move |dim| {
-            let base_house = p.get_base_house(dim);
-            let pi = p.get_cell_id(HouseType::from_dim(dim));
-            (0..9).filter(move |&qi| qi != pi).filter_map(move |qi| {
-                let (qx, qy) = base_house.ith_cell(qi);
-                game_board
-                    .get_pen_mark(qx, qy)
-                    .map(|qv| BaseHouse::new(p, HouseType::from_dim(dim), qi, qv))
-            })
-        })
-    }
-
     fn try_extra_candidate(
         game_board: &GameBoard,
         x: usize,
@@ -150,34 +165,36 @@ impl AvoidableRectangle2 {
     }
 
     fn iter_ar(game_board: &GameBoard, house: BaseHouse) -> impl Iterator<Item = AR2> + '_ {
-        (0..9)
-            .filter(move |&span| {
-                let base = house.house.get_index();
-                span != base && (span / 3 == base / 3) != (house.pi / 3 == house.qi / 3)
-            })
-            .filter_map(move |span| {
-                let span_house = house.house.get_parallel(span);
-                let (rx, ry) = span_house.ith_cell(house.pi);
-                let (sx, sy) = span_house.ith_cell(house.qi);
-                Self::try_extra_candidate(game_board, rx, ry, house.qv)
-                    .and_then(|r_clue| {
-                        Self::try_extra_candidate(game_board, sx, sy, house.pv).map(|s_clue| {
-                            (s_clue == r_clue).then_some(AR2::new(house, span_house, r_clue))
-                        })
+        iter_span_house(house).filter_map(move |span_house| {
+            let (rx, ry) = span_house.ith_cell(house.pi);
+            let (sx, sy) = span_house.ith_cell(house.qi);
+            Self::try_extra_candidate(game_board, rx, ry, house.qv)
+                .and_then(|r_clue| {
+                    Self::try_extra_candidate(game_board, sx, sy, house.pv).map(|s_clue| {
+                        (s_clue == r_clue).then_some(AR2::new(house, span_house, r_clue))
                     })
-                    .flatten()
-            })
+                })
+                .flatten()
+        })
+    }
+
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        iter_pen_cell(game_board)
+            .flat_map(move |p| iter_base_house(game_board, p))
+            .flat_map(move |house| Self::iter_ar(game_board, house))
+            .filter_map(move |ar| ar.try_get_solution(game_board, self.solver_id()))
     }
 }
 impl Solver for AvoidableRectangle2 {
-    fn solve(
-        &self,
-        game_board: &crate::game_board::GameBoard,
-    ) -> Option<crate::solvers::solution::Solution> {
-        iter_pen_cell(game_board)
-            .flat_map(|p| Self::iter_q(game_board, p))
-            .flat_map(|house| Self::iter_ar(game_board, house))
-            .find_map(|ar| ar.try_get_solution(game_board, self.solver_id()))
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
@@ -0,0 +1,109 @@
+use crate::{
+    game_board::GameBoard,
+    solvers::{
+        Solver, SolverIdentifier,
+        solution::{Action, Candidate, EliminationDetails, Solution},
+    },
+    utils::{BitMap, Coord},
+};
+
+use super::BiValueUniversalGravePlusN;
+
+/// Count the appearance of each candidate in each row, column and box
+fn count_candidates(cells: &[(usize, usize, BitMap)]) -> [[[usize; 9]; 9]; 3] {
+    let mut counts = [[[0; 9]; 9]; 3];
+    for &(x, y, candidates) in cells {
+        for (dim, id) in Coord::components_array(x, y).into_iter().enumerate() {
+            for target in candidates.iter_ones() {
+                counts[dim][id][target] += 1;
+            }
+        }
+    }
+    counts
+}
+
+/// Find the BUG candidates, which appear more than twice in a house
+/// returns None if removing them doesn't leave a Bi-Value Universal Grave
+fn find_bug_candidates(game_board: &GameBoard) -> Option<Vec<(usize, usize, usize)>> {
+    let cells: Vec<_> = Coord::all_cells()
+        .filter_map(|(x, y)| Some((x, y, game_board.get_candidates(x, y)?)))
+        .collect();
+    let counts = count_candidates(&cells);
+    let mut bug_candidates = vec![];
+    let mut grave = vec![];
+    for &(x, y, candidates) in &cells {
+        let components = Coord::components_array(x, y);
+        let extra: BitMap = candidates
+            .iter_ones()
+            .filter(|&target| {
+                candidates.count() > 2 && (0..3).any(|dim| counts[dim][components[dim]][target] > 2)
+            })
+            .collect();
+        let remaining = candidates.difference(extra);
+        if remaining.count() != 2 {
+            return None;
+        }
+        bug_candidates.extend(extra.iter_ones().map(|target| (x, y, target)));
+        grave.push((x, y, remaining));
+    }
+    count_candidates(&grave)
+        .iter()
+        .flatten()
+        .flatten()
+        .all(|&count| count == 0 || count == 2)
+        .then_some(bug_candidates)
+}
+
+impl Solver for BiValueUniversalGravePlusN {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        let bug_candidates = find_bug_candidates(game_board)?;
+        if bug_candidates.len() < 2 {
+            return None;
+        }
+        // one of the BUG candidates must be true, so a candidate eliminated by each of them is false
+        let eliminated_by =
+            |x: usize, y: usize, target: usize, (bx, by, bt): (usize, usize, usize)| {
+                if (x, y) == (bx, by) {
+                    target != bt
+                } else {
+                    target == bt && Coord::sees(x, y, bx, by)
+                }
+            };
+        let actions: Vec<_> = Coord::all_cells()
+            .filter_map(|(x, y)| {
+                let target: BitMap = game_board
+                    .get_candidates(x, y)?
+                    .iter_ones()
+                    .filter(|&target| {
+                        bug_candidates
+                            .iter()
+                            .all(|&bug| eliminated_by(x, y, target, bug))
+                    })
+                    .collect();
+                (target.count() > 0).then_some(Action::Elimination(EliminationDetails {
+                    x,
+                    y,
+                    target,
+                }))
+            })
+            .collect();
+        (!actions.is_empty()).then(|| Solution {
+            actions,
+            house_clues: vec![],
+            candidate_clues: bug_candidates
+                .into_iter()
+                .map(|(x, y, target)| Candidate::new_single(x, y, target))
+                .collect(),
+            solver_id: self.solver_id(),
+        })
+    }
+
+    /// The grave covers the whole board, so the step of solve is the only one
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        self.solve(game_board).into_iter().collect()
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::BiValueUniversalGravePlusN
+    }
+}
@@ -1,8 +1,8 @@
 use crate::{
     game_board::GameBoard,
     solvers::{
-        solution::{Action, ConfirmationDetails, Solution},
         Solver, SolverIdentifier,
+        solution::{Action, ConfirmationDetails, Solution},
     },
     utils::{BitMap, Coord},
 };
@@ -118,6 +118,11 @@ impl Solver for BiValueUniversalGravePlusOne {
             })
     }
 
+    /// The grave covers the whole board, so the step of solve is the only one
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        self.solve(game_board).into_iter().collect()
+    }
+
     fn solver_id(&self) -> SolverIdentifier {
         SolverIdentifier::BiValueUniversalGravePlusOne
     }
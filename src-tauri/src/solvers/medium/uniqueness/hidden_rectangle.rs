@@ -1,13 +1,13 @@
 use crate::{
     game_board::GameBoard,
     solvers::{
-        solution::{Action, Candidate, EliminationDetails, Solution},
         Solver, SolverIdentifier,
+        solution::{Action, Candidate, EliminationDetails, Solution, distinct_by_actions},
     },
     utils::{BitMap, House, HouseType},
 };
 
-use super::{iter_valid_bi_value, valid_unique_rectangle_cell, BiValueCell, HiddenRectangle};
+use super::{BiValueCell, HiddenRectangle, iter_valid_bi_value, valid_unique_rectangle_cell};
 #[derive(Clone, Copy)]
 
 struct BaseRow {
@@ -126,6 +126,8 @@ impl HiddenRectangle {
                         .trailing_zeros(),
                 )
             })
+            // the clue may be gone from the fourth corner already
+            .filter(|&(_, clue)| game_board.contains_candidate(hr.sx, hr.sy, clue))
             .find_map(|(target, clue)| {
                 game_board
                     .get_hard_link(hr.sx, hr.sy, target, HouseType::Row)
@@ -144,13 +146,24 @@ impl HiddenRectangle {
                     })
             })
     }
+
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        iter_valid_bi_value(game_board)
+            .flat_map(move |bi_value_cell| Self::iter_q(bi_value_cell, game_board))
+            .flat_map(move |row| Self::iter_r(row, game_board))
+            .filter_map(move |ur| self.get_solution(ur, game_board))
+    }
 }
 impl Solver for HiddenRectangle {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        iter_valid_bi_value(game_board)
-            .flat_map(|bi_value_cell| Self::iter_q(bi_value_cell, game_board))
-            .flat_map(|row| Self::iter_r(row, game_board))
-            .find_map(|ur| self.get_solution(ur, game_board))
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
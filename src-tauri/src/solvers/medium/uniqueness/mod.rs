@@ -1,4 +1,4 @@
-use super::{iter_valid_bi_value, BiValueCell};
+use super::{BiValueCell, iter_valid_bi_value};
 use crate::{
     game_board::GameBoard,
     utils::{BitMap, Coord, House, HouseType},
@@ -226,6 +226,35 @@ mod test_5;
 pub struct UniquenessTest6;
 
 mod test_6;
+
+/// Uniqueness Rectangle with two bi-value cells and a strong link on one of its long sides, an extension of [HoDoKu explanations on Uniqueness Rectangle Type 4](https://hodoku.sourceforge.net/en/tech_ur.php#u4)
+///
+/// ## Terminology
+/// - The side containing two bi-value cells is called the **base house**, and the opposite side is called the **span house**.
+/// - One of the bi-value candidates, called **link**, forms a strong link between a base cell and the span cell in the same line.
+/// - The other bi-value candidate is called **target**, and it is eliminated from the other span cell, which would otherwise complete the deadly pattern.
+///
+/// ## Return Format
+/// - **Actions**: Contains 1 element, representing the elimination of the target.
+/// - **House Clues**: Contains 4 elements, representing the base house, the span house, and the other two sides, in ascending order.
+/// - **Candidate Clues**: Contains 4 elements, representing the bi-value candidates in the first and second base cells, followed by the two ends of the strong link.
+pub struct UniquenessTest7;
+mod test_7;
+
+/// Uniqueness Rectangle with one bi-value cell and strong links on both sides opposite to it, an extension of [HoDoKu explanations on Hidden Rectangle](https://hodoku.sourceforge.net/en/tech_ur.php#hr)
+///
+/// ## Terminology
+/// - The bi-value cell is called **pivot**, denoted by P, and the cell diagonally opposite to it is denoted by S.
+/// - The cell in the same row as P is denoted by Q, and the cell in the same column as P is denoted by R.
+/// - One bi-value candidate forms a strong link between Q and S in their column, and the other forms a strong link between R and S in their row.
+/// - The candidate linked in the row is eliminated from Q, and the candidate linked in the column is eliminated from R.
+///
+/// ## Return Format
+/// - **Actions**: Contains up to 2 elements, representing the eliminations in Q and R.
+/// - **House Clues**: Contains 4 elements, representing the rows of P and S, followed by the columns of P and S.
+/// - **Candidate Clues**: Contains 5 elements, representing the bi-value candidates of P, followed by the two ends of the column link and the two ends of the row link.
+pub struct UniquenessTest8;
+mod test_8;
 /// [HoDoKu explanations on Hidden Rectangle](https://hodoku.sourceforge.net/en/tech_ur.php#hr)
 ///
 /// ## Terminology
@@ -269,13 +298,59 @@ mod avoidable_rectangle_1;
 pub struct AvoidableRectangle2;
 mod avoidable_rectangles_2;
 
+/// Avoidable Rectangle with a naked subset, in the same way as [HoDoKu explanations on Uniqueness Rectangle Type 3](https://hodoku.sourceforge.net/en/tech_ur.php#u3)
+///
+/// ## Terminology
+/// - The line with two pen marks is called **Base Line**, the two AR Cells are denoted by P and Q in ascending order of indices.
+/// - The line with two unsolved cells is called **Span Line**, the two Cells are denoted by R and S in ascending order of indices.
+/// - The candidates in R and S other than the pen marks of Q and P form a **virtual cell**, which forms a naked subset with other cells in a house containing both R and S.
+///
+/// ## Return Format
+/// - **Actions**: Contains a variable number of elements representing the subset candidates eliminated in the house.
+/// - **House Clues**: Contains 5 elements, representing the base house, the span house, and the other two sides, in ascending order, followed by the house of naked subset.
+/// - **Candidate Clues**: Contains a variable number of elements, representing AR candidate in R and S, the extra candidates in R and S, followed by the subset candidates in other cells of the naked subset.
+pub struct AvoidableRectangle3;
+mod avoidable_rectangle_3;
+
 pub struct BiValueUniversalGravePlusOne;
 mod bivalue_universal_grave_plus_one;
 
+/// [Sudopedia explanations on Unique Loop](http://sudopedia.enjoysudoku.com/Unique_Loop.html)
+///
+/// ## Terminology
+/// - A loop of 6 or 8 cells containing the same two candidates, where consecutive cells share a row or a column,
+///   and each row, column and box contains either none or two of the cells, is a deadly pattern.
+/// - The candidates other than the loop candidates are called **extra candidates**.
+///     - Type 1: only one cell contains extra candidates, so the loop candidates are eliminated from it.
+///     - Type 2: all cells with extra candidates have the same single extra candidate, which is eliminated from all cells seeing them.
+///
+/// ## Return Format
+/// - **Actions**: Contains 1 element for type 1, or a variable number of elements for type 2.
+/// - **House Clues**: An empty vector.
+/// - **Candidate Clues**: Contains a variable number of elements, representing the loop candidates in the loop cells in order, followed by the extra candidates.
+pub struct UniqueLoop;
+mod unique_loop;
+
+/// [HoDoKu explanations on Bivalue Universal Grave](https://hodoku.sourceforge.net/en/tech_ur.php#bug)
+///
+/// ## Terminology
+/// - The candidates appearing more than twice in a house are called **BUG candidates**. Without them, every unsolved cell
+///   would be bi-value and every candidate would appear twice in each house, which is a deadly pattern.
+/// - One of the BUG candidates must be true, so a candidate eliminated by each of them is eliminated.
+///
+/// ## Return Format
+/// - **Actions**: Contains a variable number of elements, representing the eliminated candidates.
+/// - **House Clues**: An empty vector.
+/// - **Candidate Clues**: Contains a variable number of elements, representing the BUG candidates.
+pub struct BiValueUniversalGravePlusN;
+mod bivalue_universal_grave_plus_n;
+
 #[cfg(test)]
 mod uniqueness_test {
 
     use super::*;
+    use crate::solvers::Solver;
+    use crate::solvers::solution::{Action, EliminationDetails};
     use crate::tests::common::{test_function_c, test_function_e};
     use crate::utils::House::{Box, Col, Row};
     #[test]
@@ -431,6 +506,44 @@ mod uniqueness_test {
         );
     }
 
+    #[test]
+    fn uniqueness_test_7() {
+        test_function_e(
+            UniquenessTest7,
+            [
+                552, 640, 576, 524, 513, 768, 2, 548, 16, 555, 523, 547, 128, 16, 548, 768, 64,
+                556, 256, 4, 528, 586, 586, 546, 1, 640, 552, 535, 32, 775, 774, 518, 64, 520, 531,
+                128, 640, 576, 8, 16, 546, 1, 4, 546, 768, 535, 771, 775, 782, 558, 128, 576, 563,
+                547, 64, 523, 640, 32, 256, 522, 16, 519, 519, 551, 771, 807, 582, 582, 528, 640,
+                8, 515, 526, 16, 518, 1, 640, 526, 32, 256, 576,
+            ],
+            vec![(5, 7)],                         //exp_actions
+            vec![2],                              //exp_action_targets
+            vec![Row(4), Row(5), Col(4), Col(7)], //exp_house_clues
+            vec![(4, 4), (4, 7), (4, 4), (5, 4)], //exp_candidate_clues
+            vec![34, 34, 32, 32],                 //exp_candidate_masks
+        );
+    }
+
+    #[test]
+    fn uniqueness_test_8() {
+        test_function_e(
+            UniquenessTest8,
+            [
+                668, 648, 668, 672, 514, 256, 560, 513, 64, 1, 576, 544, 8, 516, 16, 768, 514, 640,
+                256, 514, 656, 576, 1, 672, 560, 520, 4, 726, 32, 724, 529, 704, 520, 580, 256,
+                531, 722, 641, 976, 4, 960, 545, 8, 608, 531, 604, 521, 860, 561, 832, 2, 128, 612,
+                529, 648, 256, 521, 2, 528, 64, 517, 644, 32, 704, 4, 705, 768, 32, 641, 2, 528,
+                520, 544, 16, 514, 641, 520, 516, 577, 704, 256,
+            ],
+            vec![(5, 3), (3, 8)],                         //exp_actions
+            vec![1, 16],                                  //exp_action_targets
+            vec![Row(5), Row(3), Col(8), Col(3)],         //exp_house_clues
+            vec![(5, 8), (5, 3), (3, 3), (3, 8), (3, 3)], //exp_candidate_clues
+            vec![17, 16, 16, 1, 1],                       //exp_candidate_masks
+        );
+    }
+
     #[test]
     fn hidden_rectangle_test() {
         test_function_e(
@@ -450,6 +563,39 @@ mod uniqueness_test {
         );
     }
 
+    #[test]
+    fn hidden_rectangle_soundness() {
+        // positions found by the soundness harness, where the clue is no longer a candidate of the fourth corner
+        let boards = [
+            [
+                896, 912, 64, 514, 4, 32, 776, 776, 905, 8, 896, 4, 576, 528, 513, 800, 802, 930,
+                513, 32, 514, 256, 640, 520, 528, 576, 4, 834, 834, 800, 128, 832, 16, 812, 1, 810,
+                4, 849, 817, 520, 832, 2, 128, 816, 816, 898, 8, 912, 544, 1, 516, 64, 786, 530,
+                32, 837, 769, 16, 520, 128, 514, 772, 832, 16, 704, 648, 516, 2, 768, 1, 552, 616,
+                770, 774, 776, 513, 544, 64, 780, 128, 792,
+            ],
+            [
+                597, 32, 732, 2, 256, 645, 641, 705, 704, 771, 643, 650, 576, 521, 16, 4, 899, 32,
+                839, 707, 710, 548, 545, 645, 16, 8, 962, 595, 256, 594, 128, 529, 577, 520, 544,
+                516, 8, 720, 756, 548, 560, 836, 2, 896, 1, 549, 641, 676, 556, 514, 773, 64, 16,
+                896, 626, 4, 626, 513, 640, 8, 768, 578, 578, 128, 8, 513, 256, 64, 514, 32, 516,
+                528, 578, 578, 256, 16, 516, 32, 641, 707, 714,
+            ],
+        ];
+        for raws in boards {
+            let game_board = GameBoard::from_array(raws);
+            for solution in HiddenRectangle.solve_all(&game_board) {
+                assert!(solution.actions.iter().all(|action| matches!(
+                    action,
+                    Action::Elimination(EliminationDetails { x, y, target })
+                        if game_board
+                            .get_candidates(*x, *y)
+                            .is_some_and(|candidates| candidates.intersect(*target).count() > 0)
+                )));
+            }
+        }
+    }
+
     #[test]
     fn avoidable_rectangle_1_test() {
         test_function_e(
@@ -488,6 +634,25 @@ mod uniqueness_test {
         );
     }
 
+    #[test]
+    fn avoidable_rectangle_3_test() {
+        test_function_e(
+            AvoidableRectangle3,
+            [
+                576, 529, 544, 2, 769, 896, 644, 8, 532, 8, 2, 640, 544, 4, 528, 576, 768, 1, 768,
+                529, 4, 64, 521, 648, 544, 642, 530, 2, 544, 520, 517, 592, 580, 644, 705, 768, 1,
+                256, 16, 644, 706, 32, 520, 578, 518, 128, 516, 576, 769, 778, 776, 16, 515, 32,
+                516, 576, 768, 656, 640, 1, 2, 544, 8, 544, 520, 514, 772, 832, 580, 513, 16, 640,
+                528, 128, 513, 520, 32, 514, 256, 4, 576,
+            ],
+            vec![(4, 3), (5, 4)],                                 //exp_actions
+            vec![4, 256],                                         //exp_action_targets
+            vec![Col(2), Col(5), Row(3), Row(5), Box(4)],         //exp_house_clues
+            vec![(3, 5), (5, 5), (3, 5), (5, 5), (3, 3), (5, 3)], //exp_candidate_clues
+            vec![64, 8, 4, 256, 5, 257],                          //exp_candidate_masks
+        );
+    }
+
     #[test]
     fn bug_test() {
         test_function_c(
@@ -506,4 +671,42 @@ mod uniqueness_test {
             vec![],       //exp_candi_masks
         )
     }
+
+    #[test]
+    fn unique_loop_test() {
+        test_function_e(
+            UniqueLoop,
+            [
+                32, 784, 784, 578, 704, 706, 524, 517, 521, 514, 576, 640, 8, 1, 4, 544, 528, 768,
+                516, 513, 8, 544, 768, 528, 514, 128, 64, 784, 784, 64, 128, 2, 32, 513, 8, 4, 128,
+                518, 545, 532, 536, 521, 576, 256, 546, 520, 518, 545, 768, 580, 577, 640, 546, 16,
+                848, 32, 784, 1, 536, 704, 780, 518, 642, 785, 640, 4, 530, 32, 522, 776, 64, 521,
+                577, 8, 514, 580, 708, 256, 16, 545, 672,
+            ],
+            vec![(6, 0)],                                                 //exp_actions
+            vec![272],                                                    //exp_action_targets
+            vec![],                                                       //exp_house_clues
+            vec![(0, 1), (0, 2), (6, 2), (6, 0), (3, 0), (3, 1), (6, 0)], //exp_candidate_clues
+            vec![272, 272, 272, 272, 272, 272, 64],                       //exp_candidate_masks
+        );
+    }
+
+    #[test]
+    fn bug_plus_n_test() {
+        test_function_e(
+            BiValueUniversalGravePlusN,
+            [
+                576, 544, 640, 526, 518, 524, 528, 513, 768, 516, 16, 513, 64, 544, 768, 640, 8,
+                514, 8, 768, 514, 1, 528, 128, 576, 32, 516, 16, 514, 4, 640, 513, 64, 552, 256,
+                552, 513, 128, 256, 528, 520, 32, 4, 514, 576, 544, 64, 520, 772, 772, 2, 1, 528,
+                640, 640, 8, 528, 800, 832, 517, 2, 580, 545, 514, 517, 32, 524, 704, 528, 768,
+                704, 521, 256, 517, 64, 546, 642, 525, 552, 644, 16,
+            ],
+            vec![(0, 5), (7, 3)], //exp_actions
+            vec![4, 4],           //exp_action_targets
+            vec![],               //exp_house_clues
+            vec![(0, 3), (8, 5)], //exp_candidate_clues
+            vec![4, 4],           //exp_candidate_masks
+        );
+    }
 }
@@ -1,14 +1,14 @@
 use crate::{
     game_board::GameBoard,
     solvers::{
-        medium::uniqueness::iter_valid_bi_value,
-        solution::{Action, Candidate, EliminationDetails, Solution},
         Solver, SolverIdentifier,
+        medium::uniqueness::iter_valid_bi_value,
+        solution::{Action, Candidate, EliminationDetails, Solution, distinct_by_actions},
     },
     utils::{BitMap, House},
 };
 
-use super::{valid_unique_rectangle_cell, BiValueCell, UniquenessTest1};
+use super::{BiValueCell, UniquenessTest1, valid_unique_rectangle_cell};
 #[derive(Clone, Copy)]
 struct BaseRow {
     x: usize,
@@ -131,14 +131,24 @@ impl UniquenessTest1 {
             })
             .flatten()
     }
+
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        iter_valid_bi_value(game_board)
+            .flat_map(move |p| Self::iter_valid_base_row(game_board, p))
+            .flat_map(move |base_row| Self::iter_valid_rectangle(game_board, base_row))
+            .map(move |ur| ur.get_solution(self.solver_id()))
+    }
 }
 impl Solver for UniquenessTest1 {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        iter_valid_bi_value(game_board)
-            .flat_map(|p| Self::iter_valid_base_row(game_board, p))
-            .flat_map(|base_row| Self::iter_valid_rectangle(game_board, base_row))
-            .map(|ur| ur.get_solution(self.solver_id()))
-            .next()
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
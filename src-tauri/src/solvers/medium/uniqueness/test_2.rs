@@ -1,26 +1,29 @@
 use crate::{
     game_board::GameBoard,
     solvers::{
-        solution::{Action, Candidate, EliminationDetails, Solution},
         Solver, SolverIdentifier,
+        solution::{Action, Candidate, EliminationDetails, Solution, distinct_by_actions},
     },
     utils::{BitMap, Coord},
 };
 
-use super::{semi_possible_ur, SemiPossibleUR, UniquenessTest2};
+use super::{SemiPossibleUR, UniquenessTest2, semi_possible_ur};
 
-impl Solver for UniquenessTest2 {
-    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        semi_possible_ur(game_board).find_map(
-            |SemiPossibleUR {
-                 base_house,
-                 base_bi_value,
-                 first_index,
-                 second_index,
-                 span_house,
-                 first_span_candidates,
-                 second_span_candidates,
-             }| {
+impl UniquenessTest2 {
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        semi_possible_ur(game_board).filter_map(
+            move |SemiPossibleUR {
+                      base_house,
+                      base_bi_value,
+                      first_index,
+                      second_index,
+                      span_house,
+                      first_span_candidates,
+                      second_span_candidates,
+                  }| {
                 let first_diff = first_span_candidates.difference(base_bi_value);
                 let second_diff = second_span_candidates.difference(base_bi_value);
                 (first_diff.count() == 1 && first_diff == second_diff)
@@ -80,6 +83,16 @@ impl Solver for UniquenessTest2 {
             },
         )
     }
+}
+
+impl Solver for UniquenessTest2 {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
+    }
 
     fn solver_id(&self) -> SolverIdentifier {
         SolverIdentifier::UniquenessTest2
@@ -1,26 +1,29 @@
 use crate::{
     game_board::GameBoard,
     solvers::{
-        solution::{Action, Candidate, EliminationDetails, Solution},
         Solver, SolverIdentifier,
+        solution::{Action, Candidate, EliminationDetails, Solution, distinct_by_actions},
     },
     utils::{BitMap, Coord},
 };
 
-use super::{semi_possible_ur, SemiPossibleUR, UniquenessTest4};
+use super::{SemiPossibleUR, UniquenessTest4, semi_possible_ur};
 
-impl Solver for UniquenessTest4 {
-    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        semi_possible_ur(game_board).find_map(
-            |SemiPossibleUR {
-                 base_house,
-                 base_bi_value,
-                 first_index,
-                 second_index,
-                 span_house,
-                 first_span_candidates,
-                 second_span_candidates,
-             }| {
+impl UniquenessTest4 {
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        semi_possible_ur(game_board).filter_map(
+            move |SemiPossibleUR {
+                      base_house,
+                      base_bi_value,
+                      first_index,
+                      second_index,
+                      span_house,
+                      first_span_candidates,
+                      second_span_candidates,
+                  }| {
                 let (px, py) = Coord::from_house_and_index(&span_house, first_index);
                 let (qx, qy) = Coord::from_house_and_index(&span_house, second_index);
 
@@ -93,6 +96,16 @@ impl Solver for UniquenessTest4 {
             },
         )
     }
+}
+
+impl Solver for UniquenessTest4 {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
+    }
 
     fn solver_id(&self) -> SolverIdentifier {
         SolverIdentifier::UniquenessTest4
@@ -1,13 +1,13 @@
 use crate::{
     game_board::GameBoard,
     solvers::{
-        solution::{Action, Candidate, EliminationDetails, Solution},
         Solver, SolverIdentifier,
+        solution::{Action, Candidate, EliminationDetails, Solution, distinct_by_actions},
     },
     utils::{BitMap, Coord, House},
 };
 
-use super::{iter_valid_bi_value, valid_unique_rectangle_cell, BiValueCell, UniquenessTest5};
+use super::{BiValueCell, UniquenessTest5, iter_valid_bi_value, valid_unique_rectangle_cell};
 
 #[derive(Clone, Copy)]
 struct BaseRow {
@@ -179,13 +179,15 @@ impl UniquenessTest5 {
             })
             .collect()
     }
-}
-impl Solver for UniquenessTest5 {
-    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
         iter_valid_bi_value(game_board)
-            .flat_map(|bi_value_cell| Self::iter_valid_base_row(game_board, bi_value_cell))
-            .flat_map(|base_row| Self::iter_valid_rectangle(game_board, base_row))
-            .find_map(|ur| {
+            .flat_map(move |bi_value_cell| Self::iter_valid_base_row(game_board, bi_value_cell))
+            .flat_map(move |base_row| Self::iter_valid_rectangle(game_board, base_row))
+            .filter_map(move |ur| {
                 let actions = Self::get_eliminables(game_board, ur);
                 (!actions.is_empty()).then(|| Solution {
                     actions,
@@ -195,6 +197,15 @@ impl Solver for UniquenessTest5 {
                 })
             })
     }
+}
+impl Solver for UniquenessTest5 {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
+    }
 
     fn solver_id(&self) -> SolverIdentifier {
         SolverIdentifier::UniquenessTest5
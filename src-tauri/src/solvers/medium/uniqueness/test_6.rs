@@ -1,13 +1,13 @@
 use crate::{
     game_board::GameBoard,
     solvers::{
-        solution::{Action, Candidate, ConfirmationDetails, Solution},
         Solver, SolverIdentifier,
+        solution::{Action, Candidate, ConfirmationDetails, Solution, distinct_by_actions},
     },
     utils::{BitMap, House, HouseType},
 };
 
-use super::{iter_valid_bi_value, valid_unique_rectangle_cell, BiValueCell, UniquenessTest6};
+use super::{BiValueCell, UniquenessTest6, iter_valid_bi_value, valid_unique_rectangle_cell};
 #[derive(Clone, Copy)]
 
 struct BaseRow {
@@ -180,13 +180,24 @@ impl UniquenessTest6 {
                     })
             })
     }
+
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        iter_valid_bi_value(game_board)
+            .flat_map(move |bi_value_cell| Self::iter_q(bi_value_cell, game_board))
+            .flat_map(move |row| Self::iter_r(row, game_board))
+            .filter_map(move |ur| self.get_solution(ur, game_board))
+    }
 }
 impl Solver for UniquenessTest6 {
-    fn solve(&self, game_board: &GameBoard) -> Option<crate::solvers::solution::Solution> {
-        iter_valid_bi_value(game_board)
-            .flat_map(|bi_value_cell| Self::iter_q(bi_value_cell, game_board))
-            .flat_map(|row| Self::iter_r(row, game_board))
-            .find_map(|ur| self.get_solution(ur, game_board))
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
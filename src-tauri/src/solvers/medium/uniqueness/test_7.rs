@@ -0,0 +1,84 @@
+use crate::{
+    game_board::GameBoard,
+    solvers::{
+        Solver, SolverIdentifier,
+        solution::{Action, Candidate, EliminationDetails, Solution, distinct_by_actions},
+    },
+    utils::{BitMap, Coord},
+};
+
+use super::{SemiPossibleUR, UniquenessTest7, semi_possible_ur};
+
+impl UniquenessTest7 {
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        semi_possible_ur(game_board).filter_map(
+            move |SemiPossibleUR {
+                      base_house,
+                      base_bi_value,
+                      first_index,
+                      second_index,
+                      span_house,
+                      ..
+                  }| {
+                let side_type = base_house.get_type().other();
+                [(first_index, second_index), (second_index, first_index)]
+                    .into_iter()
+                    .find_map(|(link_index, target_index)| {
+                        let (bx, by) = Coord::from_house_and_index(&base_house, link_index);
+                        let (dx, dy) = Coord::from_house_and_index(&span_house, link_index);
+                        let (cx, cy) = Coord::from_house_and_index(&span_house, target_index);
+                        base_bi_value.iter_ones().find_map(|link| {
+                            let target = base_bi_value
+                                .difference(BitMap::from(link))
+                                .trailing_zeros();
+                            (game_board.get_hard_link(dx, dy, link, side_type) == Some((bx, by))
+                                && game_board.contains_candidate(cx, cy, target))
+                            .then(|| Solution {
+                                actions: vec![Action::Elimination(EliminationDetails {
+                                    x: cx,
+                                    y: cy,
+                                    target: BitMap::from(target),
+                                })],
+                                house_clues: vec![
+                                    base_house,
+                                    span_house,
+                                    base_house.get_perpendicular(first_index),
+                                    base_house.get_perpendicular(second_index),
+                                ],
+                                candidate_clues: vec![
+                                    Candidate::from_coord(
+                                        Coord::from_house_and_index(&base_house, first_index),
+                                        base_bi_value,
+                                    ),
+                                    Candidate::from_coord(
+                                        Coord::from_house_and_index(&base_house, second_index),
+                                        base_bi_value,
+                                    ),
+                                    Candidate::new_single(bx, by, link),
+                                    Candidate::new_single(dx, dy, link),
+                                ],
+                                solver_id: self.solver_id(),
+                            })
+                        })
+                    })
+            },
+        )
+    }
+}
+
+impl Solver for UniquenessTest7 {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::UniquenessTest7
+    }
+}
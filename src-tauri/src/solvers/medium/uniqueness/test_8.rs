@@ -0,0 +1,127 @@
+use crate::{
+    game_board::GameBoard,
+    solvers::{
+        Solver, SolverIdentifier,
+        solution::{Action, Candidate, EliminationDetails, Solution, distinct_by_actions},
+    },
+    utils::{BitMap, House, HouseType},
+};
+
+use super::{BiValueCell, UniquenessTest8, iter_valid_bi_value, valid_unique_rectangle_cell};
+
+struct UR8 {
+    px: usize,
+    py: usize,
+    sx: usize,
+    sy: usize,
+    bi_value: BitMap,
+}
+
+impl UR8 {
+    pub fn get_house_clues(&self) -> Vec<House> {
+        vec![
+            House::Row(self.px),
+            House::Row(self.sx),
+            House::Col(self.py),
+            House::Col(self.sy),
+        ]
+    }
+
+    /// The column link is on `col_link` between Q and S, the row link is on `row_link` between R and S
+    pub fn try_get_solution(
+        &self,
+        game_board: &GameBoard,
+        col_link: usize,
+        row_link: usize,
+        solver_id: SolverIdentifier,
+    ) -> Option<Solution> {
+        let (qx, qy) = (self.px, self.sy);
+        let (rx, ry) = (self.sx, self.py);
+        if game_board.get_hard_link(self.sx, self.sy, col_link, HouseType::Col) != Some((qx, qy))
+            || game_board.get_hard_link(self.sx, self.sy, row_link, HouseType::Row)
+                != Some((rx, ry))
+        {
+            return None;
+        }
+        let actions: Vec<_> = [(qx, qy, row_link), (rx, ry, col_link)]
+            .into_iter()
+            .filter(|&(x, y, target)| game_board.contains_candidate(x, y, target))
+            .map(|(x, y, target)| {
+                Action::Elimination(EliminationDetails {
+                    x,
+                    y,
+                    target: BitMap::from(target),
+                })
+            })
+            .collect();
+        (!actions.is_empty()).then(|| Solution {
+            actions,
+            house_clues: self.get_house_clues(),
+            candidate_clues: vec![
+                Candidate::new(self.px, self.py, self.bi_value),
+                Candidate::new_single(qx, qy, col_link),
+                Candidate::new_single(self.sx, self.sy, col_link),
+                Candidate::new_single(rx, ry, row_link),
+                Candidate::new_single(self.sx, self.sy, row_link),
+            ],
+            solver_id,
+        })
+    }
+}
+
+impl UniquenessTest8 {
+    fn iter_ur(p: BiValueCell, game_board: &GameBoard) -> impl Iterator<Item = UR8> + '_ {
+        (0..9)
+            .filter(move |&qy| {
+                qy != p.y && valid_unique_rectangle_cell(game_board, p.x, qy, p.bi_value).is_some()
+            })
+            .flat_map(move |qy| {
+                (0..9)
+                    .filter(move |&rx| {
+                        rx != p.x
+                            && (rx / 3 == p.x / 3) != (p.y / 3 == qy / 3)
+                            && valid_unique_rectangle_cell(game_board, rx, p.y, p.bi_value)
+                                .is_some()
+                            && valid_unique_rectangle_cell(game_board, rx, qy, p.bi_value).is_some()
+                    })
+                    .map(move |rx| UR8 {
+                        px: p.x,
+                        py: p.y,
+                        sx: rx,
+                        sy: qy,
+                        bi_value: p.bi_value,
+                    })
+            })
+    }
+
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        iter_valid_bi_value(game_board)
+            .flat_map(move |p| Self::iter_ur(p, game_board))
+            .filter_map(move |ur| {
+                ur.bi_value.iter_ones().find_map(|col_link| {
+                    let row_link = ur
+                        .bi_value
+                        .difference(BitMap::from(col_link))
+                        .trailing_zeros();
+                    ur.try_get_solution(game_board, col_link, row_link, self.solver_id())
+                })
+            })
+    }
+}
+
+impl Solver for UniquenessTest8 {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::UniquenessTest8
+    }
+}
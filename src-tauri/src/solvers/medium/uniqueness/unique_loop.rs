@@ -0,0 +1,214 @@
+use crate::{
+    game_board::GameBoard,
+    solvers::{
+        Solver, SolverIdentifier,
+        solution::{
+            Action, Candidate, EliminationDetails, Solution, Visitor, distinct_visited,
+            first_visited,
+        },
+    },
+    utils::{BitMap, Coord},
+};
+
+use super::{UniqueLoop, valid_unique_rectangle_cell};
+
+/// Loops with 4 cells are unique rectangles, and longer loops are rarely seen
+const LOOP_LENGTHS: [usize; 2] = [6, 8];
+
+#[derive(Clone, Copy)]
+struct LoopCell {
+    x: usize,
+    y: usize,
+    extra: BitMap,
+}
+
+struct LoopSearch<'a> {
+    game_board: &'a GameBoard,
+    bi_value: BitMap,
+    cells: Vec<LoopCell>,
+    house_counts: [[usize; 9]; 3],
+}
+
+impl<'a> LoopSearch<'a> {
+    fn new(game_board: &'a GameBoard, bi_value: BitMap) -> Self {
+        Self {
+            game_board,
+            bi_value,
+            cells: Coord::all_cells()
+                .filter_map(|(x, y)| {
+                    valid_unique_rectangle_cell(game_board, x, y, bi_value).and_then(
+                        |(candidates, extra)| {
+                            (candidates == bi_value).then_some(LoopCell { x, y, extra })
+                        },
+                    )
+                })
+                .collect(),
+            house_counts: [[0; 9]; 3],
+        }
+    }
+
+    fn update_house_counts(&mut self, cell: LoopCell, add: bool) {
+        for (dim, id) in Coord::components_array(cell.x, cell.y)
+            .into_iter()
+            .enumerate()
+        {
+            if add {
+                self.house_counts[dim][id] += 1;
+            } else {
+                self.house_counts[dim][id] -= 1;
+            }
+        }
+    }
+
+    /// A row, column or box of the loop can't contain more than 2 loop cells
+    fn fits(&self, cell: LoopCell) -> bool {
+        Coord::components_array(cell.x, cell.y)
+            .into_iter()
+            .enumerate()
+            .all(|(dim, id)| self.house_counts[dim][id] < 2)
+    }
+
+    /// Only cells with no extra candidates, and either a single cell with extra candidates,
+    /// or cells with the same single extra candidate are accepted
+    fn extra_acceptable(path: &[LoopCell]) -> bool {
+        let mut extras = path.iter().filter(|cell| cell.extra.count() > 0);
+        match extras.next() {
+            None => true,
+            Some(first) => {
+                let others: Vec<_> = extras.collect();
+                others.is_empty()
+                    || (first.extra.count() == 1
+                        && others.iter().all(|cell| cell.extra == first.extra))
+            }
+        }
+    }
+
+    /// Depth first search of the loop, where the first cell has the smallest index,
+    /// returns true once the visitor stops it
+    fn search(
+        &mut self,
+        path: &mut Vec<usize>,
+        solver_id: SolverIdentifier,
+        visit: Visitor,
+    ) -> bool {
+        let Some(&last) = path.last() else {
+            return false;
+        };
+        let last = self.cells[last];
+        let first = self.cells[path[0]];
+        if LOOP_LENGTHS.contains(&path.len())
+            && (last.x == first.x || last.y == first.y)
+            && self.house_counts.iter().flatten().all(|&count| count != 1)
+            && self
+                .try_get_solution(path, solver_id)
+                .is_some_and(&mut *visit)
+        {
+            return true;
+        }
+        if path.len() == LOOP_LENGTHS[LOOP_LENGTHS.len() - 1] {
+            return false;
+        }
+        for next in path[0] + 1..self.cells.len() {
+            let cell = self.cells[next];
+            if path.contains(&next) || !(cell.x == last.x || cell.y == last.y) || !self.fits(cell) {
+                continue;
+            }
+            let mut loop_cells: Vec<_> = path.iter().map(|&index| self.cells[index]).collect();
+            loop_cells.push(cell);
+            if !Self::extra_acceptable(&loop_cells) {
+                continue;
+            }
+            path.push(next);
+            self.update_house_counts(cell, true);
+            let stopped = self.search(path, solver_id, visit);
+            self.update_house_counts(cell, false);
+            path.pop();
+            if stopped {
+                return true;
+            }
+        }
+        false
+    }
+
+    fn try_get_solution(&self, path: &[usize], solver_id: SolverIdentifier) -> Option<Solution> {
+        let loop_cells: Vec<_> = path.iter().map(|&index| self.cells[index]).collect();
+        let extra_cells: Vec<_> = loop_cells
+            .iter()
+            .filter(|cell| cell.extra.count() > 0)
+            .collect();
+        let actions: Vec<_> = match extra_cells[..] {
+            [] => return None,
+            // type 1, the only cell with extra candidates can't be one of the loop candidates
+            [cell] => vec![Action::Elimination(EliminationDetails {
+                x: cell.x,
+                y: cell.y,
+                target: self.bi_value,
+            })],
+            // type 2, one of the cells with the extra candidate must be true
+            [first, ..] => {
+                let target = first.extra.trailing_zeros();
+                Coord::all_cells()
+                    .filter(|&(x, y)| {
+                        extra_cells
+                            .iter()
+                            .all(|cell| Coord::sees(x, y, cell.x, cell.y))
+                            && self.game_board.contains_candidate(x, y, target)
+                    })
+                    .map(|(x, y)| {
+                        Action::Elimination(EliminationDetails {
+                            x,
+                            y,
+                            target: first.extra,
+                        })
+                    })
+                    .collect()
+            }
+        };
+        (!actions.is_empty()).then(|| Solution {
+            actions,
+            house_clues: vec![],
+            candidate_clues: loop_cells
+                .iter()
+                .map(|cell| Candidate::new(cell.x, cell.y, self.bi_value))
+                .chain(
+                    loop_cells
+                        .iter()
+                        .filter(|cell| cell.extra.count() > 0)
+                        .map(|cell| Candidate::new(cell.x, cell.y, cell.extra)),
+                )
+                .collect(),
+            solver_id,
+        })
+    }
+
+    fn search_all_starts(&mut self, solver_id: SolverIdentifier, visit: Visitor) -> bool {
+        (0..self.cells.len()).any(|start| {
+            self.update_house_counts(self.cells[start], true);
+            let stopped = self.search(&mut vec![start], solver_id, visit);
+            self.update_house_counts(self.cells[start], false);
+            stopped
+        })
+    }
+}
+
+impl UniqueLoop {
+    fn search(&self, game_board: &GameBoard, visit: Visitor) {
+        BitMap::get_combinations(2).any(|bi_value| {
+            LoopSearch::new(game_board, bi_value).search_all_starts(self.solver_id(), visit)
+        });
+    }
+}
+
+impl Solver for UniqueLoop {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        first_visited(|visit| self.search(game_board, visit))
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_visited(|visit| self.search(game_board, visit))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::UniqueLoop
+    }
+}
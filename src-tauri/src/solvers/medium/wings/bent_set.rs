@@ -0,0 +1,193 @@
+use crate::{
+    game_board::GameBoard,
+    solvers::{
+        Solver, SolverIdentifier,
+        solution::{Action, Candidate, EliminationDetails, Solution, distinct_by_actions},
+    },
+    utils::{BitMap, Coord, House},
+};
+
+use super::{VWXYZWing, WXYZWing};
+
+/// N cells in the union of a box and a line, containing N candidates in total
+struct BentSet {
+    cells: Vec<(usize, usize)>,
+    candidates: BitMap,
+}
+
+impl BentSet {
+    fn try_new(game_board: &GameBoard, cells: Vec<(usize, usize)>) -> Option<Self> {
+        let mut candidates = BitMap::new();
+        for &(x, y) in &cells {
+            candidates.insert_set(game_board.get_candidates(x, y)?);
+        }
+        (candidates.count() == cells.len()).then_some(Self { cells, candidates })
+    }
+
+    fn cells_with<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+        target: usize,
+    ) -> impl Iterator<Item = (usize, usize)> + 'a {
+        self.cells
+            .iter()
+            .copied()
+            .filter(move |&(x, y)| game_board.contains_candidate(x, y, target))
+    }
+
+    /// A candidate is restricted if all its appearances in the set see each other
+    fn restricted(&self, game_board: &GameBoard, target: usize) -> bool {
+        self.cells_with(game_board, target).all(|(px, py)| {
+            self.cells_with(game_board, target)
+                .all(|(qx, qy)| Coord::same(px, py, qx, qy) || Coord::sees(px, py, qx, qy))
+        })
+    }
+
+    /// The only non-restricted candidate z
+    fn try_get_z(&self, game_board: &GameBoard) -> Option<usize> {
+        let mut non_restricted = self
+            .candidates
+            .iter_ones()
+            .filter(|&target| !self.restricted(game_board, target));
+        let z = non_restricted.next()?;
+        non_restricted.next().is_none().then_some(z)
+    }
+
+    fn get_actions(&self, game_board: &GameBoard, z: usize) -> Vec<Action> {
+        let z_cells: Vec<_> = self.cells_with(game_board, z).collect();
+        let ((px, py), (qx, qy)) = (z_cells[0], z_cells[1]);
+        Coord::pinched_by(px, py, qx, qy)
+            .filter(|&(x, y)| game_board.contains_candidate(x, y, z))
+            .filter(|&(x, y)| !self.cells.contains(&(x, y)))
+            .filter(|&(x, y)| {
+                z_cells[2..]
+                    .iter()
+                    .all(|&(zx, zy)| Coord::sees(x, y, zx, zy))
+            })
+            .map(|(x, y)| {
+                Action::Elimination(EliminationDetails {
+                    x,
+                    y,
+                    target: BitMap::from(z),
+                })
+            })
+            .collect()
+    }
+
+    fn get_candidate_clues(&self, game_board: &GameBoard, z: usize) -> Vec<Candidate> {
+        self.candidates
+            .iter_ones()
+            .filter(|&target| target != z)
+            .chain(std::iter::once(z))
+            .map(|target| {
+                self.cells_with(game_board, target)
+                    .map(|(x, y)| Candidate::new_single(x, y, target))
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>()
+            .join(&Candidate::SEPARATOR)
+    }
+
+    fn try_get_solution(
+        &self,
+        game_board: &GameBoard,
+        solver_id: SolverIdentifier,
+    ) -> Option<Solution> {
+        let z = self.try_get_z(game_board)?;
+        let actions = self.get_actions(game_board, z);
+        (!actions.is_empty()).then(|| Solution {
+            actions,
+            house_clues: vec![],
+            candidate_clues: self.get_candidate_clues(game_board, z),
+            solver_id,
+        })
+    }
+}
+
+/// Indices of the unsolved cells in the house with at most n candidates
+fn available_indices(game_board: &GameBoard, house: &House, n: usize) -> BitMap {
+    (0..9)
+        .filter(|&index| {
+            let (x, y) = house.ith_cell(index);
+            game_board
+                .get_candidates(x, y)
+                .is_some_and(|candidates| candidates.count() <= n)
+        })
+        .collect()
+}
+
+/// Search bent sets of size n, with some cells in the box and the others in the line outside the box
+fn iter_box_line(
+    game_board: &GameBoard,
+    n: usize,
+    box_house: House,
+    line: House,
+    solver_id: SolverIdentifier,
+) -> impl Iterator<Item = Solution> + '_ {
+    let box_indices = available_indices(game_board, &box_house, n);
+    let line_indices: BitMap = available_indices(game_board, &line, n)
+        .iter_ones()
+        .filter(|&index| {
+            let (x, y) = line.ith_cell(index);
+            !Coord::is_in_house(x, y, &box_house)
+        })
+        .collect();
+    (1..n).flat_map(move |line_cnt| {
+        BitMap::get_combos_in_subset(line_cnt, line_indices).flat_map(move |line_combo| {
+            BitMap::get_combos_in_subset(n - line_cnt, box_indices)
+                .map(move |box_combo| {
+                    box_combo
+                        .iter_ones()
+                        .map(|index| box_house.ith_cell(index))
+                        .chain(line_combo.iter_ones().map(|index| line.ith_cell(index)))
+                        .collect::<Vec<_>>()
+                })
+                // all the cells in the line form a naked subset instead
+                .filter(move |cells| cells.iter().any(|&(x, y)| !Coord::is_in_house(x, y, &line)))
+                .filter_map(move |cells| BentSet::try_new(game_board, cells))
+                .filter_map(move |bent_set| bent_set.try_get_solution(game_board, solver_id))
+        })
+    })
+}
+
+fn iter_bent_set(
+    game_board: &GameBoard,
+    n: usize,
+    solver_id: SolverIdentifier,
+) -> impl Iterator<Item = Solution> + '_ {
+    (0..9).flat_map(move |box_id| {
+        let (x_offset, y_offset) = (box_id / 3 * 3, box_id % 3 * 3);
+        (0..3)
+            .map(move |i| House::Row(x_offset + i))
+            .chain((0..3).map(move |i| House::Col(y_offset + i)))
+            .flat_map(move |line| iter_box_line(game_board, n, House::Box(box_id), line, solver_id))
+    })
+}
+
+impl Solver for WXYZWing {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        iter_bent_set(game_board, 4, self.solver_id()).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(iter_bent_set(game_board, 4, self.solver_id()))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::WXYZWing
+    }
+}
+
+impl Solver for VWXYZWing {
+    fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
+        iter_bent_set(game_board, 5, self.solver_id()).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(iter_bent_set(game_board, 5, self.solver_id()))
+    }
+
+    fn solver_id(&self) -> SolverIdentifier {
+        SolverIdentifier::VWXYZWing
+    }
+}
@@ -37,6 +37,27 @@ mod xyz_wing;
 pub struct WWing;
 mod w_wing;
 
+/// WXYZ-Wing Solver
+/// ## Terminology
+/// - The bent set: n cells in the union of a box and a line, with some cells outside the line and some cells outside the box,
+///   containing exactly n candidates in total. For WXYZ-Wing, n = 4.
+/// - A candidate is restricted if all its appearances in the bent set see each other, so at most one of them is true.
+/// - The only non-restricted candidate is denoted by z. One of the z in the bent set must be true.
+/// ## Return Format
+/// - **Actions**: Contains variable number of elements, representing z in the cells seeing all the z in the bent set.
+/// - **House Clues**: An empty vector.
+/// - **Candidate Clues**: Contains n groups of elements, seperated by the Candidate::SEPARATOR:
+///     - The first n - 1 groups: Each restricted candidate in ascending order, in the bent set cells containing it.
+///     - The last group: z in the bent set cells containing it.
+///     - Inside each group, the cells in the box come first, followed by the cells in the line outside the box.
+pub struct WXYZWing;
+
+/// VWXYZ-Wing Solver
+///
+/// The bent set of size 5, following the same terminology and return format as [`WXYZWing`].
+pub struct VWXYZWing;
+mod bent_set;
+
 #[cfg(test)]
 mod wings_test {
     use super::*;
@@ -98,4 +119,76 @@ mod wings_test {
             vec![32, 32, 32, 1, 32, 1],                           //exp_candi_masks
         );
     }
+
+    #[test]
+    fn test_wxyz_wing() {
+        test_function_e(
+            WXYZWing,
+            [
+                516, 8, 64, 528, 640, 32, 768, 1, 514, 528, 2, 640, 576, 1, 768, 4, 520, 544, 1,
+                256, 544, 522, 516, 522, 640, 16, 576, 864, 628, 788, 801, 2, 529, 577, 640, 8, 8,
+                1, 2, 516, 64, 640, 544, 768, 528, 128, 608, 784, 552, 784, 521, 579, 582, 517,
+                866, 612, 773, 128, 784, 531, 8, 614, 517, 834, 592, 520, 771, 32, 4, 595, 578,
+                128, 546, 640, 533, 515, 8, 64, 531, 550, 256,
+            ],
+            vec![(6, 5), (8, 6)], //exp_actions
+            vec![1, 1],           //exp_action_targets
+            vec![],               //exp_house_clues
+            vec![
+                (8, 7),
+                (8, 0),
+                (8, 3),
+                (0, 0),
+                (6, 8),
+                (8, 7),
+                (0, 0),
+                (8, 7),
+                (8, 0),
+                (0, 0),
+                (6, 8),
+                (8, 3),
+            ], //exp_candidate_clues
+            vec![2, 2, 2, 65535, 4, 4, 65535, 32, 32, 65535, 1, 1], //exp_candidate_masks
+        );
+    }
+
+    #[test]
+    fn test_vwxyz_wing() {
+        test_function_e(
+            VWXYZWing,
+            [
+                528, 4, 128, 577, 584, 777, 2, 617, 809, 768, 579, 577, 16, 32, 527, 716, 717, 524,
+                8, 579, 544, 711, 710, 775, 836, 16, 773, 2, 897, 769, 8, 16, 549, 676, 677, 64,
+                576, 32, 520, 647, 646, 519, 660, 256, 533, 641, 528, 4, 673, 256, 64, 680, 514,
+                553, 4, 8, 592, 256, 1, 640, 624, 608, 2, 672, 960, 848, 614, 590, 574, 1, 588,
+                796, 545, 833, 2, 612, 588, 572, 860, 588, 128,
+            ],
+            vec![(7, 1)], //exp_actions
+            vec![64],     //exp_action_targets
+            vec![],       //exp_house_clues
+            vec![
+                (8, 1),
+                (1, 1),
+                (2, 1),
+                (0, 0),
+                (1, 1),
+                (2, 1),
+                (0, 0),
+                (6, 2),
+                (7, 2),
+                (0, 0),
+                (7, 2),
+                (8, 1),
+                (0, 0),
+                (6, 2),
+                (7, 2),
+                (8, 1),
+                (1, 1),
+                (2, 1),
+            ], //exp_candidate_clues
+            vec![
+                1, 1, 1, 65535, 2, 2, 65535, 16, 16, 65535, 256, 256, 65535, 64, 64, 64, 64, 64,
+            ], //exp_candidate_masks
+        );
+    }
 }
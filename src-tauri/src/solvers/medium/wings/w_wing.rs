@@ -1,8 +1,8 @@
 use crate::{
     game_board::GameBoard,
     solvers::{
-        solution::{Action, Candidate, EliminationDetails, Solution},
         Solver, SolverIdentifier,
+        solution::{Action, Candidate, EliminationDetails, Solution, distinct_by_actions},
     },
     utils::{BitMap, Coord, HouseType},
 };
@@ -68,6 +68,16 @@ impl WWing {
                 WWingPattern::try_from_p_q(pincer_p, qx, qy, candidates)
             })
     }
+
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        Self::iter_bridge(game_board)
+            .flat_map(move |bridge| Self::iter_p(game_board, bridge))
+            .flat_map(move |pincer_p| Self::iter_q(game_board, pincer_p))
+            .filter_map(move |w_wing| w_wing.try_get_solution(game_board, self.solver_id()))
+    }
 }
 #[derive(Clone, Copy)]
 struct PincerP {
@@ -155,10 +165,11 @@ impl WWingPattern {
 
 impl Solver for WWing {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        Self::iter_bridge(game_board)
-            .flat_map(|bridge| Self::iter_p(game_board, bridge))
-            .flat_map(|pincer_p| Self::iter_q(game_board, pincer_p))
-            .find_map(|w_wing| w_wing.try_get_solution(game_board, self.solver_id()))
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
@@ -1,9 +1,9 @@
 use crate::{
     game_board::GameBoard,
     solvers::{
-        medium::{iter_valid_bi_value, BiValueCell},
-        solution::{Action, Candidate, EliminationDetails, Solution},
         Solver, SolverIdentifier,
+        medium::{BiValueCell, iter_valid_bi_value},
+        solution::{Action, Candidate, EliminationDetails, Solution, distinct_by_actions},
     },
     utils::{BitMap, Coord},
 };
@@ -124,13 +124,24 @@ impl XYWing {
             .filter_map(|(x, y, bi_value)| BiValueCell::try_new(x, y, bi_value))
             .filter_map(move |r| XYPincers::try_from_p_q_r(pq, r))
     }
+
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        iter_valid_bi_value(game_board)
+            .flat_map(move |p| Self::iter_x_pincer(game_board, p))
+            .flat_map(move |pq| Self::iter_xy_pincer(game_board, pq))
+            .filter_map(move |xy_wing| xy_wing.try_get_solution(game_board, self.solver_id()))
+    }
 }
 impl Solver for XYWing {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        iter_valid_bi_value(game_board)
-            .flat_map(|p| Self::iter_x_pincer(game_board, p))
-            .flat_map(|pq| Self::iter_xy_pincer(game_board, pq))
-            .find_map(|xy_wing| xy_wing.try_get_solution(game_board, self.solver_id()))
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
@@ -1,9 +1,9 @@
 use crate::{
     game_board::GameBoard,
     solvers::{
-        medium::{iter_valid_bi_value, BiValueCell},
-        solution::{Action, Candidate, EliminationDetails, Solution},
         Solver, SolverIdentifier,
+        medium::{BiValueCell, iter_valid_bi_value},
+        solution::{Action, Candidate, EliminationDetails, Solution, distinct_by_actions},
     },
     utils::{BitMap, Coord},
 };
@@ -139,13 +139,24 @@ impl XYZWing {
         Coord::pinched_by(pincers.qx, pincers.qy, pincers.rx, pincers.ry)
             .find_map(|(px, py)| Self::check_pattern(game_board, pincers, px, py))
     }
+
+    fn iter_solutions<'a>(
+        &'a self,
+        game_board: &'a GameBoard,
+    ) -> impl Iterator<Item = Solution> + 'a {
+        iter_valid_bi_value(game_board)
+            .flat_map(move |q| Self::iter_pincers_from_q(game_board, q))
+            .filter_map(move |pincers| Self::find_xyz_wing(game_board, pincers))
+            .filter_map(move |xyz_wing| xyz_wing.try_get_solution(game_board, self.solver_id()))
+    }
 }
 impl Solver for XYZWing {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution> {
-        iter_valid_bi_value(game_board)
-            .flat_map(|q| Self::iter_pincers_from_q(game_board, q))
-            .filter_map(|pincers| Self::find_xyz_wing(game_board, pincers))
-            .find_map(|xyz_wing| xyz_wing.try_get_solution(game_board, self.solver_id()))
+        self.iter_solutions(game_board).next()
+    }
+
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        distinct_by_actions(self.iter_solutions(game_board))
     }
 
     fn solver_id(&self) -> SolverIdentifier {
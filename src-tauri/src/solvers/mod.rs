@@ -1,9 +1,14 @@
 pub mod easy;
+pub mod extreme;
+pub mod guess;
 pub mod hard;
 pub mod medium;
 pub mod solution;
+pub mod solver_config;
 pub mod solver_enum;
 pub mod traits;
 pub use traits::Solver; // 重新导出 Solver trait
+mod implication;
 mod solver_identifier;
+pub use solver_config::{SolverConfig, Tier};
 pub use solver_identifier::SolverIdentifier;
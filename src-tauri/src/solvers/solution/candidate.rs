@@ -1,7 +1,9 @@
 use std::fmt;
 
+use serde::Serialize;
+
 use crate::utils::BitMap;
-#[derive(Clone)]
+#[derive(Clone, Serialize)]
 pub struct Candidate {
     pub x: usize,
     pub y: usize,
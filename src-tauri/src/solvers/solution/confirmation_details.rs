@@ -1,5 +1,8 @@
 use std::fmt;
 
+use serde::Serialize;
+
+#[derive(Clone, Serialize)]
 pub struct ConfirmationDetails {
     pub x: usize,
     pub y: usize,
@@ -1,7 +1,10 @@
 use std::fmt;
 
+use serde::Serialize;
+
 use crate::utils::BitMap;
 
+#[derive(Clone, Serialize)]
 pub struct EliminationDetails {
     pub x: usize,
     pub y: usize,
@@ -1,7 +1,9 @@
 pub mod candidate;
 pub mod confirmation_details;
 pub mod elimination_details;
-use std::fmt;
+use std::{collections::HashSet, fmt};
+
+use serde::Serialize;
 
 use crate::utils::House;
 pub use candidate::Candidate;
@@ -9,6 +11,7 @@ pub use confirmation_details::ConfirmationDetails;
 pub use elimination_details::EliminationDetails;
 
 use super::SolverIdentifier;
+#[derive(Clone, Serialize)]
 pub enum Action {
     Confirmation(ConfirmationDetails),
     Elimination(EliminationDetails),
@@ -21,9 +24,63 @@ impl fmt::Debug for Action {
         }
     }
 }
+#[derive(Clone, Serialize)]
 pub struct Solution {
     pub actions: Vec<Action>,
     pub house_clues: Vec<House>,
     pub candidate_clues: Vec<Candidate>,
     pub solver_id: SolverIdentifier,
 }
+
+impl Solution {
+    /// The actions in a canonical order, two solutions with the same key change the board in the same way
+    fn action_key(&self) -> Vec<(bool, usize, usize, u16)> {
+        let mut key: Vec<_> = self
+            .actions
+            .iter()
+            .map(|action| match action {
+                Action::Confirmation(ConfirmationDetails { x, y, target }) => {
+                    (true, *x, *y, 1 << target)
+                }
+                Action::Elimination(EliminationDetails { x, y, target }) => {
+                    (false, *x, *y, target.get_raw())
+                }
+            })
+            .collect();
+        key.sort_unstable();
+        key
+    }
+}
+
+/// Keeps the first of the solutions with the same actions, in the original order
+pub fn distinct_by_actions(solutions: impl IntoIterator<Item = Solution>) -> Vec<Solution> {
+    let mut seen = HashSet::new();
+    solutions
+        .into_iter()
+        .filter(|solution| seen.insert(solution.action_key()))
+        .collect()
+}
+
+/// A search passing each solution found to the visitor, and stopping as soon as the visitor returns true.
+/// Used by the depth first searches which can't be turned into iterators.
+pub type Visitor<'a> = &'a mut dyn FnMut(Solution) -> bool;
+
+/// The first solution found by the search
+pub fn first_visited(search: impl FnOnce(Visitor)) -> Option<Solution> {
+    let mut first = None;
+    search(&mut |solution| {
+        first = Some(solution);
+        true
+    });
+    first
+}
+
+/// All the solutions found by the search, keeping the first of those with the same actions
+pub fn distinct_visited(search: impl FnOnce(Visitor)) -> Vec<Solution> {
+    let mut solutions = vec![];
+    search(&mut |solution| {
+        solutions.push(solution);
+        false
+    });
+    distinct_by_actions(solutions)
+}
@@ -0,0 +1,277 @@
+use std::{fs, path::Path};
+
+use serde::{Deserialize, Serialize};
+
+use super::{
+    Solver, SolverIdentifier, easy::get_easy_solvers, extreme::get_extreme_solvers,
+    guess::get_guess_solvers, hard::KrakenFish, hard::get_hard_solvers, medium::get_medium_solvers,
+    solver_enum::SolverEnum,
+};
+
+/// The difficulty class of a technique, the hardest one used rates the puzzle
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Tier {
+    Easy,
+    Medium,
+    Hard,
+    Extreme,
+    Guess,
+}
+
+impl Tier {
+    /// The score weight given to the solvers of the tier unless configured otherwise
+    pub fn default_score(&self) -> u32 {
+        match self {
+            Tier::Easy => 5,
+            Tier::Medium => 50,
+            Tier::Hard => 200,
+            Tier::Extreme => 500,
+            Tier::Guess => 1000,
+        }
+    }
+}
+
+/// How a single solver takes part in finding the next step and in rating the difficulty
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SolverSetting {
+    pub solver_id: SolverIdentifier,
+    /// Disabled solvers are never tried
+    pub enabled: bool,
+    /// The solvers are tried in increasing order until one of them finds a step
+    pub order: usize,
+    pub tier: Tier,
+    /// Added to the difficulty score every time the solver is used
+    pub score: u32,
+    /// The maximum number of links in each chain, for the solvers bounding their chain search
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_chain_length: Option<usize>,
+}
+
+impl SolverSetting {
+    /// The solver, with the limits of the setting
+    pub(crate) fn solver(&self) -> SolverEnum {
+        match (self.solver_id, self.max_chain_length) {
+            (SolverIdentifier::KrakenFish, Some(max_chain_length)) => {
+                SolverEnum::from(KrakenFish::new(max_chain_length))
+            }
+            _ => SolverEnum::from(self.solver_id),
+        }
+    }
+}
+
+/// The configuration of every solver, which players and graders can edit and save in the app data
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct SolverConfig {
+    pub solvers: Vec<SolverSetting>,
+}
+
+impl Default for SolverConfig {
+    /// Every solver but the guesses enabled, in the order and tier of the solver lists.
+    /// Trial and error is left to the players and graders who ask for it.
+    fn default() -> Self {
+        let tiers = [
+            (Tier::Easy, get_easy_solvers()),
+            (Tier::Medium, get_medium_solvers()),
+            (Tier::Hard, get_hard_solvers()),
+            (Tier::Extreme, get_extreme_solvers()),
+            (Tier::Guess, get_guess_solvers()),
+        ];
+        let solvers = tiers
+            .into_iter()
+            .flat_map(|(tier, solvers)| solvers.into_iter().map(move |solver| (tier, solver)))
+            .enumerate()
+            .map(|(order, (tier, solver))| SolverSetting {
+                solver_id: solver.solver_id(),
+                enabled: tier != Tier::Guess,
+                order,
+                tier,
+                score: tier.default_score(),
+                max_chain_length: (solver.solver_id() == SolverIdentifier::KrakenFish)
+                    .then_some(KrakenFish::DEFAULT_MAX_CHAIN_LENGTH),
+            })
+            .collect();
+        Self { solvers }
+    }
+}
+
+impl SolverConfig {
+    /// Keeps the first setting of each solver, and appends the default settings of the solvers
+    /// missing from the configuration, e.g. the ones added since it was saved
+    pub fn normalized(self) -> Self {
+        let mut solvers: Vec<SolverSetting> = Vec::with_capacity(SolverIdentifier::ALL.len());
+        for setting in self.solvers {
+            if solvers
+                .iter()
+                .all(|kept| kept.solver_id != setting.solver_id)
+            {
+                solvers.push(setting);
+            }
+        }
+        let next_order = solvers
+            .iter()
+            .map(|setting| setting.order + 1)
+            .max()
+            .unwrap_or(0);
+        let missing: Vec<_> = Self::default()
+            .solvers
+            .into_iter()
+            .filter(|default| {
+                solvers
+                    .iter()
+                    .all(|kept| kept.solver_id != default.solver_id)
+            })
+            .collect();
+        solvers.extend(
+            missing
+                .into_iter()
+                .enumerate()
+                .map(|(i, setting)| SolverSetting {
+                    order: next_order + i,
+                    ..setting
+                }),
+        );
+        Self { solvers }
+    }
+
+    /// The setting of a solver
+    pub fn setting(&self, solver_id: SolverIdentifier) -> Option<&SolverSetting> {
+        self.solvers
+            .iter()
+            .find(|setting| setting.solver_id == solver_id)
+    }
+
+    /// The enabled solvers in the order they should be tried
+    pub(crate) fn enabled_solvers(&self) -> Vec<SolverEnum> {
+        let mut enabled: Vec<_> = self
+            .solvers
+            .iter()
+            .filter(|setting| setting.enabled)
+            .collect();
+        enabled.sort_by_key(|setting| setting.order);
+        enabled.into_iter().map(SolverSetting::solver).collect()
+    }
+
+    /// Reads the configuration saved at path, or the default one if nothing has been saved yet
+    pub fn load(path: &Path) -> Result<Self, String> {
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+        let json = fs::read_to_string(path).map_err(|err| err.to_string())?;
+        let config: Self = serde_json::from_str(&json).map_err(|err| err.to_string())?;
+        Ok(config.normalized())
+    }
+
+    /// Writes the configuration at path, creating the missing directories
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|err| err.to_string())?;
+        }
+        let json = serde_json::to_string_pretty(self).map_err(|err| err.to_string())?;
+        fs::write(path, json).map_err(|err| err.to_string())
+    }
+}
+
+#[cfg(test)]
+mod solver_config_test {
+    use super::*;
+
+    #[test]
+    fn default_lists_every_solver_once() {
+        let config = SolverConfig::default();
+        assert_eq!(config.solvers.len(), SolverIdentifier::ALL.len());
+        for &solver_id in SolverIdentifier::ALL {
+            assert!(
+                config
+                    .setting(solver_id)
+                    .is_some_and(|setting| setting.enabled != solver_id.is_guess())
+            );
+        }
+        assert!(
+            config
+                .solvers
+                .windows(2)
+                .all(|pair| pair[0].order < pair[1].order && pair[0].tier <= pair[1].tier)
+        );
+        assert_eq!(
+            config.setting(SolverIdentifier::BowmansBingo).unwrap().tier,
+            Tier::Guess
+        );
+    }
+
+    #[test]
+    fn default_enables_no_guess() {
+        assert!(
+            SolverConfig::default()
+                .enabled_solvers()
+                .iter()
+                .all(|solver| !solver.solver_id().is_guess())
+        );
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let mut config = SolverConfig::default();
+        config.solvers[1].enabled = false;
+        config.solvers[2].score = 42;
+        let json = serde_json::to_string(&config).unwrap();
+        assert_eq!(serde_json::from_str::<SolverConfig>(&json).unwrap(), config);
+    }
+
+    #[test]
+    fn normalized_fills_missing_and_drops_duplicates() {
+        let mut config = SolverConfig::default();
+        config.solvers.retain(|setting| setting.tier == Tier::Easy);
+        config.solvers[0].enabled = false;
+        config.solvers.push(SolverSetting {
+            enabled: true,
+            ..config.solvers[0].clone()
+        });
+        let config = config.normalized();
+        assert_eq!(config.solvers.len(), SolverIdentifier::ALL.len());
+        assert!(
+            !config
+                .setting(SolverIdentifier::NakedSingle)
+                .unwrap()
+                .enabled
+        );
+        let mut orders: Vec<_> = config.solvers.iter().map(|setting| setting.order).collect();
+        orders.sort_unstable();
+        orders.dedup();
+        assert_eq!(orders.len(), SolverIdentifier::ALL.len());
+    }
+
+    #[test]
+    fn enabled_solvers_follow_the_order() {
+        let mut config = SolverConfig::default();
+        config.solvers[0].enabled = false;
+        config.solvers[2].order = 0;
+        let solvers: Vec<_> = config
+            .enabled_solvers()
+            .iter()
+            .map(|solver| solver.solver_id())
+            .collect();
+        let guesses = SolverIdentifier::ALL
+            .iter()
+            .filter(|solver_id| solver_id.is_guess())
+            .count();
+        assert_eq!(solvers.len(), SolverIdentifier::ALL.len() - guesses - 1);
+        assert_eq!(
+            solvers[..2],
+            [SolverIdentifier::Pointing, SolverIdentifier::HiddenSingle]
+        );
+    }
+
+    #[test]
+    fn load_and_save() {
+        let dir = std::env::temp_dir().join(format!("solver_config_test_{}", std::process::id()));
+        let path = dir.join("solver_config.json");
+        assert_eq!(SolverConfig::load(&path).unwrap(), SolverConfig::default());
+        let mut config = SolverConfig::default();
+        config.solvers[3].enabled = false;
+        config.save(&path).unwrap();
+        assert_eq!(SolverConfig::load(&path).unwrap(), config);
+        fs::write(&path, "not json").unwrap();
+        assert!(SolverConfig::load(&path).is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
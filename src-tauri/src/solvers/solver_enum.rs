@@ -1,4 +1,7 @@
+use super::SolverIdentifier;
 use super::easy::*;
+use super::extreme::*;
+use super::guess::*;
 use super::hard::*;
 use super::medium::*;
 use enum_dispatch::enum_dispatch;
@@ -26,16 +29,153 @@ pub(crate) enum SolverEnum {
     UniquenessTest5,
     UniquenessTest6,
     FinnedXWing,
+    SashimiXWing,
     AvoidableRectangle1,
     AvoidableRectangle2,
+    UniquenessTest7,
+    UniquenessTest8,
+    AvoidableRectangle3,
+    UniqueLoop,
+    BiValueUniversalGravePlusN,
     NakedQuadruple,
     HiddenQuadruple,
     Jellyfish,
     XYWing,
     XYZWing,
     WWing,
+    WXYZWing,
+    VWXYZWing,
+    RemotePairs,
     FinnedSwordfish,
+    SashimiSwordfish,
     FinnedJellyfish,
-    Coloring,
+    SashimiJellyfish,
+    FrankenFish,
+    MutantFish,
+    HiddenRectangle,
+    SimpleColorsTrap,
+    SimpleColorsWrap,
+    MultiColors,
+    ThreeDMedusa,
     SueDeCoq,
+    AlsChain,
+    DeathBlossom,
+    AlignedPairExclusion,
+    AlignedTripleExclusion,
+    KrakenFish,
+    JuniorExocet,
+    PatternOverlay,
+    CellForcingChain,
+    UnitForcingChain,
+    DigitForcingChain,
+    ForcingNet,
+    BowmansBingo,
+}
+
+/// Every identifier has a solver, adding an identifier without its solver fails to build
+impl From<SolverIdentifier> for SolverEnum {
+    fn from(solver_id: SolverIdentifier) -> Self {
+        match solver_id {
+            SolverIdentifier::NakedSingle => SolverEnum::from(NakedSingle),
+            SolverIdentifier::HiddenSingle => SolverEnum::from(HiddenSingle),
+            SolverIdentifier::Pointing => SolverEnum::from(Pointing),
+            SolverIdentifier::Claiming => SolverEnum::from(Claiming),
+            SolverIdentifier::NakedPair => SolverEnum::from(NakedPair),
+            SolverIdentifier::HiddenPair => SolverEnum::from(HiddenPair),
+            SolverIdentifier::NakedTriple => SolverEnum::from(NakedTriple),
+            SolverIdentifier::HiddenTriple => SolverEnum::from(HiddenTriple),
+            SolverIdentifier::XWing => SolverEnum::from(XWing),
+            SolverIdentifier::Swordfish => SolverEnum::from(Swordfish),
+            SolverIdentifier::Skyscraper => SolverEnum::from(Skyscraper),
+            SolverIdentifier::TwoStringKite => SolverEnum::from(TwoStringKite),
+            SolverIdentifier::TurbotFish => SolverEnum::from(TurbotFish),
+            SolverIdentifier::EmptyRectangle => SolverEnum::from(EmptyRectangle),
+            SolverIdentifier::BiValueUniversalGravePlusOne => {
+                SolverEnum::from(BiValueUniversalGravePlusOne)
+            }
+            SolverIdentifier::UniquenessTest1 => SolverEnum::from(UniquenessTest1),
+            SolverIdentifier::UniquenessTest2 => SolverEnum::from(UniquenessTest2),
+            SolverIdentifier::UniquenessTest3 => SolverEnum::from(UniquenessTest3),
+            SolverIdentifier::UniquenessTest4 => SolverEnum::from(UniquenessTest4),
+            SolverIdentifier::UniquenessTest5 => SolverEnum::from(UniquenessTest5),
+            SolverIdentifier::UniquenessTest6 => SolverEnum::from(UniquenessTest6),
+            SolverIdentifier::FinnedXWing => SolverEnum::from(FinnedXWing),
+            SolverIdentifier::SashimiXWing => SolverEnum::from(SashimiXWing),
+            SolverIdentifier::AvoidableRectangle1 => SolverEnum::from(AvoidableRectangle1),
+            SolverIdentifier::AvoidableRectangle2 => SolverEnum::from(AvoidableRectangle2),
+            SolverIdentifier::UniquenessTest7 => SolverEnum::from(UniquenessTest7),
+            SolverIdentifier::UniquenessTest8 => SolverEnum::from(UniquenessTest8),
+            SolverIdentifier::AvoidableRectangle3 => SolverEnum::from(AvoidableRectangle3),
+            SolverIdentifier::UniqueLoop => SolverEnum::from(UniqueLoop),
+            SolverIdentifier::BiValueUniversalGravePlusN => {
+                SolverEnum::from(BiValueUniversalGravePlusN)
+            }
+            SolverIdentifier::NakedQuadruple => SolverEnum::from(NakedQuadruple),
+            SolverIdentifier::HiddenQuadruple => SolverEnum::from(HiddenQuadruple),
+            SolverIdentifier::Jellyfish => SolverEnum::from(Jellyfish),
+            SolverIdentifier::XYWing => SolverEnum::from(XYWing),
+            SolverIdentifier::XYZWing => SolverEnum::from(XYZWing),
+            SolverIdentifier::WWing => SolverEnum::from(WWing),
+            SolverIdentifier::WXYZWing => SolverEnum::from(WXYZWing),
+            SolverIdentifier::VWXYZWing => SolverEnum::from(VWXYZWing),
+            SolverIdentifier::RemotePairs => SolverEnum::from(RemotePairs),
+            SolverIdentifier::FinnedSwordfish => SolverEnum::from(FinnedSwordfish),
+            SolverIdentifier::SashimiSwordfish => SolverEnum::from(SashimiSwordfish),
+            SolverIdentifier::FinnedJellyfish => SolverEnum::from(FinnedJellyfish),
+            SolverIdentifier::SashimiJellyfish => SolverEnum::from(SashimiJellyfish),
+            SolverIdentifier::FrankenFish => SolverEnum::from(FrankenFish),
+            SolverIdentifier::MutantFish => SolverEnum::from(MutantFish),
+            SolverIdentifier::HiddenRectangle => SolverEnum::from(HiddenRectangle),
+            SolverIdentifier::SimpleColorsTrap => SolverEnum::from(SimpleColorsTrap),
+            SolverIdentifier::SimpleColorsWrap => SolverEnum::from(SimpleColorsWrap),
+            SolverIdentifier::MultiColors => SolverEnum::from(MultiColors),
+            SolverIdentifier::ThreeDMedusa => SolverEnum::from(ThreeDMedusa),
+            SolverIdentifier::SueDeCoq => SolverEnum::from(SueDeCoq),
+            SolverIdentifier::AlsChain => SolverEnum::from(AlsChain),
+            SolverIdentifier::DeathBlossom => SolverEnum::from(DeathBlossom),
+            SolverIdentifier::AlignedPairExclusion => SolverEnum::from(AlignedPairExclusion),
+            SolverIdentifier::AlignedTripleExclusion => SolverEnum::from(AlignedTripleExclusion),
+            SolverIdentifier::KrakenFish => SolverEnum::from(KrakenFish::default()),
+            SolverIdentifier::JuniorExocet => SolverEnum::from(JuniorExocet),
+            SolverIdentifier::PatternOverlay => SolverEnum::from(PatternOverlay),
+            SolverIdentifier::CellForcingChain => SolverEnum::from(CellForcingChain),
+            SolverIdentifier::UnitForcingChain => SolverEnum::from(UnitForcingChain),
+            SolverIdentifier::DigitForcingChain => SolverEnum::from(DigitForcingChain),
+            SolverIdentifier::ForcingNet => SolverEnum::from(ForcingNet),
+            SolverIdentifier::BowmansBingo => SolverEnum::from(BowmansBingo),
+        }
+    }
+}
+
+#[cfg(test)]
+mod solver_enum_test {
+    use super::*;
+    use crate::solvers::Solver;
+
+    #[test]
+    fn every_identifier_has_its_solver() {
+        for &solver_id in SolverIdentifier::ALL {
+            assert_eq!(SolverEnum::from(solver_id).solver_id(), solver_id);
+        }
+    }
+
+    #[test]
+    fn every_identifier_is_registered_once() {
+        let registered: Vec<_> = [
+            get_easy_solvers(),
+            get_medium_solvers(),
+            get_hard_solvers(),
+            get_extreme_solvers(),
+            get_guess_solvers(),
+        ]
+        .into_iter()
+        .flatten()
+        .map(|solver| solver.solver_id())
+        .collect();
+        for &solver_id in SolverIdentifier::ALL {
+            let count = registered.iter().filter(|&&id| id == solver_id).count();
+            assert_eq!(count, 1, "{solver_id:?} is registered {count} times");
+        }
+        assert_eq!(registered.len(), SolverIdentifier::ALL.len());
+    }
 }
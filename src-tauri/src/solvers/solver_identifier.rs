@@ -1,5 +1,21 @@
-#[derive(Clone, Copy)]
-pub enum SolverIdentifier {
+use serde::{Deserialize, Serialize};
+
+/// Declares the identifiers together with the list of all of them, so that they can't drift apart
+macro_rules! solver_identifiers {
+    ($($name:ident,)*) => {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+        pub enum SolverIdentifier {
+            $($name,)*
+        }
+
+        impl SolverIdentifier {
+            /// All the identifiers, in the order of declaration
+            pub const ALL: &[SolverIdentifier] = &[$(SolverIdentifier::$name,)*];
+        }
+    };
+}
+
+solver_identifiers! {
     NakedSingle,
     HiddenSingle,
     Pointing,
@@ -22,17 +38,52 @@ pub enum SolverIdentifier {
     UniquenessTest5,
     UniquenessTest6,
     FinnedXWing,
+    SashimiXWing,
     AvoidableRectangle1,
     AvoidableRectangle2,
+    UniquenessTest7,
+    UniquenessTest8,
+    AvoidableRectangle3,
+    UniqueLoop,
+    BiValueUniversalGravePlusN,
     NakedQuadruple,
     HiddenQuadruple,
     Jellyfish,
     XYWing,
     XYZWing,
     WWing,
+    WXYZWing,
+    VWXYZWing,
+    RemotePairs,
     FinnedSwordfish,
+    SashimiSwordfish,
     FinnedJellyfish,
+    SashimiJellyfish,
+    FrankenFish,
+    MutantFish,
     HiddenRectangle,
-    Coloring,
+    SimpleColorsTrap,
+    SimpleColorsWrap,
+    MultiColors,
+    ThreeDMedusa,
     SueDeCoq,
+    AlsChain,
+    DeathBlossom,
+    AlignedPairExclusion,
+    AlignedTripleExclusion,
+    KrakenFish,
+    JuniorExocet,
+    PatternOverlay,
+    CellForcingChain,
+    UnitForcingChain,
+    DigitForcingChain,
+    ForcingNet,
+    BowmansBingo,
+}
+
+impl SolverIdentifier {
+    /// Returns true if the solver guesses a candidate instead of deducing it, which should be graded as a guess
+    pub fn is_guess(&self) -> bool {
+        matches!(self, Self::BowmansBingo)
+    }
 }
@@ -1,5 +1,7 @@
 use super::SolverIdentifier;
 use super::easy::*;
+use super::extreme::*;
+use super::guess::*;
 use super::hard::*;
 use super::medium::*;
 use super::solver_enum::SolverEnum;
@@ -10,5 +12,11 @@ use enum_dispatch::enum_dispatch;
 #[enum_dispatch]
 pub trait Solver {
     fn solve(&self, game_board: &GameBoard) -> Option<Solution>;
+    /// Every distinct application of the solver on the board, deduplicated by their actions,
+    /// with the one returned by `solve` first.
+    /// The default only knows about the first one, solvers able to enumerate their patterns override it.
+    fn solve_all(&self, game_board: &GameBoard) -> Vec<Solution> {
+        self.solve(game_board).into_iter().collect()
+    }
     fn solver_id(&self) -> SolverIdentifier;
 }
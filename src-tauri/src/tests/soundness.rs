@@ -0,0 +1,361 @@
+//! Soundness harness: every solver is run on random positions reachable from a puzzle,
+//! and each step is checked against the answers and the structure expected from a `Solution`.
+//!
+//! A position is a random equivalent of one of the corpus puzzles with some cells filled with their answer
+//! and some wrong candidates eliminated. A failing position is shrunk to the fewest fills and eliminations
+//! still making the solver fail, then dumped in the `GameBoard::from_array` format to be added to the fixtures.
+//! Set `SOUNDNESS_CASES` to check more positions than the default.
+//! The dumped positions are also added to `soundness_regressions.txt`, which is checked on every run.
+use crate::game_board::GameBoard;
+use crate::game_board::dlx_solver::DLXSolver;
+use crate::solvers::solution::{
+    Action, Candidate, ConfirmationDetails, EliminationDetails, Solution,
+};
+use crate::solvers::solver_enum::SolverEnum;
+use crate::solvers::{Solver, SolverIdentifier};
+use crate::utils::{BitMap, Coord, House};
+
+const DEFAULT_CASES: usize = 12;
+
+/// xorshift64, the tests don't need anything better and stay reproducible
+struct Rng(u64);
+
+impl Rng {
+    fn next_u64(&mut self) -> u64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        self.0
+    }
+
+    fn below(&mut self, n: usize) -> usize {
+        (self.next_u64() % n as u64) as usize
+    }
+
+    fn shuffle<T>(&mut self, items: &mut [T]) {
+        for i in (1..items.len()).rev() {
+            items.swap(i, self.below(i + 1));
+        }
+    }
+}
+
+/// A change made to the puzzle to reach the position
+#[derive(Clone, Copy, Debug)]
+enum Move {
+    /// Fill the cell with its answer
+    Fill(usize),
+    /// Eliminate a wrong candidate of the cell
+    Eliminate(usize, usize),
+}
+
+/// The givens and the answers of a puzzle, one digit from 0 to 8 per cell in row-major order
+struct Puzzle {
+    givens: [Option<usize>; 81],
+    answers: [usize; 81],
+}
+
+impl Puzzle {
+    /// A random equivalent of the puzzle: digits relabelled, rows and columns permuted
+    /// inside their band and stack, bands and stacks permuted, and possibly transposed
+    fn random(puzzle: &str, rng: &mut Rng) -> Self {
+        let mut digits: Vec<usize> = (0..9).collect();
+        rng.shuffle(&mut digits);
+        let mut permutation = || {
+            let mut bands = [0, 1, 2];
+            rng.shuffle(&mut bands);
+            let mut lines = Vec::with_capacity(9);
+            for band in bands {
+                let mut inner = [0, 1, 2];
+                rng.shuffle(&mut inner);
+                lines.extend(inner.map(|line| band * 3 + line));
+            }
+            lines
+        };
+        let rows = permutation();
+        let cols = permutation();
+        let transposed = rng.below(2) == 1;
+        let source: Vec<Option<usize>> = puzzle
+            .chars()
+            .map(|c| c.to_digit(10).map(|digit| digit as usize - 1))
+            .collect();
+        let givens = std::array::from_fn(|index| {
+            let (x, y) = (index / 9, index % 9);
+            let (x, y) = if transposed { (y, x) } else { (x, y) };
+            source[rows[x] * 9 + cols[y]].map(|digit| digits[digit])
+        });
+        let mut game_board = GameBoard::from_array(Self::raws_of(&givens));
+        assert!(DLXSolver::solve_sudoku(&mut game_board).is_ok());
+        let answers = std::array::from_fn(|index| game_board.get_answer(index / 9, index % 9));
+        Self { givens, answers }
+    }
+
+    fn raws_of(givens: &[Option<usize>; 81]) -> [u16; 81] {
+        givens.map(|given| match given {
+            Some(digit) => 1 << digit,
+            None => BitMap::all().get_raw() | 512,
+        })
+    }
+
+    /// Random moves reaching a position: a random number of cells filled, then random wrong candidates eliminated
+    fn random_moves(&self, rng: &mut Rng) -> Vec<Move> {
+        let mut blanks: Vec<usize> = (0..81).filter(|&i| self.givens[i].is_none()).collect();
+        rng.shuffle(&mut blanks);
+        let fills = rng.below(blanks.len());
+        let mut moves: Vec<Move> = blanks[..fills].iter().map(|&i| Move::Fill(i)).collect();
+        let eliminations = rng.below(120);
+        for _ in 0..eliminations {
+            let index = blanks[fills..].get(rng.below(blanks.len() - fills + 1));
+            if let Some(&index) = index {
+                let digit = rng.below(9);
+                if digit != self.answers[index] {
+                    moves.push(Move::Eliminate(index, digit));
+                }
+            }
+        }
+        moves
+    }
+
+    /// The position reached by the moves. A cell left with a single candidate is filled as well,
+    /// since it couldn't be told apart from a pen mark, and every other cell keeps at least 2 candidates.
+    fn raws(&self, moves: &[Move]) -> [u16; 81] {
+        let mut filled = self.givens;
+        for &mv in moves {
+            if let Move::Fill(index) = mv {
+                filled[index] = Some(self.answers[index]);
+            }
+        }
+        let mut candidates = loop {
+            let candidates: [BitMap; 81] = std::array::from_fn(|index| {
+                let mut candidates = BitMap::all();
+                for (px, py) in Coord::seeable_cells(index / 9, index % 9) {
+                    if let Some(digit) = filled[px * 9 + py] {
+                        candidates.remove(digit);
+                    }
+                }
+                candidates
+            });
+            match (0..81).find(|&index| filled[index].is_none() && candidates[index].count() == 1) {
+                Some(index) => filled[index] = Some(self.answers[index]),
+                None => break candidates,
+            }
+        };
+        for &mv in moves {
+            match mv {
+                Move::Eliminate(index, digit)
+                    if filled[index].is_none() && candidates[index].count() > 2 =>
+                {
+                    candidates[index].remove(digit);
+                }
+                _ => {}
+            }
+        }
+        std::array::from_fn(|index| match (self.givens[index], filled[index]) {
+            (Some(digit), _) => 1 << digit,
+            (None, Some(digit)) => (1 << digit) | 512,
+            (None, None) => candidates[index].get_raw() | 512,
+        })
+    }
+}
+
+/// Checks the step against the answers and the expected structure of a solution
+fn check(game_board: &GameBoard, solution: &Solution) -> Result<(), String> {
+    if solution.actions.is_empty() {
+        return Err("no action".to_string());
+    }
+    let mut changes = false;
+    for action in &solution.actions {
+        match action {
+            Action::Confirmation(ConfirmationDetails { x, y, target }) => {
+                if *x >= 9 || *y >= 9 || *target >= 9 {
+                    return Err(format!("{action:?} out of range"));
+                }
+                if game_board.get_answer(*x, *y) != *target {
+                    return Err(format!("{action:?} is wrong"));
+                }
+                changes |= game_board.get_candidates(*x, *y).is_some();
+            }
+            Action::Elimination(EliminationDetails { x, y, target }) => {
+                if *x >= 9
+                    || *y >= 9
+                    || target.count() == 0
+                    || !target.subset_of_raw(BitMap::all().get_raw())
+                {
+                    return Err(format!("{action:?} out of range"));
+                }
+                if target.contains(game_board.get_answer(*x, *y)) {
+                    return Err(format!("{action:?} removes the answer"));
+                }
+                changes |= game_board
+                    .get_candidates(*x, *y)
+                    .is_some_and(|candidates| candidates.intersect(*target).count() > 0);
+            }
+        }
+    }
+    if !changes {
+        return Err("the board isn't changed".to_string());
+    }
+    for house in &solution.house_clues {
+        let (House::Row(id) | House::Col(id) | House::Box(id)) = house;
+        if *id >= 9 {
+            return Err(format!("{house:?} out of range"));
+        }
+    }
+    let clues = &solution.candidate_clues;
+    let is_separator = |clue: &Candidate| clue.candidates == BitMap::NAN;
+    // a trailing separator closes the last group, like in Sue De Coq without rotten Yoke candidates
+    if clues.first().is_some_and(is_separator)
+        || clues.windows(2).any(|pair| pair.iter().all(is_separator))
+    {
+        return Err("unbalanced separators".to_string());
+    }
+    match clues
+        .iter()
+        .filter(|clue| !is_separator(clue))
+        .find(|clue| {
+            clue.x >= 9 || clue.y >= 9 || !clue.candidates.subset_of_raw(BitMap::all().get_raw())
+        }) {
+        Some(clue) => Err(format!("{clue:?} out of range")),
+        None => Ok(()),
+    }
+}
+
+/// The error of the first wrong step among all the steps the solver finds on the position, if any
+fn first_error(solver: &SolverEnum, raws: [u16; 81]) -> Option<String> {
+    let mut game_board = GameBoard::from_array(raws);
+    assert!(game_board.enable_verification().is_ok());
+    solver
+        .solve_all(&game_board)
+        .iter()
+        .find_map(|solution| check(&game_board, solution).err())
+}
+
+fn failure(solver: &SolverEnum, puzzle: &Puzzle, moves: &[Move]) -> Option<String> {
+    first_error(solver, puzzle.raws(moves))
+}
+
+/// Removes the moves one by one while the solver still fails without them
+fn shrink(solver: &SolverEnum, puzzle: &Puzzle, mut moves: Vec<Move>) -> Vec<Move> {
+    let mut i = 0;
+    while i < moves.len() {
+        let mut fewer = moves.clone();
+        fewer.remove(i);
+        if failure(solver, puzzle, &fewer).is_some() {
+            moves = fewer;
+        } else {
+            i += 1;
+        }
+    }
+    moves
+}
+
+fn format_raws(raws: &[u16; 81]) -> String {
+    raws.map(|raw| raw.to_string()).join(", ")
+}
+
+#[test]
+fn solvers_are_sound() {
+    let cases = std::env::var("SOUNDNESS_CASES")
+        .ok()
+        .and_then(|cases| cases.parse().ok())
+        .unwrap_or(DEFAULT_CASES);
+    let corpus: Vec<&str> = include_str!("puzzles.txt").lines().collect();
+    let solvers: Vec<SolverEnum> = SolverIdentifier::ALL
+        .iter()
+        .map(|&solver_id| SolverEnum::from(solver_id))
+        .collect();
+    let mut rng = Rng(0x9E37_79B9_7F4A_7C15);
+    let mut failures = Vec::new();
+    for _ in 0..cases {
+        let puzzle = Puzzle::random(corpus[rng.below(corpus.len())], &mut rng);
+        let moves = puzzle.random_moves(&mut rng);
+        for solver in &solvers {
+            if failure(solver, &puzzle, &moves).is_some() {
+                let moves = shrink(solver, &puzzle, moves.clone());
+                let error = failure(solver, &puzzle, &moves).unwrap();
+                failures.push(format!(
+                    "{:?}: {error}\n[{}]",
+                    solver.solver_id(),
+                    format_raws(&puzzle.raws(&moves))
+                ));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// The positions some solver failed on once, one per line as dumped by `solvers_are_sound`
+fn regressions() -> impl Iterator<Item = [u16; 81]> {
+    include_str!("soundness_regressions.txt")
+        .lines()
+        .map(|line| {
+            let raws: Vec<u16> = line
+                .trim_matches(['[', ']'])
+                .split(',')
+                .map(|raw| raw.trim().parse().unwrap())
+                .collect();
+            raws.try_into().unwrap()
+        })
+}
+
+#[test]
+fn regressions_are_sound() {
+    let mut failures = Vec::new();
+    for raws in regressions() {
+        for &solver_id in SolverIdentifier::ALL {
+            if let Some(error) = first_error(&SolverEnum::from(solver_id), raws) {
+                failures.push(format!("{solver_id:?}: {error}\n[{}]", format_raws(&raws)));
+            }
+        }
+    }
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn harness_catches_wrong_steps() {
+    let mut rng = Rng(1);
+    let puzzle = Puzzle::random(
+        include_str!("puzzles.txt").lines().last().unwrap(),
+        &mut rng,
+    );
+    let mut game_board = GameBoard::from_array(puzzle.raws(&[]));
+    assert!(game_board.enable_verification().is_ok());
+    let (x, y) = Coord::all_cells()
+        .find(|&(x, y)| game_board.get_candidates(x, y).is_some())
+        .unwrap();
+    let answer = game_board.get_answer(x, y);
+    let step = |actions, candidate_clues| Solution {
+        actions,
+        house_clues: vec![],
+        candidate_clues,
+        solver_id: SolverIdentifier::XWing,
+    };
+    let eliminate = |target| vec![Action::Elimination(EliminationDetails { x, y, target })];
+    let confirm = vec![Action::Confirmation(ConfirmationDetails {
+        x,
+        y,
+        target: answer,
+    })];
+    assert!(check(&game_board, &step(confirm.clone(), vec![])).is_ok());
+    assert!(check(&game_board, &step(vec![], vec![])).is_err());
+    assert!(check(&game_board, &step(eliminate(BitMap::from(answer)), vec![])).is_err());
+    let clue = Candidate::new_single(x, y, answer);
+    for clues in [
+        vec![Candidate::SEPARATOR, clue.clone()],
+        vec![
+            clue.clone(),
+            Candidate::SEPARATOR,
+            Candidate::SEPARATOR,
+            clue.clone(),
+        ],
+        vec![Candidate::new_single(9, y, answer)],
+    ] {
+        assert!(check(&game_board, &step(confirm.clone(), clues)).is_err());
+    }
+    // the answers of the position are the ones of the puzzle
+    for (x, y) in Coord::all_cells() {
+        assert_eq!(game_board.get_answer(x, y), puzzle.answers[x * 9 + y]);
+        if let Some(candidates) = game_board.get_candidates(x, y) {
+            assert!(candidates.count() >= 2 && candidates.contains(puzzle.answers[x * 9 + y]));
+        }
+    }
+}
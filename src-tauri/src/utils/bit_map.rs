@@ -1,6 +1,8 @@
 use std::fmt;
 
-#[derive(Clone, Copy, PartialEq)]
+use serde::Serialize;
+
+#[derive(Clone, Copy, PartialEq, Serialize)]
 pub struct BitMap(u16);
 
 impl Default for BitMap {
@@ -45,6 +47,10 @@ impl BitMap {
 
     fn next_combination(&self, limit: usize) -> Option<Self> {
         let raw = self.0;
+        // the empty set is the only combination of size 0
+        if raw == 0 {
+            return None;
+        }
         let u = raw & (!raw + 1);
         let v = raw + u;
         let next = (((raw ^ v) >> 2) / u) | v;
@@ -129,12 +135,10 @@ impl BitMap {
     pub fn difference(&self, other: Self) -> Self {
         self.intersect(other.complement())
     }
-    #[cfg(test)]
     pub fn get_raw(&self) -> u16 {
         self.0
     }
 
-    #[cfg(test)]
     pub fn from_raw(raw: u16) -> Self {
         Self(raw)
     }
@@ -188,5 +192,9 @@ mod bit_map_test {
         {
             assert_eq!(combo.get_raw(), exp[index])
         }
+        assert_eq!(
+            BitMap::get_masked_combo(0, BitMap(0b11011011).complement()).count(),
+            1
+        );
     }
 }
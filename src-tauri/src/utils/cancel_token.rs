@@ -0,0 +1,123 @@
+use std::fmt;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+type ProgressCallback = Arc<dyn Fn(usize, usize) + Send + Sync>;
+
+/// Tells the long-running searches to give up, once cancelled by its owner or past its deadline.
+/// Clones share the same flag, so a token handed to a worker can be cancelled from another thread.
+#[derive(Clone, Default)]
+pub struct CancelToken {
+    cancelled: Arc<AtomicBool>,
+    interrupted: Arc<AtomicBool>,
+    deadline: Option<Instant>,
+    progress: Option<ProgressCallback>,
+}
+
+impl fmt::Debug for CancelToken {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CancelToken")
+            .field("cancelled", &self.cancelled)
+            .field("interrupted", &self.interrupted)
+            .field("deadline", &self.deadline)
+            .finish_non_exhaustive()
+    }
+}
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The token is cancelled by itself once the timeout has elapsed
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.deadline = Some(Instant::now() + timeout);
+        self
+    }
+
+    /// Call `progress(done, total)` every time a search reports its progress
+    pub fn with_progress(
+        mut self,
+        progress: impl Fn(usize, usize) + Send + Sync + 'static,
+    ) -> Self {
+        self.progress = Some(Arc::new(progress));
+        self
+    }
+
+    pub fn cancel(&self) {
+        self.cancelled.store(true, Ordering::Relaxed);
+    }
+
+    /// Checked by the searches, which give up as soon as it returns true
+    pub fn is_cancelled(&self) -> bool {
+        let expired = || {
+            self.deadline
+                .is_some_and(|deadline| Instant::now() >= deadline)
+        };
+        if !self.cancelled.load(Ordering::Relaxed) && !expired() {
+            return false;
+        }
+        self.cancel();
+        self.interrupted.store(true, Ordering::Relaxed);
+        true
+    }
+
+    /// Returns true once a search has seen the token cancelled and stopped early.
+    /// A search finishing before the cancellation or the deadline keeps its complete result.
+    pub fn interrupted(&self) -> bool {
+        self.interrupted.load(Ordering::Relaxed)
+    }
+
+    /// Report that `done` out of `total` units of work are finished
+    pub fn report_progress(&self, done: usize, total: usize) {
+        if let Some(progress) = &self.progress {
+            progress(done, total);
+        }
+    }
+}
+
+#[cfg(test)]
+mod cancel_token_test {
+    use super::*;
+    use std::sync::Mutex;
+
+    #[test]
+    fn test_cancel() {
+        let token = CancelToken::new();
+        let shared = token.clone();
+        assert!(!token.is_cancelled());
+        std::thread::spawn(move || shared.cancel()).join().unwrap();
+        assert!(token.is_cancelled());
+    }
+
+    #[test]
+    fn test_timeout() {
+        let token = CancelToken::new().with_timeout(Duration::from_secs(60));
+        assert!(!token.is_cancelled());
+        let token = CancelToken::new().with_timeout(Duration::ZERO);
+        assert!(token.clone().is_cancelled());
+        assert!(token.cancelled.load(Ordering::Relaxed));
+    }
+
+    #[test]
+    fn test_interrupted() {
+        let token = CancelToken::new();
+        token.cancel();
+        assert!(!token.interrupted());
+        assert!(token.clone().is_cancelled());
+        assert!(token.interrupted());
+    }
+
+    #[test]
+    fn test_progress() {
+        let reports = Arc::new(Mutex::new(Vec::new()));
+        let sink = reports.clone();
+        let token = CancelToken::new().with_progress(move |done, total| {
+            sink.lock().unwrap().push((done, total));
+        });
+        token.clone().report_progress(1, 3);
+        token.report_progress(3, 3);
+        assert_eq!(*reports.lock().unwrap(), [(1, 3), (3, 3)]);
+    }
+}
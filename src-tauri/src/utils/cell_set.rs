@@ -0,0 +1,155 @@
+use std::fmt;
+
+use super::House;
+use super::lookup::{COMMON_PEERS, HOUSE_SETS, PEER_SETS};
+
+/// A set of cells of the grid, cell (x,y) is represented by bit x * 9 + y
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CellSet(u128);
+
+impl fmt::Debug for CellSet {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl CellSet {
+    pub const ALL: Self = Self((1 << 81) - 1);
+
+    pub const fn new() -> Self {
+        Self(0)
+    }
+
+    pub const fn from_cell(x: usize, y: usize) -> Self {
+        Self(1 << (x * 9 + y))
+    }
+
+    /// The cells of the house
+    pub fn house(house: &House) -> Self {
+        HOUSE_SETS[house.get_dim() * 9 + house.get_index()]
+    }
+
+    /// The cells seeing cell (x,y)
+    pub fn peers(x: usize, y: usize) -> Self {
+        PEER_SETS[x * 9 + y]
+    }
+
+    /// The cells seeing both cell (px,py) and cell (qx,qy), empty if they're the same cell
+    pub fn common_peers(px: usize, py: usize, qx: usize, qy: usize) -> Self {
+        COMMON_PEERS[px * 9 + py][qx * 9 + qy]
+    }
+
+    pub fn contains(&self, x: usize, y: usize) -> bool {
+        self.0 & (1 << (x * 9 + y)) != 0
+    }
+
+    pub fn insert(&mut self, x: usize, y: usize) {
+        self.0 |= 1 << (x * 9 + y);
+    }
+
+    pub fn remove(&mut self, x: usize, y: usize) {
+        self.0 &= !(1 << (x * 9 + y));
+    }
+
+    pub fn count(&self) -> usize {
+        self.0.count_ones() as usize
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0 == 0
+    }
+
+    /// The first cell in row-major order
+    pub fn first(&self) -> Option<(usize, usize)> {
+        let index = self.0.trailing_zeros() as usize;
+        (index < 81).then_some((index / 9, index % 9))
+    }
+
+    pub const fn union(&self, other: Self) -> Self {
+        Self(self.0 | other.0)
+    }
+
+    pub const fn intersect(&self, other: Self) -> Self {
+        Self(self.0 & other.0)
+    }
+
+    pub const fn difference(&self, other: Self) -> Self {
+        Self(self.0 & !other.0)
+    }
+
+    pub fn is_subset(&self, other: Self) -> bool {
+        self.0 & !other.0 == 0
+    }
+
+    /// Iterate through the cells in row-major order
+    pub fn iter(&self) -> impl Iterator<Item = (usize, usize)> + use<> {
+        let mut rest = self.0;
+        std::iter::from_fn(move || {
+            (rest != 0).then(|| {
+                let index = rest.trailing_zeros() as usize;
+                rest &= rest - 1;
+                (index / 9, index % 9)
+            })
+        })
+    }
+}
+
+impl FromIterator<(usize, usize)> for CellSet {
+    fn from_iter<T: IntoIterator<Item = (usize, usize)>>(iter: T) -> Self {
+        iter.into_iter().fold(Self::new(), |mut acc, (x, y)| {
+            acc.insert(x, y);
+            acc
+        })
+    }
+}
+
+#[cfg(test)]
+mod cell_set_test {
+    use super::*;
+    use crate::utils::Coord;
+
+    #[test]
+    fn test_set_operations() {
+        let set: CellSet = [(0, 0), (4, 5), (8, 8)].into_iter().collect();
+        assert_eq!(set.count(), 3);
+        assert!(set.contains(4, 5) && !set.contains(5, 4));
+        assert_eq!(set.first(), Some((0, 0)));
+        assert_eq!(set.iter().collect::<Vec<_>>(), [(0, 0), (4, 5), (8, 8)]);
+        let row = CellSet::house(&House::Row(4));
+        assert_eq!(set.intersect(row).iter().collect::<Vec<_>>(), [(4, 5)]);
+        assert_eq!(set.difference(row).count(), 2);
+        assert_eq!(set.union(row).count(), 11);
+        assert!(set.intersect(row).is_subset(row));
+        assert!(CellSet::new().is_empty() && CellSet::new().first().is_none());
+        assert_eq!(CellSet::ALL.count(), 81);
+        assert_eq!(CellSet::ALL.iter().last(), Some((8, 8)));
+    }
+
+    #[test]
+    fn test_tables() {
+        for (dim, id) in (0..3).flat_map(|dim| (0..9).map(move |id| (dim, id))) {
+            let house = House::from_dim_id(dim, id);
+            let cells: CellSet = Coord::all_cells()
+                .filter(|&(x, y)| Coord::is_in_house(x, y, &house))
+                .collect();
+            assert_eq!(CellSet::house(&house), cells);
+        }
+        for (px, py) in Coord::all_cells() {
+            let peers: CellSet = Coord::all_cells()
+                .filter(|&(x, y)| Coord::sees(px, py, x, y))
+                .collect();
+            assert_eq!(CellSet::peers(px, py), peers);
+            assert_eq!(peers.count(), 20);
+            for (qx, qy) in Coord::all_cells() {
+                let common: CellSet = Coord::all_cells()
+                    .filter(|&(x, y)| {
+                        (px, py) != (qx, qy)
+                            && Coord::sees(px, py, x, y)
+                            && Coord::sees(qx, qy, x, y)
+                    })
+                    .collect();
+                assert_eq!(CellSet::common_peers(px, py, qx, qy), common);
+            }
+        }
+    }
+}
@@ -1,50 +1,23 @@
-use crate::utils::House;
+use super::lookup::{HOUSE_CELLS, PEERS};
+use crate::utils::{CellSet, House};
 pub struct Coord;
 
 impl Coord {
     pub fn same(px: usize, py: usize, qx: usize, qy: usize) -> bool {
         (px == qx) && (py == qy)
     }
-    pub fn house(h: &House) -> Box<dyn Iterator<Item = (usize, usize)>> {
-        match *h {
-            House::Box(b) => Box::new(Self::box_coords(b)),
-            House::Col(c) => Box::new(Self::col(c)),
-            House::Row(r) => Box::new(Self::row(r)),
-        }
+    pub fn house(h: &House) -> impl Iterator<Item = (usize, usize)> + use<> {
+        HOUSE_CELLS[h.get_dim() * 9 + h.get_index()].into_iter()
     }
 
-    pub fn intersect(h1: House, h2: House) -> Box<dyn Iterator<Item = (usize, usize)>> {
-        match h1 {
-            House::Row(r) => match h2 {
-                House::Row(_) => Box::new(std::iter::empty()),
-                House::Col(c) => Box::new([(r, c)].into_iter()),
-                House::Box(b) => {
-                    if r / 3 == b / 3 {
-                        let offset = (b % 3) * 3;
-                        Box::new([(r, offset), (r, offset + 1), (r, offset + 2)].into_iter())
-                    } else {
-                        Box::new(std::iter::empty())
-                    }
-                }
-            },
-            House::Col(c) => match h2 {
-                House::Row(r) => Box::new([(r, c)].into_iter()),
-                House::Col(_) => Box::new(std::iter::empty()),
-                House::Box(b) => {
-                    if c / 3 == b % 3 {
-                        let offset = (b / 3) * 3;
-                        Box::new([(offset, c), (offset + 1, c), (offset + 2, c)].into_iter())
-                    } else {
-                        Box::new(std::iter::empty())
-                    }
-                }
-            },
-            House::Box(b) => match h2 {
-                House::Row(r) => Self::intersect(h2, h1),
-                House::Col(r) => Self::intersect(h2, h1),
-                House::Box(_) => Box::new(std::iter::empty()),
-            },
-        }
+    /// The cells in both houses, in row-major order. Two houses of the same type have no intersection.
+    pub fn intersect(h1: House, h2: House) -> impl Iterator<Item = (usize, usize)> {
+        let cells = if h1.get_dim() == h2.get_dim() {
+            CellSet::new()
+        } else {
+            CellSet::house(&h1).intersect(CellSet::house(&h2))
+        };
+        cells.iter()
     }
 
     pub fn is_in_house(x: usize, y: usize, h: &House) -> bool {
@@ -106,11 +79,9 @@ impl Coord {
         Self::box_coords(box_id).filter(move |(xi, yi)| *xi != x || *yi != y)
     }
 
+    /// The cells seeing cell (x,y): the rest of its row, then its column, then its box
     pub fn seeable_cells(x: usize, y: usize) -> impl Iterator<Item = (usize, usize)> {
-        Self::row(x)
-            .filter(move |&(_, j)| j != y)
-            .chain(Self::col(y).filter(move |&(i, _)| i != x))
-            .chain(Self::iter_box(x, y).filter(move |&(i, j)| i != x && j != y))
+        PEERS[x * 9 + y].into_iter()
     }
     pub fn components_array(x: usize, y: usize) -> [usize; 3] {
         [x, y, Self::get_box_id(x, y)]
@@ -131,74 +102,14 @@ impl Coord {
             _ => panic!(),
         }
     }
+    /// The cells seeing both cell (px,py) and cell (qx,qy) in row-major order, nothing if they're the same cell
     pub fn pinched_by(
         px: usize,
         py: usize,
         qx: usize,
         qy: usize,
-    ) -> Box<dyn Iterator<Item = (usize, usize)>> {
-        // same cell, nothing should be returned
-        if px == qx && py == qy {
-            return Box::new(std::iter::empty());
-        }
-        let same_row = px == qx;
-        let same_col = py == qy;
-        let mut res = vec![];
-        // same row, put all cells in the row in
-        if same_row {
-            res.extend((0..9).filter(|&y| y != py && y != qy).map(|y| (px, y)));
-        }
-        // same col, put all cells in the col in
-        if same_col {
-            res.extend((0..9).filter(|&x| x != px && x != qx).map(|x| (x, py)));
-        }
-        // same box:
-        let box_id_p = Coord::get_box_id(px, py);
-        let box_id_q = Coord::get_box_id(qx, qy);
-        if box_id_p == box_id_q {
-            // same box with same row, put other two rows in
-            if same_row {
-                for shift in 0..3 {
-                    if box_id_p / 3 * 3 + shift != px {
-                        res.extend(Self::intersect(
-                            House::Row(box_id_p / 3 * 3 + shift),
-                            House::Box(box_id_p),
-                        ));
-                    }
-                }
-            } else if same_col {
-                // same box with wame col, put other two cols in
-                for shift in 0..3 {
-                    if box_id_p % 3 * 3 + shift != py {
-                        res.extend(Self::intersect(
-                            House::Col(box_id_p % 3 * 3 + shift),
-                            House::Box(box_id_p),
-                        ));
-                    }
-                }
-            } else {
-                // same box but not same row and same col, put all other cells in the box in
-                res.extend(
-                    Self::box_coords(box_id_p)
-                        .filter(|&(x, y)| !((x == px && y == py) || (x == qx && y == qy))),
-                )
-            }
-        } else if !same_row && !same_col {
-            // not same box, not same row, not same col
-            if box_id_p / 3 == box_id_q / 3 {
-                // if same floor
-                res.extend(Coord::intersect(House::Row(px), House::Box(box_id_q)));
-                res.extend(Coord::intersect(House::Row(qx), House::Box(box_id_p)));
-            } else if box_id_p % 3 == box_id_q % 3 {
-                // if same tower
-                res.extend(Coord::intersect(House::Col(py), House::Box(box_id_q)));
-                res.extend(Coord::intersect(House::Col(qy), House::Box(box_id_p)));
-            } else {
-                res.push((px, qy));
-                res.push((qx, py));
-            }
-        }
-        Box::new(res.into_iter())
+    ) -> impl Iterator<Item = (usize, usize)> {
+        CellSet::common_peers(px, py, qx, qy).iter()
     }
 }
 
@@ -230,6 +141,9 @@ mod test_coord {
     fn test_pincer_same_row_box() {
         let res: Vec<_> = Coord::pinched_by(4, 4, 4, 5).collect();
         let exp = [
+            (3, 3),
+            (3, 4),
+            (3, 5),
             (4, 0),
             (4, 1),
             (4, 2),
@@ -237,9 +151,6 @@ mod test_coord {
             (4, 6),
             (4, 7),
             (4, 8),
-            (3, 3),
-            (3, 4),
-            (3, 5),
             (5, 3),
             (5, 4),
             (5, 5),
@@ -257,16 +168,16 @@ mod test_coord {
             (0, 4),
             (1, 4),
             (2, 4),
-            (3, 4),
-            (6, 4),
-            (7, 4),
-            (8, 4),
             (3, 3),
-            (4, 3),
-            (5, 3),
+            (3, 4),
             (3, 5),
+            (4, 3),
             (4, 5),
+            (5, 3),
             (5, 5),
+            (6, 4),
+            (7, 4),
+            (8, 4),
         ];
         assert_eq!(res.len(), exp.len());
         for i in 0..res.len() {
@@ -286,7 +197,7 @@ mod test_coord {
 
     fn test_pincer_same_tower() {
         let res: Vec<_> = Coord::pinched_by(0, 0, 8, 2).collect();
-        let exp = [(6, 0), (7, 0), (8, 0), (0, 2), (1, 2), (2, 2)];
+        let exp = [(0, 2), (1, 2), (2, 2), (6, 0), (7, 0), (8, 0)];
         assert_eq!(res.len(), exp.len());
         for i in 0..res.len() {
             assert_eq!(res[i], exp[i])
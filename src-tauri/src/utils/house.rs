@@ -1,6 +1,8 @@
+use serde::Serialize;
+
 use super::{Coord, HouseType};
 
-#[derive(Debug, PartialEq, Copy, Clone)]
+#[derive(Debug, PartialEq, Copy, Clone, Serialize)]
 pub enum House {
     Row(usize),
     Col(usize),
@@ -8,7 +10,7 @@ pub enum House {
 }
 
 impl House {
-    pub fn as_iter(&self) -> Box<dyn Iterator<Item = (usize, usize)>> {
+    pub fn as_iter(&self) -> impl Iterator<Item = (usize, usize)> + use<> {
         Coord::house(self)
     }
 
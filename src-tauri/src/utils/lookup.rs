@@ -0,0 +1,119 @@
+//! Lookup tables of the grid geometry, built at compile time.
+//! Cell (x,y) has index x * 9 + y, house `House::from_dim_id(dim, id)` has index dim * 9 + id.
+use super::CellSet;
+
+/// HOUSE_CELLS[house] : the cells of the house, in the order of their index in the house
+pub(super) static HOUSE_CELLS: [[(usize, usize); 9]; 27] = house_cells();
+
+/// PEERS[cell] : the cells seeing the cell, the rest of its row, then its column, then its box
+pub(super) static PEERS: [[(usize, usize); 20]; 81] = peers();
+
+/// HOUSE_SETS[house] : the cells of the house
+pub(super) static HOUSE_SETS: [CellSet; 27] = house_sets();
+
+/// PEER_SETS[cell] : the cells seeing the cell
+pub(super) static PEER_SETS: [CellSet; 81] = peer_sets();
+
+/// COMMON_PEERS[p][q] : the cells other than p and q seeing both of them, empty if p and q are the same
+pub(super) static COMMON_PEERS: [[CellSet; 81]; 81] = common_peers();
+
+const fn house_cells() -> [[(usize, usize); 9]; 27] {
+    let mut res = [[(0, 0); 9]; 27];
+    let mut id = 0;
+    while id < 9 {
+        let mut i = 0;
+        while i < 9 {
+            res[id][i] = (id, i);
+            res[9 + id][i] = (i, id);
+            res[18 + id][i] = ((id / 3) * 3 + i / 3, (id % 3) * 3 + i % 3);
+            i += 1;
+        }
+        id += 1;
+    }
+    res
+}
+
+const fn peers() -> [[(usize, usize); 20]; 81] {
+    let mut res = [[(0, 0); 20]; 81];
+    let mut index = 0;
+    while index < 81 {
+        let (x, y) = (index / 9, index % 9);
+        let mut len = 0;
+        let mut i = 0;
+        while i < 9 {
+            if i != y {
+                res[index][len] = (x, i);
+                len += 1;
+            }
+            i += 1;
+        }
+        i = 0;
+        while i < 9 {
+            if i != x {
+                res[index][len] = (i, y);
+                len += 1;
+            }
+            i += 1;
+        }
+        i = 0;
+        while i < 9 {
+            let (bx, by) = ((x / 3) * 3 + i / 3, (y / 3) * 3 + i % 3);
+            if bx != x && by != y {
+                res[index][len] = (bx, by);
+                len += 1;
+            }
+            i += 1;
+        }
+        index += 1;
+    }
+    res
+}
+
+const fn house_sets() -> [CellSet; 27] {
+    let cells = house_cells();
+    let mut res = [CellSet::new(); 27];
+    let mut house = 0;
+    while house < 27 {
+        let mut i = 0;
+        while i < 9 {
+            let (x, y) = cells[house][i];
+            res[house] = res[house].union(CellSet::from_cell(x, y));
+            i += 1;
+        }
+        house += 1;
+    }
+    res
+}
+
+const fn peer_sets() -> [CellSet; 81] {
+    let peers = peers();
+    let mut res = [CellSet::new(); 81];
+    let mut index = 0;
+    while index < 81 {
+        let mut i = 0;
+        while i < 20 {
+            let (x, y) = peers[index][i];
+            res[index] = res[index].union(CellSet::from_cell(x, y));
+            i += 1;
+        }
+        index += 1;
+    }
+    res
+}
+
+const fn common_peers() -> [[CellSet; 81]; 81] {
+    let peer_sets = peer_sets();
+    let mut res = [[CellSet::new(); 81]; 81];
+    let mut p = 0;
+    while p < 81 {
+        let mut q = 0;
+        while q < 81 {
+            if p != q {
+                res[p][q] = peer_sets[p].intersect(peer_sets[q]);
+            }
+            q += 1;
+        }
+        p += 1;
+    }
+    res
+}
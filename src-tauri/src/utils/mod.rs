@@ -1,9 +1,14 @@
 mod bit_map;
 pub use bit_map::BitMap;
 
+mod cell_set;
+pub use cell_set::CellSet;
+
 mod coord;
 pub use coord::Coord;
 
+mod lookup;
+
 mod house;
 pub use house::House;
 
@@ -12,3 +17,6 @@ pub use all_equal_value::AllEqualValue;
 
 mod house_type;
 pub use house_type::HouseType;
+
+mod cancel_token;
+pub use cancel_token::CancelToken;
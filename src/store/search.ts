@@ -0,0 +1,66 @@
+import { defineStore } from 'pinia'
+import { invoke } from "@tauri-apps/api/tauri";
+import { listen } from "@tauri-apps/api/event";
+
+// payloads of the "search-progress" and "search-finished" events emitted by the backend
+interface SearchProgress {
+    searchId: number,
+    done: number,
+    total: number,
+}
+
+interface SearchFinished {
+    searchId: number,
+    cancelled: boolean,
+}
+
+let nextSearchId = 0;
+
+// the search running on the backend, shown with its progress until it finishes or is cancelled
+export const useSearchStore = defineStore('search', {
+    state:()=>({
+        searchId: null as number | null,
+        done: 0,
+        total: 0,
+        cancelled: false,
+    }),
+    getters:{
+        running: (state) => state.searchId !== null,
+    },
+    actions:{
+        // invokes a search command with a new search id, rejects with "cancelled" if it stopped early
+        async run<T>(command: string, args: Record<string, unknown>, timeoutMs?: number): Promise<T> {
+            const searchId = nextSearchId++;
+            this.searchId = searchId;
+            this.done = 0;
+            this.total = 0;
+            this.cancelled = false;
+            const unlistenProgress = await listen<SearchProgress>("search-progress", (event) => {
+                if (event.payload.searchId === searchId) {
+                    this.done = event.payload.done;
+                    this.total = event.payload.total;
+                }
+            });
+            const unlistenFinished = await listen<SearchFinished>("search-finished", (event) => {
+                if (event.payload.searchId === searchId && this.searchId === searchId) {
+                    this.cancelled = event.payload.cancelled;
+                    this.searchId = null;
+                }
+            });
+            try {
+                return await invoke<T>(command, { ...args, searchId, timeoutMs });
+            } finally {
+                unlistenProgress();
+                unlistenFinished();
+                if (this.searchId === searchId) {
+                    this.searchId = null;
+                }
+            }
+        },
+        async cancel() {
+            if (this.searchId !== null) {
+                await invoke("cancel_search", { searchId: this.searchId });
+            }
+        },
+    },
+});